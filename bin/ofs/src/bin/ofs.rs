@@ -0,0 +1,94 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use clap::Arg;
+use clap::ArgAction;
+use clap::Command;
+use opendal::Operator;
+use opendal::Scheme;
+
+fn main() -> Result<()> {
+    let matches = Command::new("ofs")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("OpenDAL File System")
+        .arg(
+            Arg::new("mount_point")
+                .required(true)
+                .help("Path to mount the file system"),
+        )
+        .arg(
+            Arg::new("scheme")
+                .required(true)
+                .help("Scheme of the service, for example: s3, fs"),
+        )
+        .arg(
+            Arg::new("option")
+                .short('o')
+                .long("option")
+                .action(ArgAction::Append)
+                .help("Options of the service in key=value format"),
+        )
+        .get_matches();
+
+    let mount_point = matches
+        .get_one::<String>("mount_point")
+        .expect("mount_point is required");
+    let scheme = matches
+        .get_one::<String>("scheme")
+        .expect("scheme is required");
+    let scheme = Scheme::from_str(scheme).context("unsupported scheme")?;
+
+    let mut map = HashMap::new();
+    for opt in matches.get_many::<String>("option").unwrap_or_default() {
+        let (k, v) = opt
+            .split_once('=')
+            .ok_or_else(|| anyhow!("invalid option {opt}, expected key=value"))?;
+        map.insert(k.to_string(), v.to_string());
+    }
+    let op = Operator::via_map(scheme, map)?;
+
+    mount(op, mount_point)
+}
+
+#[cfg(unix)]
+fn mount(op: Operator, mount_point: &str) -> Result<()> {
+    use fuser::MountOption;
+    use ofs::Ofs;
+
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
+
+    let fs = Ofs::new(op, rt.handle().clone());
+    fuser::mount2(
+        fs,
+        mount_point,
+        &[MountOption::FSName("ofs".to_string()), MountOption::RW],
+    )?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn mount(_: Operator, _: &str) -> Result<()> {
+    Err(anyhow!("ofs is only supported on unix"))
+}
@@ -0,0 +1,553 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use fuser::FileAttr;
+use fuser::FileType;
+use fuser::Filesystem;
+use fuser::ReplyAttr;
+use fuser::ReplyCreate;
+use fuser::ReplyData;
+use fuser::ReplyDirectory;
+use fuser::ReplyEmpty;
+use fuser::ReplyEntry;
+use fuser::ReplyOpen;
+use fuser::ReplyWrite;
+use fuser::Request;
+use fuser::TimeOrNow;
+use futures::TryStreamExt;
+use log::debug;
+use opendal::EntryMode;
+use opendal::ErrorKind;
+use opendal::Metadata;
+use opendal::Metakey;
+use opendal::Operator;
+use tokio::runtime::Handle;
+
+use crate::inode::child_path;
+use crate::inode::InodeTable;
+use crate::inode::ROOT_INODE;
+
+/// TTL of attrs, both for kernel and our internal stat cache.
+const TTL: Duration = Duration::from_secs(1);
+
+/// Block size reported to kernel.
+const BLOCK_SIZE: u32 = 512;
+
+/// Ofs implements [`fuser::Filesystem`] on top of an [`Operator`].
+///
+/// - `stat` is mapped to `getattr` and `lookup`.
+/// - `read` is mapped to `read`.
+/// - `write` is buffered in memory and flushed on `flush` or `release`
+///   since most services can't write at random offset. Existing content is
+///   only dropped on `open` with `O_TRUNC` or `setattr` with size 0.
+/// - `list` is mapped to `readdir`.
+///
+/// Stat results are cached for 1 second to avoid sending repeated requests
+/// during directory traversal.
+pub struct Ofs {
+    op: Operator,
+    rt: Handle,
+
+    uid: u32,
+    gid: u32,
+
+    inodes: InodeTable,
+    attrs: HashMap<u64, (Instant, FileAttr)>,
+    buffers: HashMap<u64, Vec<u8>>,
+}
+
+impl Ofs {
+    /// Create a new Ofs.
+    ///
+    /// All operations will be executed via given tokio runtime handle.
+    pub fn new(op: Operator, rt: Handle) -> Self {
+        Ofs {
+            op,
+            rt,
+
+            // Safety: getuid and getgid are always successful.
+            uid: unsafe { libc::getuid() },
+            gid: unsafe { libc::getgid() },
+
+            inodes: InodeTable::new(),
+            attrs: HashMap::new(),
+            buffers: HashMap::new(),
+        }
+    }
+
+    fn new_attr(&self, ino: u64, meta: &Metadata) -> FileAttr {
+        let (kind, perm) = match meta.mode() {
+            EntryMode::DIR => (FileType::Directory, 0o755),
+            _ => (FileType::RegularFile, 0o644),
+        };
+        let size = self
+            .buffers
+            .get(&ino)
+            .map(|v| v.len() as u64)
+            .unwrap_or_else(|| meta.content_length());
+        let mtime = meta
+            .last_modified()
+            .map(SystemTime::from)
+            .unwrap_or(UNIX_EPOCH);
+
+        FileAttr {
+            ino,
+            size,
+            blocks: (size + BLOCK_SIZE as u64 - 1) / BLOCK_SIZE as u64,
+            atime: mtime,
+            mtime,
+            ctime: mtime,
+            crtime: mtime,
+            kind,
+            perm,
+            nlink: 1,
+            uid: self.uid,
+            gid: self.gid,
+            rdev: 0,
+            blksize: BLOCK_SIZE,
+            flags: 0,
+        }
+    }
+
+    /// Stat given path with cache.
+    fn stat(&mut self, path: &str) -> opendal::Result<FileAttr> {
+        let ino = self.inodes.inode(path);
+        if let Some((at, attr)) = self.attrs.get(&ino) {
+            if at.elapsed() < TTL {
+                return Ok(*attr);
+            }
+        }
+
+        let meta = if path == "/" {
+            Metadata::new(EntryMode::DIR)
+        } else {
+            self.rt.block_on(self.op.stat(path))?
+        };
+        let attr = self.new_attr(ino, &meta);
+        self.attrs.insert(ino, (Instant::now(), attr));
+        Ok(attr)
+    }
+
+    /// Lookup the child entry of parent with given name.
+    ///
+    /// Object storage services may report any dir path as existing, so we
+    /// check dirs by listing the parent instead of stat.
+    fn lookup_child(&mut self, parent: &str, name: &str) -> opendal::Result<FileAttr> {
+        let file = child_path(parent, name, false);
+        match self.stat(&file) {
+            Ok(attr) if attr.kind == FileType::RegularFile => return Ok(attr),
+            Ok(_) => {}
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        let dir = child_path(parent, name, true);
+        let exist = self.rt.block_on(async {
+            let mut lister = self.op.list(parent).await?;
+            while let Some(entry) = lister.try_next().await? {
+                if entry.path() == dir {
+                    return Ok::<bool, opendal::Error>(true);
+                }
+            }
+            Ok(false)
+        })?;
+        if !exist {
+            return Err(opendal::Error::new(ErrorKind::NotFound, "entry not found"));
+        }
+
+        let ino = self.inodes.inode(&dir);
+        let attr = self.new_attr(ino, &Metadata::new(EntryMode::DIR));
+        self.attrs.insert(ino, (Instant::now(), attr));
+        Ok(attr)
+    }
+
+    fn child(&self, parent: u64, name: &OsStr) -> Result<(String, String), libc::c_int> {
+        let parent = self.inodes.path(parent).ok_or(libc::ENOENT)?.to_string();
+        let name = name.to_str().ok_or(libc::EINVAL)?.to_string();
+        Ok((parent, name))
+    }
+
+    /// Flush the write buffer of given inode into storage.
+    fn flush_buffer(&mut self, ino: u64) -> opendal::Result<()> {
+        let bs = match self.buffers.remove(&ino) {
+            Some(bs) => bs,
+            None => return Ok(()),
+        };
+        let path = self.path(ino)?;
+
+        debug!("ofs flush {} bytes into {}", bs.len(), path);
+        self.rt.block_on(self.op.write(&path, bs))?;
+        self.attrs.remove(&ino);
+        Ok(())
+    }
+
+    /// Load the write buffer of given inode, existing content will be read
+    /// unless `truncate` is true.
+    fn load_buffer(&mut self, ino: u64, truncate: bool) -> opendal::Result<&mut Vec<u8>> {
+        if self.buffers.get(&ino).is_none() {
+            let bs = if truncate {
+                vec![]
+            } else {
+                let path = self.path(ino)?;
+                match self.rt.block_on(self.op.read(&path)) {
+                    Ok(bs) => bs,
+                    Err(err) if err.kind() == ErrorKind::NotFound => vec![],
+                    Err(err) => return Err(err),
+                }
+            };
+            self.buffers.insert(ino, bs);
+        }
+
+        Ok(self.buffers.get_mut(&ino).expect("buffer must be loaded"))
+    }
+
+    fn path(&self, ino: u64) -> opendal::Result<String> {
+        self.inodes
+            .path(ino)
+            .map(|v| v.to_string())
+            .ok_or_else(|| opendal::Error::new(ErrorKind::NotFound, "inode not found"))
+    }
+}
+
+impl Filesystem for Ofs {
+    fn lookup(&mut self, _req: &Request<'_>, parent: u64, name: &OsStr, reply: ReplyEntry) {
+        let (parent, name) = match self.child(parent, name) {
+            Ok(v) => v,
+            Err(errno) => return reply.error(errno),
+        };
+
+        match self.lookup_child(&parent, &name) {
+            Ok(attr) => reply.entry(&TTL, &attr, 0),
+            Err(err) => reply.error(format_errno(&err)),
+        }
+    }
+
+    fn getattr(&mut self, _req: &Request<'_>, ino: u64, reply: ReplyAttr) {
+        let path = match self.path(ino) {
+            Ok(path) => path,
+            Err(err) => return reply.error(format_errno(&err)),
+        };
+
+        match self.stat(&path) {
+            Ok(attr) => reply.attr(&TTL, &attr),
+            Err(err) => reply.error(format_errno(&err)),
+        }
+    }
+
+    fn setattr(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        _mode: Option<u32>,
+        _uid: Option<u32>,
+        _gid: Option<u32>,
+        size: Option<u64>,
+        _atime: Option<TimeOrNow>,
+        _mtime: Option<TimeOrNow>,
+        _ctime: Option<SystemTime>,
+        _fh: Option<u64>,
+        _crtime: Option<SystemTime>,
+        _chgtime: Option<SystemTime>,
+        _bkuptime: Option<SystemTime>,
+        _flags: Option<u32>,
+        reply: ReplyAttr,
+    ) {
+        // Only truncate is supported, other attrs are ignored.
+        if let Some(size) = size {
+            let res = self
+                .load_buffer(ino, size == 0)
+                .map(|bs| bs.resize(size as usize, 0))
+                .and_then(|_| self.flush_buffer(ino));
+            if let Err(err) = res {
+                return reply.error(format_errno(&err));
+            }
+        }
+
+        self.getattr(_req, ino, reply)
+    }
+
+    fn mkdir(
+        &mut self,
+        _req: &Request<'_>,
+        parent: u64,
+        name: &OsStr,
+        _mode: u32,
+        _umask: u32,
+        reply: ReplyEntry,
+    ) {
+        let (parent, name) = match self.child(parent, name) {
+            Ok(v) => v,
+            Err(errno) => return reply.error(errno),
+        };
+
+        let path = child_path(&parent, &name, true);
+        if let Err(err) = self.rt.block_on(self.op.create_dir(&path)) {
+            return reply.error(format_errno(&err));
+        }
+
+        let ino = self.inodes.inode(&path);
+        let attr = self.new_attr(ino, &Metadata::new(EntryMode::DIR));
+        self.attrs.insert(ino, (Instant::now(), attr));
+        reply.entry(&TTL, &attr, 0)
+    }
+
+    fn unlink(&mut self, _req: &Request<'_>, parent: u64, name: &OsStr, reply: ReplyEmpty) {
+        let (parent, name) = match self.child(parent, name) {
+            Ok(v) => v,
+            Err(errno) => return reply.error(errno),
+        };
+
+        let path = child_path(&parent, &name, false);
+        if let Err(err) = self.rt.block_on(self.op.delete(&path)) {
+            return reply.error(format_errno(&err));
+        }
+
+        let ino = self.inodes.inode(&path);
+        self.attrs.remove(&ino);
+        self.buffers.remove(&ino);
+        self.inodes.remove(&path);
+        reply.ok()
+    }
+
+    fn rmdir(&mut self, _req: &Request<'_>, parent: u64, name: &OsStr, reply: ReplyEmpty) {
+        let (parent, name) = match self.child(parent, name) {
+            Ok(v) => v,
+            Err(errno) => return reply.error(errno),
+        };
+
+        let path = child_path(&parent, &name, true);
+        let res = self.rt.block_on(async {
+            let mut lister = self.op.list(&path).await?;
+            if lister.try_next().await?.is_some() {
+                return Ok(Err(libc::ENOTEMPTY));
+            }
+            self.op.delete(&path).await.map(Ok)
+        });
+        match res {
+            Ok(Ok(())) => {}
+            Ok(Err(errno)) => return reply.error(errno),
+            Err(err) => return reply.error(format_errno(&err)),
+        }
+
+        let ino = self.inodes.inode(&path);
+        self.attrs.remove(&ino);
+        self.inodes.remove(&path);
+        reply.ok()
+    }
+
+    fn open(&mut self, _req: &Request<'_>, ino: u64, flags: i32, reply: ReplyOpen) {
+        if flags & libc::O_TRUNC != 0 {
+            // Pending writes are dropped too, the empty buffer will be
+            // flushed on `flush` or `release`.
+            self.buffers.insert(ino, vec![]);
+            self.attrs.remove(&ino);
+        }
+
+        reply.opened(0, 0)
+    }
+
+    fn read(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        _fh: u64,
+        offset: i64,
+        size: u32,
+        _flags: i32,
+        _lock_owner: Option<u64>,
+        reply: ReplyData,
+    ) {
+        let offset = offset as u64;
+
+        // Read from the write buffer if there are pending writes.
+        if let Some(bs) = self.buffers.get(&ino) {
+            let start = (offset as usize).min(bs.len());
+            let end = (start + size as usize).min(bs.len());
+            return reply.data(&bs[start..end]);
+        }
+
+        let path = match self.path(ino) {
+            Ok(path) => path,
+            Err(err) => return reply.error(format_errno(&err)),
+        };
+
+        match self
+            .rt
+            .block_on(self.op.range_read(&path, offset..offset + size as u64))
+        {
+            Ok(bs) => reply.data(&bs),
+            Err(err) => reply.error(format_errno(&err)),
+        }
+    }
+
+    fn write(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        _fh: u64,
+        offset: i64,
+        data: &[u8],
+        _write_flags: u32,
+        _flags: i32,
+        _lock_owner: Option<u64>,
+        reply: ReplyWrite,
+    ) {
+        let offset = offset as usize;
+
+        let bs = match self.load_buffer(ino, false) {
+            Ok(bs) => bs,
+            Err(err) => return reply.error(format_errno(&err)),
+        };
+        if bs.len() < offset + data.len() {
+            bs.resize(offset + data.len(), 0);
+        }
+        bs[offset..offset + data.len()].copy_from_slice(data);
+
+        reply.written(data.len() as u32)
+    }
+
+    fn flush(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        _fh: u64,
+        _lock_owner: u64,
+        reply: ReplyEmpty,
+    ) {
+        match self.flush_buffer(ino) {
+            Ok(()) => reply.ok(),
+            Err(err) => reply.error(format_errno(&err)),
+        }
+    }
+
+    fn release(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        _fh: u64,
+        _flags: i32,
+        _lock_owner: Option<u64>,
+        _flush: bool,
+        reply: ReplyEmpty,
+    ) {
+        match self.flush_buffer(ino) {
+            Ok(()) => reply.ok(),
+            Err(err) => reply.error(format_errno(&err)),
+        }
+    }
+
+    fn readdir(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        _fh: u64,
+        offset: i64,
+        mut reply: ReplyDirectory,
+    ) {
+        let path = match self.path(ino) {
+            Ok(path) => path,
+            Err(err) => return reply.error(format_errno(&err)),
+        };
+
+        let res = self.rt.block_on(async {
+            let mut entries = vec![];
+            let mut lister = self.op.list(&path).await?;
+            while let Some(entry) = lister.try_next().await? {
+                let meta = self.op.metadata(&entry, Metakey::Mode).await?;
+                entries.push((entry.path().to_string(), entry.name().to_string(), meta));
+            }
+            Ok::<_, opendal::Error>(entries)
+        });
+        let entries = match res {
+            Ok(entries) => entries,
+            Err(err) => return reply.error(format_errno(&err)),
+        };
+
+        let mut all = vec![
+            (ino, FileType::Directory, ".".to_string()),
+            (ROOT_INODE, FileType::Directory, "..".to_string()),
+        ];
+        for (path, name, meta) in entries {
+            // Services could return the dir itself, skip it.
+            if path == self.inodes.path(ino).unwrap_or_default() {
+                continue;
+            }
+            let kind = if meta.is_dir() {
+                FileType::Directory
+            } else {
+                FileType::RegularFile
+            };
+            let name = name.trim_end_matches('/').to_string();
+            all.push((self.inodes.inode(&path), kind, name));
+        }
+
+        for (idx, (ino, kind, name)) in all.into_iter().enumerate().skip(offset as usize) {
+            // Offset is the offset of next entry.
+            if reply.add(ino, (idx + 1) as i64, kind, name) {
+                break;
+            }
+        }
+        reply.ok()
+    }
+
+    fn create(
+        &mut self,
+        _req: &Request<'_>,
+        parent: u64,
+        name: &OsStr,
+        _mode: u32,
+        _umask: u32,
+        _flags: i32,
+        reply: ReplyCreate,
+    ) {
+        let (parent, name) = match self.child(parent, name) {
+            Ok(v) => v,
+            Err(errno) => return reply.error(errno),
+        };
+
+        let path = child_path(&parent, &name, false);
+        if let Err(err) = self.rt.block_on(self.op.write(&path, vec![])) {
+            return reply.error(format_errno(&err));
+        }
+
+        let ino = self.inodes.inode(&path);
+        self.buffers.insert(ino, vec![]);
+        let attr = self.new_attr(ino, &Metadata::new(EntryMode::FILE));
+        self.attrs.insert(ino, (Instant::now(), attr));
+        reply.created(&TTL, &attr, 0, 0, 0)
+    }
+}
+
+/// Convert opendal error into errno.
+fn format_errno(err: &opendal::Error) -> libc::c_int {
+    match err.kind() {
+        ErrorKind::NotFound => libc::ENOENT,
+        ErrorKind::PermissionDenied => libc::EACCES,
+        ErrorKind::AlreadyExists => libc::EEXIST,
+        ErrorKind::IsADirectory => libc::EISDIR,
+        ErrorKind::NotADirectory => libc::ENOTDIR,
+        ErrorKind::Unsupported => libc::ENOSYS,
+        _ => libc::EIO,
+    }
+}
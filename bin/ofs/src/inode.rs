@@ -0,0 +1,123 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+/// Inode number of the root dir, required by FUSE.
+pub const ROOT_INODE: u64 = 1;
+
+/// InodeTable maintains the mapping between FUSE inodes and OpenDAL paths.
+///
+/// FUSE requires numeric inodes, while OpenDAL only knows about paths. We
+/// allocate a new inode for every path we have seen and never reuse them.
+///
+/// Paths follow OpenDAL's convention: dir paths end with `/` and root is `/`.
+#[derive(Debug)]
+pub struct InodeTable {
+    paths: HashMap<u64, String>,
+    inodes: HashMap<String, u64>,
+    next: u64,
+}
+
+impl Default for InodeTable {
+    fn default() -> Self {
+        let mut table = InodeTable {
+            paths: HashMap::new(),
+            inodes: HashMap::new(),
+            next: ROOT_INODE + 1,
+        };
+        table.paths.insert(ROOT_INODE, "/".to_string());
+        table.inodes.insert("/".to_string(), ROOT_INODE);
+        table
+    }
+}
+
+impl InodeTable {
+    /// Create a new InodeTable which only contains root.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get path of given inode.
+    pub fn path(&self, ino: u64) -> Option<&str> {
+        self.paths.get(&ino).map(|v| v.as_str())
+    }
+
+    /// Get the inode of given path, allocate a new one if not exist.
+    pub fn inode(&mut self, path: &str) -> u64 {
+        if let Some(ino) = self.inodes.get(path) {
+            return *ino;
+        }
+
+        let ino = self.next;
+        self.next += 1;
+        self.paths.insert(ino, path.to_string());
+        self.inodes.insert(path.to_string(), ino);
+        ino
+    }
+
+    /// Remove the given path from table.
+    pub fn remove(&mut self, path: &str) {
+        if let Some(ino) = self.inodes.remove(path) {
+            self.paths.remove(&ino);
+        }
+    }
+}
+
+/// Build the child path of given parent dir.
+pub fn child_path(parent: &str, name: &str, is_dir: bool) -> String {
+    let mut path = if parent == "/" {
+        name.to_string()
+    } else {
+        format!("{parent}{name}")
+    };
+    if is_dir {
+        path.push('/');
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inode_table() {
+        let mut table = InodeTable::new();
+        assert_eq!(table.path(ROOT_INODE), Some("/"));
+
+        let ino = table.inode("dir/");
+        assert_eq!(ino, ROOT_INODE + 1);
+        assert_eq!(table.inode("dir/"), ino, "inode must be stable");
+        assert_eq!(table.path(ino), Some("dir/"));
+
+        let file = table.inode("dir/file");
+        assert_ne!(file, ino);
+
+        table.remove("dir/file");
+        assert_eq!(table.path(file), None);
+        assert!(table.inode("dir/file") > file, "inode must not be reused");
+    }
+
+    #[test]
+    fn test_child_path() {
+        assert_eq!(child_path("/", "file", false), "file");
+        assert_eq!(child_path("/", "dir", true), "dir/");
+        assert_eq!(child_path("dir/", "file", false), "dir/file");
+        assert_eq!(child_path("dir/", "sub", true), "dir/sub/");
+    }
+}
@@ -0,0 +1,194 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// ConfigLoader loads TOML config files with environment variable
+/// interpolation.
+///
+/// All string values in the form of `{{ env.VAR_NAME }}` will be replaced
+/// by the value of environment variable `VAR_NAME`. Loading fails if the
+/// variable is not set.
+///
+/// Only `{{ env.* }}` is supported, other contents are kept as is.
+///
+/// # Example
+///
+/// ```toml
+/// [profiles.mys3]
+/// type = "s3"
+/// secret_access_key = "{{ env.S3_SECRET_ACCESS_KEY }}"
+/// ```
+pub struct ConfigLoader;
+
+impl ConfigLoader {
+    /// Load config from given TOML file.
+    pub fn load<T: DeserializeOwned>(path: &Path) -> Result<T> {
+        let content = fs::read_to_string(path)?;
+        Self::load_from_str(&content)
+    }
+
+    /// Load config from given TOML content.
+    pub fn load_from_str<T: DeserializeOwned>(content: &str) -> Result<T> {
+        let mut value: Value = toml::from_str(content)?;
+        interpolate_value(&mut value, &|k| env::var(k).ok())?;
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+/// Interpolate all string values inside given value.
+fn interpolate_value(value: &mut Value, lookup: &dyn Fn(&str) -> Option<String>) -> Result<()> {
+    match value {
+        Value::String(s) => *s = interpolate(s, lookup)?,
+        Value::Array(arr) => {
+            for v in arr {
+                interpolate_value(v, lookup)?;
+            }
+        }
+        Value::Object(map) => {
+            for v in map.values_mut() {
+                interpolate_value(v, lookup)?;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+/// Replace all `{{ env.VAR_NAME }}` in given string.
+fn interpolate(s: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Result<String> {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find("{{") {
+        let (before, after) = rest.split_at(start);
+        result.push_str(before);
+
+        let placeholder = after[2..]
+            .find("}}")
+            .map(|end| (&after[2..2 + end], &after[2 + end + 2..]));
+        match placeholder {
+            Some((expr, remain)) if expr.trim().starts_with("env.") => {
+                let key = expr.trim().trim_start_matches("env.");
+                let value = lookup(key)
+                    .ok_or_else(|| anyhow!("environment variable {} is not set", key))?;
+                result.push_str(&value);
+                rest = remain;
+            }
+            // Not a placeholder we support, keep `{{` as is.
+            _ => {
+                result.push_str("{{");
+                rest = &after[2..];
+            }
+        }
+    }
+    result.push_str(rest);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn lookup(k: &str) -> Option<String> {
+        match k {
+            "SECRET_KEY" => Some("secret".to_string()),
+            "REGION" => Some("us-east-1".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let cases = vec![
+            ("empty", "", ""),
+            ("plain", "hello", "hello"),
+            ("env", "{{ env.SECRET_KEY }}", "secret"),
+            ("no spaces", "{{env.SECRET_KEY}}", "secret"),
+            (
+                "multiple",
+                "{{ env.REGION }}/{{ env.SECRET_KEY }}",
+                "us-east-1/secret",
+            ),
+            ("surrounded", "a-{{ env.REGION }}-b", "a-us-east-1-b"),
+            ("not env", "{{ abc }}", "{{ abc }}"),
+            ("unclosed", "{{ env.REGION", "{{ env.REGION"),
+        ];
+
+        for (name, input, expected) in cases {
+            let actual = interpolate(input, &lookup).expect("interpolate must succeed");
+            assert_eq!(expected, actual, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_interpolate_missing_env() {
+        assert!(interpolate("{{ env.NOT_EXIST }}", &lookup).is_err());
+    }
+
+    #[test]
+    fn test_load_from_str() -> Result<()> {
+        env::set_var("OLI_TEST_LOADER_SECRET", "foo");
+
+        let cfg: HashMap<String, HashMap<String, HashMap<String, String>>> =
+            ConfigLoader::load_from_str(
+                r#"
+[profiles.mys3]
+type = "s3"
+secret_access_key = "{{ env.OLI_TEST_LOADER_SECRET }}"
+"#,
+            )?;
+        let profile = &cfg["profiles"]["mys3"];
+        assert_eq!(profile["type"], "s3");
+        assert_eq!(profile["secret_access_key"], "foo");
+
+        env::remove_var("OLI_TEST_LOADER_SECRET");
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_interpolate_passthrough(s in "[^{]*") {
+            prop_assert_eq!(interpolate(&s, &lookup).unwrap(), s);
+        }
+
+        #[test]
+        fn test_interpolate_without_env_passthrough(s in "\\PC*") {
+            prop_assume!(!s.contains("env."));
+            prop_assert_eq!(interpolate(&s, &lookup).unwrap(), s);
+        }
+
+        #[test]
+        fn test_load_passthrough(k in "[a-z_]{1,16}", v in "[a-zA-Z0-9 ./:_-]*") {
+            let content = format!("{k} = \"{v}\"");
+            let cfg: HashMap<String, String> = ConfigLoader::load_from_str(&content).unwrap();
+            prop_assert_eq!(&cfg[&k], &v);
+        }
+    }
+}
@@ -18,7 +18,6 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::env;
-use std::fs;
 use std::path::Component;
 use std::path::Path;
 use std::path::PathBuf;
@@ -30,7 +29,9 @@ use opendal::services;
 use opendal::Operator;
 use opendal::Scheme;
 use serde::Deserialize;
-use toml;
+
+mod loader;
+pub use loader::ConfigLoader;
 
 #[derive(Deserialize, Default)]
 pub struct Config {
@@ -87,12 +88,12 @@ impl Config {
     /// Parse a local config file.
     ///
     /// - If the config file is not present, a default Config is returned.
+    /// - `{{ env.VAR_NAME }}` in values will be replaced by environment variables.
     pub fn load_from_file(config_path: &Path) -> Result<Config> {
         if !config_path.exists() {
             return Ok(Config::default());
         }
-        let data = fs::read_to_string(config_path)?;
-        Ok(toml::from_str(&data)?)
+        ConfigLoader::load(config_path)
     }
 
     /// Load config from environment variables.
@@ -264,6 +265,8 @@ impl Config {
 
 #[cfg(test)]
 mod tests {
+    use std::fs;
+
     use opendal::Scheme;
 
     use super::*;
@@ -79,6 +79,18 @@ typedef enum opendal_code {
    * The given file paths are same.
    */
   OPENDAL_IS_SAME_FILE,
+  /**
+   * The quota of this operation has been exceeded.
+   */
+  OPENDAL_QUOTA_EXCEEDED,
+  /**
+   * The content is invalid.
+   */
+  OPENDAL_INVALID_DATA,
+  /**
+   * The file is locked by its retention.
+   */
+  OPENDAL_OBJECT_LOCKED,
 } opendal_code;
 
 /**
@@ -51,6 +51,12 @@ pub enum opendal_code {
     OPENDAL_RATE_LIMITED,
     /// The given file paths are same.
     OPENDAL_IS_SAME_FILE,
+    /// The quota of this operation has been exceeded.
+    OPENDAL_QUOTA_EXCEEDED,
+    /// The content is invalid.
+    OPENDAL_INVALID_DATA,
+    /// The file is locked by its retention.
+    OPENDAL_OBJECT_LOCKED,
 }
 
 impl opendal_code {
@@ -75,6 +81,9 @@ impl opendal_error {
             od::ErrorKind::AlreadyExists => opendal_code::OPENDAL_ALREADY_EXISTS,
             od::ErrorKind::RateLimited => opendal_code::OPENDAL_RATE_LIMITED,
             od::ErrorKind::IsSameFile => opendal_code::OPENDAL_IS_SAME_FILE,
+            od::ErrorKind::QuotaExceeded => opendal_code::OPENDAL_QUOTA_EXCEEDED,
+            od::ErrorKind::InvalidData => opendal_code::OPENDAL_INVALID_DATA,
+            od::ErrorKind::ObjectLocked => opendal_code::OPENDAL_OBJECT_LOCKED,
             // if this is triggered, check the [`core`] crate and add a
             // new error code accordingly
             _ => panic!("The newly added ErrorKind in core crate is not handled in C bindings"),
@@ -0,0 +1,335 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! OpenDAL's arrow-flight binding.
+//!
+//! [`OpendalFlightService`] implements [`FlightService`] on top of an
+//! [`Operator`], so that Parquet files stored in any service supported by
+//! OpenDAL can be streamed as Arrow record batches over Flight.
+//!
+//! - Tickets and descriptors of type `PATH` are paths of Parquet files.
+//! - Files are read with [`ParquetRecordBatchStreamBuilder`] via OpenDAL's
+//!   [`Reader`], only the footer and the row groups being decoded are kept
+//!   in memory.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use arrow_flight::flight_service_server::FlightServiceServer;
+//! use opendal::services;
+//! use opendal::Operator;
+//! use opendal_flight::OpendalFlightService;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let op = Operator::new(services::Memory::default())?.finish();
+//! let svc = FlightServiceServer::new(OpendalFlightService::new(op));
+//!
+//! tonic::transport::Server::builder()
+//!     .add_service(svc)
+//!     .serve("0.0.0.0:50051".parse()?)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
+use arrow_flight::flight_descriptor::DescriptorType;
+use arrow_flight::flight_service_server::FlightService;
+use arrow_flight::Action;
+use arrow_flight::ActionType;
+use arrow_flight::Criteria;
+use arrow_flight::Empty;
+use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightEndpoint;
+use arrow_flight::FlightInfo;
+use arrow_flight::HandshakeRequest;
+use arrow_flight::HandshakeResponse;
+use arrow_flight::PollInfo;
+use arrow_flight::PutResult;
+use arrow_flight::SchemaAsIpc;
+use arrow_flight::SchemaResult;
+use arrow_flight::Ticket;
+use arrow_ipc::writer::IpcWriteOptions;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use opendal::ErrorKind;
+use opendal::Operator;
+use opendal::Reader;
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+use tonic::Streaming;
+
+/// OpendalFlightService serves Parquet files of an [`Operator`] over
+/// Arrow Flight.
+#[derive(Debug, Clone)]
+pub struct OpendalFlightService {
+    inner: Operator,
+}
+
+impl OpendalFlightService {
+    /// Create OpendalFlightService by given Operator.
+    pub fn new(op: Operator) -> Self {
+        Self { inner: op }
+    }
+
+    /// Open the Parquet file at path and read its footer.
+    #[allow(clippy::result_large_err)]
+    async fn open(&self, path: &str) -> Result<ParquetRecordBatchStreamBuilder<Reader>, Status> {
+        let r = self.inner.reader(path).await.map_err(format_status)?;
+        ParquetRecordBatchStreamBuilder::new(r)
+            .await
+            .map_err(|err| Status::invalid_argument(format!("open parquet {path}: {err}")))
+    }
+}
+
+#[tonic::async_trait]
+impl FlightService for OpendalFlightService {
+    type HandshakeStream = BoxStream<'static, Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = BoxStream<'static, Result<FlightInfo, Status>>;
+    type DoGetStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, Result<PutResult, Status>>;
+    type DoExchangeStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoActionStream = BoxStream<'static, Result<arrow_flight::Result, Status>>;
+    type ListActionsStream = BoxStream<'static, Result<ActionType, Status>>;
+
+    async fn handshake(
+        &self,
+        _: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("handshake is not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("list_flights is not supported"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let desc = request.into_inner();
+        let path = descriptor_path(&desc)?;
+        let builder = self.open(&path).await?;
+
+        let info = FlightInfo::new()
+            .try_with_schema(builder.schema())
+            .map_err(|err| Status::internal(err.to_string()))?
+            .with_endpoint(FlightEndpoint::new().with_ticket(Ticket::new(path)))
+            .with_descriptor(desc)
+            .with_total_records(builder.metadata().file_metadata().num_rows());
+        Ok(Response::new(info))
+    }
+
+    async fn poll_flight_info(
+        &self,
+        _: Request<FlightDescriptor>,
+    ) -> Result<Response<PollInfo>, Status> {
+        Err(Status::unimplemented("poll_flight_info is not supported"))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        let path = descriptor_path(request.get_ref())?;
+        let builder = self.open(&path).await?;
+
+        let schema = SchemaResult::try_from(SchemaAsIpc::new(
+            builder.schema(),
+            &IpcWriteOptions::default(),
+        ))
+        .map_err(|err| Status::internal(err.to_string()))?;
+        Ok(Response::new(schema))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let ticket = request.into_inner().ticket;
+        let path = std::str::from_utf8(&ticket)
+            .map_err(|_| Status::invalid_argument("ticket must be a valid utf-8 path"))?;
+        let builder = self.open(path).await?;
+
+        let schema = builder.schema().clone();
+        let batches = builder
+            .build()
+            .map_err(|err| Status::internal(err.to_string()))?
+            .map_err(|err| FlightError::ExternalError(Box::new(err)));
+        let stream = FlightDataEncoderBuilder::new()
+            .with_schema(schema)
+            .build(batches)
+            .map_err(Status::from);
+        Ok(Response::new(stream.boxed()))
+    }
+
+    async fn do_put(
+        &self,
+        _: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("do_put is not supported"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("do_exchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("do_action is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Err(Status::unimplemented("list_actions is not supported"))
+    }
+}
+
+/// Get the file path from a descriptor of type `PATH`.
+#[allow(clippy::result_large_err)]
+fn descriptor_path(desc: &FlightDescriptor) -> Result<String, Status> {
+    if desc.r#type != DescriptorType::Path as i32 {
+        return Err(Status::invalid_argument(
+            "only descriptors of type PATH are supported",
+        ));
+    }
+
+    Ok(desc.path.join("/"))
+}
+
+fn format_status(err: opendal::Error) -> Status {
+    match err.kind() {
+        ErrorKind::NotFound => Status::not_found(err.to_string()),
+        ErrorKind::PermissionDenied => Status::permission_denied(err.to_string()),
+        ErrorKind::IsADirectory | ErrorKind::InvalidInput => {
+            Status::invalid_argument(err.to_string())
+        }
+        _ => Status::internal(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::Int64Array;
+    use arrow_array::RecordBatch;
+    use arrow_array::StringArray;
+    use arrow_flight::decode::FlightRecordBatchStream;
+    use opendal::services;
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::properties::WriterProperties;
+
+    use super::*;
+
+    fn new_batch(start: i64) -> RecordBatch {
+        let ids: Vec<i64> = (start..start + 10).collect();
+        let names: Vec<String> = ids.iter().map(|v| format!("name-{v}")).collect();
+        RecordBatch::try_from_iter([
+            ("id", Arc::new(Int64Array::from(ids)) as _),
+            ("name", Arc::new(StringArray::from(names)) as _),
+        ])
+        .unwrap()
+    }
+
+    async fn new_service() -> OpendalFlightService {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+
+        // Write small row groups so that the file is streamed in batches.
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .build();
+        let mut buf = vec![];
+        let mut w = ArrowWriter::try_new(&mut buf, new_batch(0).schema(), Some(props)).unwrap();
+        for start in [0, 10, 20] {
+            w.write(&new_batch(start)).unwrap();
+        }
+        w.close().unwrap();
+        op.write("data.parquet", buf).await.unwrap();
+
+        OpendalFlightService::new(op)
+    }
+
+    #[tokio::test]
+    async fn test_do_get() {
+        let svc = new_service().await;
+
+        let stream = svc
+            .do_get(Request::new(Ticket::new("data.parquet")))
+            .await
+            .unwrap()
+            .into_inner();
+        let batches: Vec<RecordBatch> =
+            FlightRecordBatchStream::new_from_flight_data(stream.map_err(FlightError::from))
+                .try_collect()
+                .await
+                .unwrap();
+
+        assert_eq!(batches.len(), 3);
+        for (i, batch) in batches.iter().enumerate() {
+            assert_eq!(batch, &new_batch(i as i64 * 10));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_flight_info() {
+        let svc = new_service().await;
+
+        let desc = FlightDescriptor::new_path(vec!["data.parquet".to_string()]);
+        let info = svc
+            .get_flight_info(Request::new(desc))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(info.total_records, 30);
+        assert_eq!(info.endpoint[0].ticket, Some(Ticket::new("data.parquet")));
+
+        let schema = info.try_decode_schema().unwrap();
+        assert_eq!(&schema, new_batch(0).schema().as_ref());
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let svc = new_service().await;
+
+        let err = svc
+            .do_get(Request::new(Ticket::new("not_exist.parquet")))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+
+        let desc = FlightDescriptor::new_cmd("data.parquet");
+        let err = svc.get_schema(Request::new(desc)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+}
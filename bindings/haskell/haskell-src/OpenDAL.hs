@@ -97,6 +97,12 @@ data ErrorCode
     RateLimited
   | -- | The given file paths are same.
     IsSameFile
+  | -- | The quota of this operation has been exceeded.
+    QuotaExceeded
+  | -- | The content is invalid.
+    InvalidData
+  | -- | The file is locked by its retention.
+    ObjectLocked
   deriving (Eq, Show)
 
 -- | Represents an error that can occur when using OpenDAL.
@@ -109,7 +115,7 @@ data OpenDALError = OpenDALError
   deriving (Eq, Show)
 
 -- | Represents the mode of an entry in a storage system (e.g., file or directory).
-data EntryMode = File | Dir | Unknown deriving (Eq, Show)
+data EntryMode = File | Dir | Unknown | Symlink deriving (Eq, Show)
 
 -- | Represents metadata for an entry in a storage system.
 data Metadata = Metadata
@@ -230,11 +236,15 @@ parseErrorCode 8 = NotADirectory
 parseErrorCode 9 = AlreadyExists
 parseErrorCode 10 = RateLimited
 parseErrorCode 11 = IsSameFile
+parseErrorCode 12 = QuotaExceeded
+parseErrorCode 13 = InvalidData
+parseErrorCode 14 = ObjectLocked
 parseErrorCode _ = FFIError
 
 parseEntryMode :: Int -> EntryMode
 parseEntryMode 0 = File
 parseEntryMode 1 = Dir
+parseEntryMode 3 = Symlink
 parseEntryMode _ = Unknown
 
 parseCString :: CString -> IO (Maybe String)
@@ -44,6 +44,9 @@ pub enum FFIErrorCode {
     AlreadyExists,
     RateLimited,
     IsSameFile,
+    QuotaExceeded,
+    InvalidData,
+    ObjectLocked,
 }
 
 impl<T> FFIResult<T> {
@@ -88,6 +91,9 @@ impl From<od::ErrorKind> for FFIErrorCode {
             od::ErrorKind::AlreadyExists => FFIErrorCode::AlreadyExists,
             od::ErrorKind::RateLimited => FFIErrorCode::RateLimited,
             od::ErrorKind::IsSameFile => FFIErrorCode::IsSameFile,
+            od::ErrorKind::QuotaExceeded => FFIErrorCode::QuotaExceeded,
+            od::ErrorKind::InvalidData => FFIErrorCode::InvalidData,
+            od::ErrorKind::ObjectLocked => FFIErrorCode::ObjectLocked,
             _ => FFIErrorCode::Unexpected,
         }
     }
@@ -66,6 +66,7 @@ pub enum EntryMode {
     File,
     Dir,
     Unknown,
+    Symlink,
 }
 
 #[repr(C)]
@@ -87,6 +88,7 @@ impl From<od::Metadata> for Metadata {
             od::EntryMode::FILE => EntryMode::File,
             od::EntryMode::DIR => EntryMode::Dir,
             od::EntryMode::Unknown => EntryMode::Unknown,
+            od::EntryMode::SYMLINK => EntryMode::Symlink,
         };
 
         let cache_control = match val.cache_control() {
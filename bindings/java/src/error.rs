@@ -54,6 +54,9 @@ impl Error {
             ErrorKind::ConditionNotMatch => "ConditionNotMatch",
             ErrorKind::ContentTruncated => "ContentTruncated",
             ErrorKind::ContentIncomplete => "ContentIncomplete",
+            ErrorKind::QuotaExceeded => "QuotaExceeded",
+            ErrorKind::InvalidData => "InvalidData",
+            ErrorKind::ObjectLocked => "ObjectLocked",
             _ => "Unexpected",
         })?;
         let message = env.new_string(self.inner.to_string())?;
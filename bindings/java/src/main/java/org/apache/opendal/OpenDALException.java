@@ -73,5 +73,8 @@ public class OpenDALException extends RuntimeException {
         ConditionNotMatch,
         ContentTruncated,
         ContentIncomplete,
+        QuotaExceeded,
+        InvalidData,
+        ObjectLocked,
     }
 }
@@ -189,22 +189,22 @@ impl ObjectStore for OpendalStore {
         })
     }
 
-    async fn copy(&self, _from: &Path, _to: &Path) -> Result<()> {
-        Err(object_store::Error::NotSupported {
-            source: Box::new(opendal::Error::new(
-                opendal::ErrorKind::Unsupported,
-                "copy is not implemented so far",
-            )),
-        })
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner
+            .copy(from.as_ref(), to.as_ref())
+            .await
+            .map_err(|err| format_object_store_error(err, from.as_ref()))?;
+
+        Ok(())
     }
 
-    async fn rename(&self, _from: &Path, _to: &Path) -> Result<()> {
-        Err(object_store::Error::NotSupported {
-            source: Box::new(opendal::Error::new(
-                opendal::ErrorKind::Unsupported,
-                "rename is not implemented so far",
-            )),
-        })
+    async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner
+            .rename(from.as_ref(), to.as_ref())
+            .await
+            .map_err(|err| format_object_store_error(err, from.as_ref()))?;
+
+        Ok(())
     }
 
     async fn copy_if_not_exists(&self, _from: &Path, _to: &Path) -> Result<()> {
@@ -335,4 +335,22 @@ mod tests {
         assert_eq!(result.objects[0].location.as_ref(), "data/test.txt");
         assert_eq!(result.common_prefixes[0].as_ref(), "data/nested");
     }
+
+    #[tokio::test]
+    async fn test_copy_and_rename() {
+        let object_store = create_test_object_store().await;
+
+        let from: Path = "data/test.txt".try_into().unwrap();
+        let to: Path = "data/copied.txt".try_into().unwrap();
+        object_store.copy(&from, &to).await.unwrap();
+        let bs = object_store.get_range(&to, 0..5).await.unwrap();
+        assert_eq!(bs, Bytes::from_static(b"hello"));
+
+        let renamed: Path = "data/renamed.txt".try_into().unwrap();
+        object_store.rename(&to, &renamed).await.unwrap();
+        assert_eq!(object_store.head(&renamed).await.unwrap().size, 13);
+
+        let err = object_store.head(&to).await.unwrap_err();
+        assert!(matches!(err, object_store::Error::NotFound { .. }));
+    }
 }
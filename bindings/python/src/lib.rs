@@ -357,6 +357,7 @@ impl EntryMode {
         match self.0 {
             od::EntryMode::FILE => "EntryMode.FILE",
             od::EntryMode::DIR => "EntryMode.DIR",
+            od::EntryMode::SYMLINK => "EntryMode.SYMLINK",
             od::EntryMode::Unknown => "EntryMode.UNKNOWN",
         }
     }
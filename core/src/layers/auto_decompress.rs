@@ -0,0 +1,362 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::io;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use async_compression::futures::bufread::BzDecoder;
+use async_compression::futures::bufread::GzipDecoder;
+use async_compression::futures::bufread::ZstdDecoder;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::io::BufReader;
+use futures::AsyncRead;
+use log::debug;
+
+use crate::raw::*;
+use crate::*;
+
+/// Detect the compression format of objects by their magic bytes and
+/// decompress the content transparently while reading.
+///
+/// Supported formats:
+///
+/// - gzip: `1f 8b`
+/// - zstd: `28 b5 2f fd`
+/// - bzip2: `42 5a 68`
+///
+/// # Notes
+///
+/// - Only full reads will be decompressed. Range reads always return the
+///   raw bytes since a part of compressed content can't be decoded.
+/// - The first 4 bytes are peeked without being consumed, objects
+///   that don't match any magic are returned unchanged.
+/// - Content is decompressed while reading. The length of decompressed
+///   content is unknown until it's fully decoded, so the returned
+///   metadata won't have `content_length`.
+/// - Corrupt content will return [`ErrorKind::InvalidData`] while reading.
+/// - lz4 frames (`04 22 4d 18`) are detected but returned unchanged since
+///   the decoder is not available yet.
+/// - Blocking reads and `stat` are not affected.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::AutoDecompressLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(AutoDecompressLayer)
+///     .finish();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AutoDecompressLayer;
+
+impl<A: Accessor> Layer<A> for AutoDecompressLayer {
+    type LayeredAccessor = AutoDecompressAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        AutoDecompressAccessor { inner }
+    }
+}
+
+/// Compression formats that can be detected by magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompressionFormat {
+    Gzip,
+    Zstd,
+    Lz4,
+    Bzip2,
+}
+
+impl CompressionFormat {
+    /// Detect the compression format from the first bytes of content.
+    fn detect(bs: &[u8]) -> Option<Self> {
+        if bs.starts_with(&[0x1f, 0x8b]) {
+            Some(Self::Gzip)
+        } else if bs.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Some(Self::Zstd)
+        } else if bs.starts_with(&[0x04, 0x22, 0x4d, 0x18]) {
+            Some(Self::Lz4)
+        } else if bs.starts_with(b"BZh") {
+            Some(Self::Bzip2)
+        } else {
+            None
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+            Self::Lz4 => "lz4",
+            Self::Bzip2 => "bzip2",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AutoDecompressAccessor<A: Accessor> {
+    inner: A,
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for AutoDecompressAccessor<A> {
+    type Inner = A;
+    type Reader = oio::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Appender = A::Appender;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        if !args.range().is_full() {
+            return self
+                .inner
+                .read(path, args)
+                .await
+                .map(|(rp, r)| (rp, Box::new(r) as oio::Reader));
+        }
+
+        let (rp, r) = self.inner.read(path, args).await?;
+        let mut r = Reader::from_oio_reader(Box::new(r)).into_peekable_reader();
+
+        let format = match CompressionFormat::detect(r.peek(4).await?) {
+            None => return Ok((rp, Box::new(r))),
+            Some(CompressionFormat::Lz4) => {
+                debug!("lz4 decoder is not available, read {path} as is");
+                return Ok((rp, Box::new(r)));
+            }
+            Some(format) => format,
+        };
+
+        let r = BufReader::new(r);
+        let r: Pin<Box<dyn AsyncRead + Send + Sync>> = match format {
+            CompressionFormat::Gzip => Box::pin(GzipDecoder::new(r)),
+            CompressionFormat::Zstd => Box::pin(ZstdDecoder::new(r)),
+            CompressionFormat::Bzip2 => Box::pin(BzDecoder::new(r)),
+            CompressionFormat::Lz4 => unreachable!("lz4 must be returned as is"),
+        };
+        let r = DecompressReader {
+            inner: r,
+            path: path.to_string(),
+            format,
+        };
+
+        Ok((
+            RpRead::with_metadata(Metadata::new(EntryMode::FILE)),
+            Box::new(oio::into_streamable_reader(r, 256 * 1024)),
+        ))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// DecompressReader decodes the content of inner reader while reading.
+struct DecompressReader {
+    inner: Pin<Box<dyn AsyncRead + Send + Sync>>,
+    path: String,
+    format: CompressionFormat,
+}
+
+impl oio::Read for DecompressReader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        self.inner.as_mut().poll_read(cx, buf).map_err(|err| {
+            // Errors from the underlying reader are returned as is.
+            if err.get_ref().map_or(false, |e| e.is::<Error>()) {
+                let err = err.into_inner().expect("inner error must exist");
+                return *err.downcast::<Error>().expect("inner error must be Error");
+            }
+
+            Error::new(ErrorKind::InvalidData, "decompress content")
+                .with_operation(oio::ReadOperation::Read)
+                .with_context("path", &self.path)
+                .with_context("format", self.format.name())
+                .set_source(err)
+        })
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: io::SeekFrom) -> Poll<Result<u64>> {
+        let (_, _) = (cx, pos);
+
+        Poll::Ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "decompressed reader doesn't support seeking",
+        )))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        let _ = cx;
+
+        Poll::Ready(Some(Err(Error::new(
+            ErrorKind::Unsupported,
+            "decompressed reader doesn't support streaming",
+        ))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_compression::futures::bufread::BzEncoder;
+    use async_compression::futures::bufread::GzipEncoder;
+    use async_compression::futures::bufread::ZstdEncoder;
+    use futures::AsyncReadExt;
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    const CONTENT: &[u8] = b"Hello, World! Hello, World! Hello, World!";
+
+    async fn compress(format: CompressionFormat) -> Vec<u8> {
+        let mut buf = Vec::new();
+        match format {
+            CompressionFormat::Gzip => GzipEncoder::new(CONTENT).read_to_end(&mut buf).await,
+            CompressionFormat::Zstd => ZstdEncoder::new(CONTENT).read_to_end(&mut buf).await,
+            CompressionFormat::Bzip2 => BzEncoder::new(CONTENT).read_to_end(&mut buf).await,
+            CompressionFormat::Lz4 => unreachable!("lz4 encoder is not available"),
+        }
+        .expect("compress must succeed");
+        buf
+    }
+
+    #[tokio::test]
+    async fn test_auto_decompress() -> Result<()> {
+        let op = Operator::new(Memory::default())?
+            .layer(AutoDecompressLayer)
+            .finish();
+
+        for format in [
+            CompressionFormat::Gzip,
+            CompressionFormat::Zstd,
+            CompressionFormat::Bzip2,
+        ] {
+            let path = format!("file.{}", format.name());
+            let compressed = compress(format).await;
+            assert_eq!(CompressionFormat::detect(&compressed), Some(format));
+            op.write(&path, compressed.clone()).await?;
+
+            assert_eq!(op.read(&path).await?, CONTENT, "format: {format:?}");
+
+            let mut bs = vec![];
+            op.reader(&path)
+                .await?
+                .read_to_end(&mut bs)
+                .await
+                .map_err(|err| Error::new(ErrorKind::Unexpected, "read").set_source(err))?;
+            assert_eq!(bs, CONTENT, "format: {format:?}");
+
+            let chunks: Vec<Bytes> = op
+                .reader(&path)
+                .await?
+                .try_collect()
+                .await
+                .map_err(|err| Error::new(ErrorKind::Unexpected, "stream").set_source(err))?;
+            assert_eq!(chunks.concat(), CONTENT, "format: {format:?}");
+
+            // Range reads should return the raw bytes.
+            assert_eq!(op.range_read(&path, 0..4).await?, &compressed[..4]);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_auto_decompress_pass_through() -> Result<()> {
+        let op = Operator::new(Memory::default())?
+            .layer(AutoDecompressLayer)
+            .finish();
+
+        op.write("plain", CONTENT).await?;
+        assert_eq!(op.read("plain").await?, CONTENT);
+
+        let lz4 = [0x04, 0x22, 0x4d, 0x18, 0x64, 0x40, 0xa7];
+        op.write("file.lz4", lz4.to_vec()).await?;
+        assert_eq!(op.read("file.lz4").await?, lz4);
+
+        // Content shorter than magic bytes should be returned as is.
+        op.write("short", vec![0x1f]).await?;
+        assert_eq!(op.read("short").await?, [0x1f]);
+
+        op.write("empty", vec![]).await?;
+        assert!(op.read("empty").await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_auto_decompress_invalid_content() -> Result<()> {
+        let op = Operator::new(Memory::default())?
+            .layer(AutoDecompressLayer)
+            .finish();
+
+        let mut broken = compress(CompressionFormat::Zstd).await;
+        let n = broken.len();
+        broken[n / 2..].fill(0xff);
+
+        for (path, content) in [
+            ("broken.gz", vec![0x1f, 0x8b, 0x00, 0x00]),
+            ("broken.zst", broken),
+        ] {
+            op.write(path, content).await?;
+
+            let mut bs = vec![];
+            let err = op
+                .reader(path)
+                .await?
+                .read_to_end(&mut bs)
+                .await
+                .unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData, "path: {path}");
+        }
+        Ok(())
+    }
+}
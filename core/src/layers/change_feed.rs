@@ -0,0 +1,417 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use tokio::sync::broadcast;
+
+use crate::raw::*;
+use crate::*;
+
+/// ChangeEvent is a successful mutation sent by [`ChangeFeedLayer`].
+#[derive(Debug, Clone)]
+pub enum ChangeEvent {
+    /// A file that didn't exist has been written, or a dir has been
+    /// created.
+    Created {
+        /// Path of the created file or dir.
+        path: String,
+        /// Metadata with the mode and written bytes of the file.
+        metadata: Metadata,
+    },
+    /// An existing file has been overwritten.
+    Updated {
+        /// Path of the updated file.
+        path: String,
+        /// Metadata with the mode and written bytes of the file.
+        metadata: Metadata,
+    },
+    /// A file or dir has been deleted.
+    Deleted {
+        /// Path of the deleted file or dir.
+        path: String,
+    },
+}
+
+/// Send all successful mutations to subscribers via a broadcast channel.
+///
+/// # Notes
+///
+/// - Events are sent after `write` closed, `create_dir` and `delete`
+///   succeeded. Failed operations don't send events. Other mutations like
+///   `append`, `copy` and `rename` are not tracked.
+/// - Every `write` will `stat` the path first to decide whether it's
+///   [`ChangeEvent::Created`] or [`ChangeEvent::Updated`], which adds one
+///   extra request. The `write` will fail if `stat` fails with errors
+///   other than `NotFound`.
+/// - Subscribers which are too slow to receive events will get
+///   [`broadcast::error::RecvError::Lagged`], events are sent no matter
+///   whether there are subscribers or not.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::ChangeEvent;
+/// use opendal::layers::ChangeFeedLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let layer = ChangeFeedLayer::new(1024);
+/// let mut events = layer.subscribe();
+///
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(layer)
+///     .finish();
+/// op.write("file", "Hello").await?;
+///
+/// assert!(matches!(events.recv().await?, ChangeEvent::Created { .. }));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChangeFeedLayer {
+    sender: broadcast::Sender<ChangeEvent>,
+}
+
+impl ChangeFeedLayer {
+    /// Create a new ChangeFeedLayer which keeps at most `capacity` events
+    /// for every subscriber.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// Subscribe events sent after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<ChangeEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl<A: Accessor> Layer<A> for ChangeFeedLayer {
+    type LayeredAccessor = ChangeFeedAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        ChangeFeedAccessor {
+            inner,
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ChangeFeedAccessor<A: Accessor> {
+    inner: A,
+    sender: broadcast::Sender<ChangeEvent>,
+}
+
+impl<A: Accessor> ChangeFeedAccessor<A> {
+    fn send(&self, event: ChangeEvent) {
+        // Send only fails if there are no subscribers.
+        let _ = self.sender.send(event);
+    }
+
+    fn new_writer<W>(&self, path: &str, inner: W, existed: bool) -> ChangeFeedWriter<W> {
+        ChangeFeedWriter {
+            inner,
+            sender: self.sender.clone(),
+            path: path.to_string(),
+            existed,
+            written: 0,
+        }
+    }
+}
+
+/// Check whether the path existed via the result of stat, errors other
+/// than `NotFound` will be returned since the event can't be decided.
+fn existed(res: Result<RpStat>) -> Result<bool> {
+    match res {
+        Ok(_) => Ok(true),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for ChangeFeedAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = ChangeFeedWriter<A::Writer>;
+    type BlockingWriter = ChangeFeedWriter<A::BlockingWriter>;
+    type Appender = A::Appender;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let rp = self.inner.create_dir(path, args).await?;
+        self.send(ChangeEvent::Created {
+            path: path.to_string(),
+            metadata: Metadata::new(EntryMode::DIR),
+        });
+        Ok(rp)
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let existed = existed(self.inner.stat(path, OpStat::new()).await)?;
+
+        self.inner
+            .write(path, args)
+            .await
+            .map(|(rp, w)| (rp, self.new_writer(path, w, existed)))
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let rp = self.inner.delete(path, args).await?;
+        self.send(ChangeEvent::Deleted {
+            path: path.to_string(),
+        });
+        Ok(rp)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let rp = self.inner.blocking_create_dir(path, args)?;
+        self.send(ChangeEvent::Created {
+            path: path.to_string(),
+            metadata: Metadata::new(EntryMode::DIR),
+        });
+        Ok(rp)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let existed = existed(self.inner.blocking_stat(path, OpStat::new()))?;
+
+        self.inner
+            .blocking_write(path, args)
+            .map(|(rp, w)| (rp, self.new_writer(path, w, existed)))
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let rp = self.inner.blocking_delete(path, args)?;
+        self.send(ChangeEvent::Deleted {
+            path: path.to_string(),
+        });
+        Ok(rp)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+pub struct ChangeFeedWriter<W> {
+    inner: W,
+    sender: broadcast::Sender<ChangeEvent>,
+    path: String,
+    /// Whether the file existed before written.
+    existed: bool,
+    written: u64,
+}
+
+impl<W> ChangeFeedWriter<W> {
+    fn send(&self) {
+        let path = self.path.clone();
+        let metadata = Metadata::new(EntryMode::FILE).with_content_length(self.written);
+        let event = if self.existed {
+            ChangeEvent::Updated { path, metadata }
+        } else {
+            ChangeEvent::Created { path, metadata }
+        };
+
+        let _ = self.sender.send(event);
+    }
+}
+
+#[async_trait]
+impl<W: oio::Write> oio::Write for ChangeFeedWriter<W> {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        let size = bs.len() as u64;
+        self.inner.write(bs).await?;
+        self.written += size;
+        Ok(())
+    }
+
+    async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+        self.inner.sink(size, s).await?;
+        self.written += size;
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await?;
+        self.send();
+        Ok(())
+    }
+}
+
+impl<W: oio::BlockingWrite> oio::BlockingWrite for ChangeFeedWriter<W> {
+    fn write(&mut self, bs: Bytes) -> Result<()> {
+        let size = bs.len() as u64;
+        self.inner.write(bs)?;
+        self.written += size;
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()?;
+        self.send();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    /// MockService denies stat on all paths.
+    #[derive(Debug)]
+    struct MockService;
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = ();
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.capability_mut().stat = true;
+            am.capability_mut().write = true;
+            am
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            Err(Error::new(ErrorKind::PermissionDenied, "denied"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_change_feed() -> Result<()> {
+        let layer = ChangeFeedLayer::new(16);
+        let mut events = layer.subscribe();
+        let op = Operator::new(Memory::default())?.layer(layer).finish();
+
+        op.create_dir("dir/").await?;
+        op.write("dir/file", "Hello").await?;
+        op.write("dir/file", "Hello, World!").await?;
+        op.delete("dir/file").await?;
+        // Failed operations don't send events.
+        op.write("dir/", "Hello")
+            .await
+            .expect_err("write into dir must fail");
+
+        let mut received = vec![];
+        while let Ok(event) = events.try_recv() {
+            received.push(match event {
+                ChangeEvent::Created { path, metadata } => {
+                    format!("created {path} {}", metadata.content_length())
+                }
+                ChangeEvent::Updated { path, metadata } => {
+                    format!("updated {path} {}", metadata.content_length())
+                }
+                ChangeEvent::Deleted { path } => format!("deleted {path}"),
+            });
+        }
+        assert_eq!(
+            received,
+            vec![
+                "created dir/ 0",
+                "created dir/file 5",
+                "updated dir/file 13",
+                "deleted dir/file",
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_change_feed_subscribers() -> Result<()> {
+        let layer = ChangeFeedLayer::new(16);
+        let mut first = layer.subscribe();
+        let op = Operator::new(Memory::default())?
+            .layer(layer.clone())
+            .finish();
+
+        op.write("a", "Hello").await?;
+        let mut second = layer.subscribe();
+        op.blocking().delete("a")?;
+
+        assert!(matches!(
+            first.recv().await.expect("must receive"),
+            ChangeEvent::Created { .. }
+        ));
+        assert!(matches!(
+            first.recv().await.expect("must receive"),
+            ChangeEvent::Deleted { .. }
+        ));
+        // Subscribers only receive events sent after subscribed.
+        assert!(matches!(
+            second.recv().await.expect("must receive"),
+            ChangeEvent::Deleted { .. }
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_change_feed_stat_failed() {
+        let layer = ChangeFeedLayer::new(16);
+        let mut events = layer.subscribe();
+        let op = OperatorBuilder::new(MockService).layer(layer).finish();
+
+        let err = op
+            .write("file", "Hello")
+            .await
+            .expect_err("write must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(events.try_recv().is_err());
+    }
+}
@@ -246,6 +246,14 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
                     .with_operation("list"),
             );
         }
+        if args.prefix_filter().is_some() && !cap.list_with_prefix_filter {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "list with prefix filter is not supported",
+            )
+            .with_context("service", self.meta.scheme())
+            .with_operation("list"));
+        }
 
         let delimiter = args.delimiter();
 
@@ -295,6 +303,14 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
                     .with_operation("list"),
             );
         }
+        if args.prefix_filter().is_some() && !cap.list_with_prefix_filter {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "list with prefix filter is not supported",
+            )
+            .with_context("service", self.meta.scheme())
+            .with_operation("list"));
+        }
 
         let delimiter = args.delimiter();
 
@@ -370,6 +386,18 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         })
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        if self.meta.capability().exists {
+            return self.inner.object_exists(path).await;
+        }
+
+        match LayeredAccessor::stat(self, path, OpStat::new()).await {
+            Ok(_) => Ok(true),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let capability = self.meta.capability();
         if !capability.stat || !capability.blocking {
@@ -389,6 +417,12 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         if !capability.write {
             return new_capability_unsupported_error(Operation::Write);
         }
+        if args.if_none_match().is_some() && !capability.write_with_if_none_match {
+            return new_capability_unsupported_error(Operation::Write);
+        }
+        if args.expected_checksum().is_some() && !capability.write_with_expected_checksum {
+            return new_capability_unsupported_error(Operation::Write);
+        }
 
         let size = args.content_length();
         self.inner
@@ -402,6 +436,12 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         if !capability.write || !capability.blocking {
             return new_capability_unsupported_error(Operation::BlockingWrite);
         }
+        if args.if_none_match().is_some() && !capability.write_with_if_none_match {
+            return new_capability_unsupported_error(Operation::BlockingWrite);
+        }
+        if args.expected_checksum().is_some() && !capability.write_with_expected_checksum {
+            return new_capability_unsupported_error(Operation::BlockingWrite);
+        }
 
         let size = args.content_length();
         self.inner
@@ -484,6 +524,15 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         self.inner().rename(from, to, args).await
     }
 
+    async fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        let capability = self.meta.capability();
+        if !capability.symlink {
+            return new_capability_unsupported_error(Operation::CreateSymlink);
+        }
+
+        self.inner().create_symlink(src, dst).await
+    }
+
     fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
         let capability = self.meta.capability();
         if !capability.rename || !capability.blocking {
@@ -493,6 +542,15 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         self.inner().blocking_rename(from, to, args)
     }
 
+    fn blocking_create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        let capability = self.meta.capability();
+        if !capability.symlink || !capability.blocking {
+            return new_capability_unsupported_error(Operation::BlockingCreateSymlink);
+        }
+
+        self.inner().blocking_create_symlink(src, dst)
+    }
+
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
         let capability = self.meta.capability();
         if !capability.list {
@@ -502,6 +560,227 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         self.complete_list(path, args).await
     }
 
+    async fn list_versions(
+        &self,
+        path: &str,
+        args: OpListVersions,
+    ) -> Result<(RpListVersions, oio::Pager)> {
+        let capability = self.meta.capability();
+        if !capability.versioning {
+            return new_capability_unsupported_error(Operation::ListVersions);
+        }
+
+        self.inner.list_versions(path, args).await
+    }
+
+    async fn create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let capability = self.meta.capability();
+        if !capability.write_multipart {
+            return new_capability_unsupported_error(Operation::CreateMultipart);
+        }
+
+        self.inner.create_multipart(path, args).await
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        bs: Bytes,
+    ) -> Result<RpWriteMultipart> {
+        let capability = self.meta.capability();
+        if !capability.write_multipart {
+            return new_capability_unsupported_error(Operation::WriteMultipart);
+        }
+
+        self.inner.write_multipart(path, args, bs).await
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let capability = self.meta.capability();
+        if !capability.write_multipart {
+            return new_capability_unsupported_error(Operation::CompleteMultipart);
+        }
+
+        self.inner.complete_multipart(path, args).await
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let capability = self.meta.capability();
+        if !capability.write_multipart {
+            return new_capability_unsupported_error(Operation::AbortMultipart);
+        }
+
+        self.inner.abort_multipart(path, args).await
+    }
+
+    async fn touch(&self, path: &str, args: OpTouch) -> Result<RpTouch> {
+        let capability = self.meta.capability();
+        if !capability.touch {
+            return new_capability_unsupported_error(Operation::Touch);
+        }
+
+        self.inner.touch(path, args).await
+    }
+
+    async fn get_lifecycle_rules(&self, args: OpGetLifecycleRules) -> Result<RpGetLifecycleRules> {
+        let capability = self.meta.capability();
+        if !capability.lifecycle {
+            return new_capability_unsupported_error(Operation::GetLifecycleRules);
+        }
+
+        self.inner.get_lifecycle_rules(args).await
+    }
+
+    async fn set_lifecycle_rules(&self, args: OpSetLifecycleRules) -> Result<RpSetLifecycleRules> {
+        let capability = self.meta.capability();
+        if !capability.lifecycle {
+            return new_capability_unsupported_error(Operation::SetLifecycleRules);
+        }
+
+        self.inner.set_lifecycle_rules(args).await
+    }
+
+    async fn get_cors_config(&self, args: OpGetCorsConfig) -> Result<RpGetCorsConfig> {
+        let capability = self.meta.capability();
+        if !capability.cors {
+            return new_capability_unsupported_error(Operation::GetCorsConfig);
+        }
+
+        self.inner.get_cors_config(args).await
+    }
+
+    async fn set_cors_config(&self, args: OpSetCorsConfig) -> Result<RpSetCorsConfig> {
+        let capability = self.meta.capability();
+        if !capability.cors {
+            return new_capability_unsupported_error(Operation::SetCorsConfig);
+        }
+
+        self.inner.set_cors_config(args).await
+    }
+
+    async fn get_bucket_acl(&self, args: OpGetBucketAcl) -> Result<RpGetBucketAcl> {
+        let capability = self.meta.capability();
+        if !capability.bucket_acl {
+            return new_capability_unsupported_error(Operation::GetBucketAcl);
+        }
+
+        self.inner.get_bucket_acl(args).await
+    }
+
+    async fn set_bucket_acl(&self, args: OpSetBucketAcl) -> Result<RpSetBucketAcl> {
+        let capability = self.meta.capability();
+        if !capability.bucket_acl {
+            return new_capability_unsupported_error(Operation::SetBucketAcl);
+        }
+
+        self.inner.set_bucket_acl(args).await
+    }
+
+    async fn head(&self, path: &str, args: OpHead) -> Result<RpHead> {
+        let capability = self.meta.capability();
+        if !capability.stat {
+            return new_capability_unsupported_error(Operation::Head);
+        }
+
+        // Dirs are handled by stat, so that head and stat of the same dir
+        // always behave the same.
+        if path.ends_with('/') {
+            let meta = LayeredAccessor::stat(self, path, OpStat::new())
+                .await?
+                .into_metadata();
+            return Ok(RpHead::new(
+                meta.content_length_raw().unwrap_or_default(),
+                meta.etag_raw(),
+            ));
+        }
+
+        self.inner.head(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        let capability = self.meta.capability();
+        if !capability.tagging {
+            return new_capability_unsupported_error(Operation::GetTags);
+        }
+
+        self.inner.get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let capability = self.meta.capability();
+        if !capability.tagging {
+            return new_capability_unsupported_error(Operation::SetTags);
+        }
+
+        self.inner.set_tags(path, args).await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        let capability = self.meta.capability();
+        if !capability.update_metadata {
+            return new_capability_unsupported_error(Operation::UpdateMetadata);
+        }
+
+        self.inner.update_metadata(path, args).await
+    }
+
+    async fn get_object_lock(&self, path: &str, args: OpGetObjectLock) -> Result<RpGetObjectLock> {
+        let capability = self.meta.capability();
+        if !capability.object_lock {
+            return new_capability_unsupported_error(Operation::GetObjectLock);
+        }
+
+        self.inner.get_object_lock(path, args).await
+    }
+
+    async fn lock_object(&self, path: &str, args: OpLockObject) -> Result<RpLockObject> {
+        let capability = self.meta.capability();
+        if !capability.object_lock {
+            return new_capability_unsupported_error(Operation::LockObject);
+        }
+
+        self.inner.lock_object(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let capability = self.meta.capability();
+        if !capability.presign_post {
+            return new_capability_unsupported_error(Operation::PresignPost);
+        }
+
+        self.inner.presign_post(path, args).await
+    }
+
+    async fn get_restore_status(
+        &self,
+        path: &str,
+        args: OpGetRestoreStatus,
+    ) -> Result<RpGetRestoreStatus> {
+        let capability = self.meta.capability();
+        if !capability.restore_status {
+            return new_capability_unsupported_error(Operation::GetRestoreStatus);
+        }
+
+        self.inner.get_restore_status(path, args).await
+    }
+
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
         let capability = self.meta.capability();
         if !capability.list || !capability.blocking {
@@ -930,6 +1209,10 @@ mod tests {
             Ok(RpStat::new(Metadata::new(EntryMode::Unknown)))
         }
 
+        async fn head(&self, _: &str, _: OpHead) -> Result<RpHead> {
+            Ok(RpHead::new(1, Some("etag")))
+        }
+
         async fn read(&self, _: &str, _: OpRead) -> Result<(RpRead, Self::Reader)> {
             Ok((RpRead::new(0), ()))
         }
@@ -1018,4 +1301,35 @@ mod tests {
     capability_test!(presign, |op| {
         op.presign_read("/path/to/mock_file", Duration::from_secs(1))
     });
+
+    #[tokio::test]
+    async fn test_head_dir_via_stat() -> Result<()> {
+        let builder = MockBuilder::default().with_capacity(Capability {
+            stat: true,
+            ..Default::default()
+        });
+        let op = Operator::new(builder)?.finish();
+
+        assert_eq!(op.head("file").await?, (1, Some("etag".to_string())));
+        assert_eq!(op.head("dir/").await?, (0, None));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_default_ensure_dir() -> Result<()> {
+        let srv = MockService {
+            capability: Capability::default(),
+        };
+        let err = srv.ensure_dir("dir/file").await.expect_err("must fail");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        // Dirs are virtual, nothing needs to be created.
+        let srv = MockService {
+            capability: Capability {
+                directory_create: true,
+                ..Default::default()
+            },
+        };
+        srv.ensure_dir("dir/file").await
+    }
 }
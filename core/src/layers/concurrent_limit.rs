@@ -156,6 +156,16 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
         self.inner.stat(path, args).await
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        let _permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("semaphore must be valid");
+
+        self.inner.object_exists(path).await
+    }
+
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let _permit = self
             .semaphore
@@ -94,6 +94,17 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
             .await
     }
 
+    async fn ensure_dir(&self, path: &str) -> Result<()> {
+        self.inner
+            .ensure_dir(path)
+            .map_err(|err| {
+                err.with_operation(Operation::EnsureDir)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let br = args.range();
 
@@ -184,6 +195,18 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
             .await
     }
 
+    async fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        self.inner
+            .create_symlink(src, dst)
+            .map_err(|err| {
+                err.with_operation(Operation::CreateSymlink)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("src", src)
+                    .with_context("dst", dst)
+            })
+            .await
+    }
+
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         self.inner
             .stat(path, args)
@@ -195,6 +218,17 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
             .await
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        self.inner
+            .object_exists(path)
+            .map_err(|err| {
+                err.with_operation(Operation::ObjectExists)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.inner
             .delete(path, args)
@@ -227,6 +261,257 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
             .await
     }
 
+    async fn list_versions(
+        &self,
+        path: &str,
+        args: OpListVersions,
+    ) -> Result<(RpListVersions, oio::Pager)> {
+        self.inner
+            .list_versions(path, args)
+            .map_ok(|(rp, os)| {
+                let pager: oio::Pager = Box::new(ErrorContextWrapper {
+                    scheme: self.meta.scheme(),
+                    path: path.to_string(),
+                    inner: os,
+                });
+                (rp, pager)
+            })
+            .map_err(|err| {
+                err.with_operation(Operation::ListVersions)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        self.inner
+            .create_multipart(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::CreateMultipart)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        bs: Bytes,
+    ) -> Result<RpWriteMultipart> {
+        self.inner
+            .write_multipart(path, args, bs)
+            .map_err(|err| {
+                err.with_operation(Operation::WriteMultipart)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.inner
+            .complete_multipart(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::CompleteMultipart)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        self.inner
+            .abort_multipart(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::AbortMultipart)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn touch(&self, path: &str, args: OpTouch) -> Result<RpTouch> {
+        self.inner
+            .touch(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::Touch)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn get_lifecycle_rules(&self, args: OpGetLifecycleRules) -> Result<RpGetLifecycleRules> {
+        self.inner
+            .get_lifecycle_rules(args)
+            .map_err(|err| {
+                err.with_operation(Operation::GetLifecycleRules)
+                    .with_context("service", self.meta.scheme())
+            })
+            .await
+    }
+
+    async fn set_lifecycle_rules(&self, args: OpSetLifecycleRules) -> Result<RpSetLifecycleRules> {
+        self.inner
+            .set_lifecycle_rules(args)
+            .map_err(|err| {
+                err.with_operation(Operation::SetLifecycleRules)
+                    .with_context("service", self.meta.scheme())
+            })
+            .await
+    }
+
+    async fn get_cors_config(&self, args: OpGetCorsConfig) -> Result<RpGetCorsConfig> {
+        self.inner
+            .get_cors_config(args)
+            .map_err(|err| {
+                err.with_operation(Operation::GetCorsConfig)
+                    .with_context("service", self.meta.scheme())
+            })
+            .await
+    }
+
+    async fn set_cors_config(&self, args: OpSetCorsConfig) -> Result<RpSetCorsConfig> {
+        self.inner
+            .set_cors_config(args)
+            .map_err(|err| {
+                err.with_operation(Operation::SetCorsConfig)
+                    .with_context("service", self.meta.scheme())
+            })
+            .await
+    }
+
+    async fn get_bucket_acl(&self, args: OpGetBucketAcl) -> Result<RpGetBucketAcl> {
+        self.inner
+            .get_bucket_acl(args)
+            .map_err(|err| {
+                err.with_operation(Operation::GetBucketAcl)
+                    .with_context("service", self.meta.scheme())
+            })
+            .await
+    }
+
+    async fn set_bucket_acl(&self, args: OpSetBucketAcl) -> Result<RpSetBucketAcl> {
+        self.inner
+            .set_bucket_acl(args)
+            .map_err(|err| {
+                err.with_operation(Operation::SetBucketAcl)
+                    .with_context("service", self.meta.scheme())
+            })
+            .await
+    }
+
+    async fn head(&self, path: &str, args: OpHead) -> Result<RpHead> {
+        self.inner
+            .head(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::Head)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.inner
+            .get_tags(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::GetTags)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        self.inner
+            .set_tags(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::SetTags)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        self.inner
+            .update_metadata(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::UpdateMetadata)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn get_object_lock(&self, path: &str, args: OpGetObjectLock) -> Result<RpGetObjectLock> {
+        self.inner
+            .get_object_lock(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::GetObjectLock)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn lock_object(&self, path: &str, args: OpLockObject) -> Result<RpLockObject> {
+        self.inner
+            .lock_object(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::LockObject)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.inner
+            .presign_post(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::PresignPost)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn get_restore_status(
+        &self,
+        path: &str,
+        args: OpGetRestoreStatus,
+    ) -> Result<RpGetRestoreStatus> {
+        self.inner
+            .get_restore_status(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::GetRestoreStatus)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         self.inner.presign(path, args).await.map_err(|err| {
             err.with_operation(Operation::Presign)
@@ -327,6 +612,15 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
         })
     }
 
+    fn blocking_create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        self.inner.blocking_create_symlink(src, dst).map_err(|err| {
+            err.with_operation(Operation::BlockingCreateSymlink)
+                .with_context("service", self.meta.scheme())
+                .with_context("src", src)
+                .with_context("dst", dst)
+        })
+    }
+
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         self.inner.blocking_stat(path, args).map_err(|err| {
             err.with_operation(Operation::BlockingStat)
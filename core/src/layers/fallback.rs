@@ -0,0 +1,347 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use async_trait::async_trait;
+use log::warn;
+
+use crate::raw::*;
+use crate::*;
+
+/// Add a secondary [`Operator`] to fall back to while the primary one
+/// is unavailable.
+///
+/// # Notes
+///
+/// - Only `read`, `stat`, `head` and `object_exists` will fall back to
+///   the secondary operator. Other operations like `write` and `delete` always
+///   use the primary one, so that writes will not be redirected silently.
+/// - Fallback happens only if the kind of the primary error is in
+///   `fallback_on`, which is [`ErrorKind::Unexpected`] and
+///   [`ErrorKind::RateLimited`] by default. Network errors like timeouts
+///   and refused connections are returned as [`ErrorKind::Unexpected`].
+/// - If both operators failed, the error of the secondary one will be
+///   returned with the primary error in its context.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::FallbackLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # fn main() -> Result<()> {
+/// let replica = Operator::new(services::Memory::default())?.finish();
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(FallbackLayer::new(replica))
+///     .finish();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FallbackLayer {
+    secondary: FusedAccessor,
+    fallback_on: Vec<ErrorKind>,
+}
+
+impl FallbackLayer {
+    /// Create a new FallbackLayer which falls back to `secondary`.
+    pub fn new(secondary: Operator) -> Self {
+        Self {
+            secondary: secondary.into(),
+            fallback_on: vec![ErrorKind::Unexpected, ErrorKind::RateLimited],
+        }
+    }
+
+    /// Set the error kinds of the primary operator that will trigger
+    /// fallback.
+    pub fn with_fallback_on(mut self, kinds: impl IntoIterator<Item = ErrorKind>) -> Self {
+        self.fallback_on = kinds.into_iter().collect();
+        self
+    }
+}
+
+impl Debug for FallbackLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FallbackLayer")
+            .field("secondary", &self.secondary.info())
+            .field("fallback_on", &self.fallback_on)
+            .finish()
+    }
+}
+
+impl<A: Accessor> Layer<A> for FallbackLayer {
+    type LayeredAccessor = FallbackAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        FallbackAccessor {
+            inner,
+            secondary: self.secondary.clone(),
+            fallback_on: self.fallback_on.clone(),
+        }
+    }
+}
+
+pub struct FallbackAccessor<A: Accessor> {
+    inner: A,
+    secondary: FusedAccessor,
+    fallback_on: Vec<ErrorKind>,
+}
+
+impl<A: Accessor> Debug for FallbackAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FallbackAccessor")
+            .field("inner", &self.inner)
+            .field("secondary", &self.secondary.info())
+            .field("fallback_on", &self.fallback_on)
+            .finish()
+    }
+}
+
+impl<A: Accessor> FallbackAccessor<A> {
+    fn should_fallback(&self, err: &Error) -> bool {
+        self.fallback_on.contains(&err.kind())
+    }
+
+    fn on_fallback(&self, op: Operation, path: &str, err: &Error) {
+        warn!(
+            target: "opendal::layers::fallback",
+            "service={} operation={} path={} -> primary failed, fallback to {}: {}",
+            self.inner.info().scheme(),
+            op,
+            path,
+            self.secondary.info().scheme(),
+            err,
+        );
+    }
+}
+
+/// Annotate the secondary error with context of the primary one.
+fn both_failed(primary: Error, secondary: Error) -> Error {
+    secondary.with_context("primary_error", primary.to_string())
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for FallbackAccessor<A> {
+    type Inner = A;
+    type Reader = oio::Reader;
+    type BlockingReader = oio::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Appender = A::Appender;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let err = match self.inner.read(path, args.clone()).await {
+            Ok((rp, r)) => return Ok((rp, Box::new(r) as oio::Reader)),
+            Err(err) if self.should_fallback(&err) => err,
+            Err(err) => return Err(err),
+        };
+
+        self.on_fallback(Operation::Read, path, &err);
+        self.secondary
+            .read(path, args)
+            .await
+            .map_err(|e| both_failed(err, e))
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let err = match self.inner.stat(path, args.clone()).await {
+            Ok(rp) => return Ok(rp),
+            Err(err) if self.should_fallback(&err) => err,
+            Err(err) => return Err(err),
+        };
+
+        self.on_fallback(Operation::Stat, path, &err);
+        self.secondary
+            .stat(path, args)
+            .await
+            .map_err(|e| both_failed(err, e))
+    }
+
+    async fn head(&self, path: &str, args: OpHead) -> Result<RpHead> {
+        let err = match self.inner.head(path, args.clone()).await {
+            Ok(rp) => return Ok(rp),
+            Err(err) if self.should_fallback(&err) => err,
+            Err(err) => return Err(err),
+        };
+
+        self.on_fallback(Operation::Head, path, &err);
+        self.secondary
+            .head(path, args)
+            .await
+            .map_err(|e| both_failed(err, e))
+    }
+
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        let err = match self.inner.object_exists(path).await {
+            Ok(v) => return Ok(v),
+            Err(err) if self.should_fallback(&err) => err,
+            Err(err) => return Err(err),
+        };
+
+        self.on_fallback(Operation::ObjectExists, path, &err);
+        self.secondary
+            .object_exists(path)
+            .await
+            .map_err(|e| both_failed(err, e))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let err = match self.inner.blocking_read(path, args.clone()) {
+            Ok((rp, r)) => return Ok((rp, Box::new(r) as oio::BlockingReader)),
+            Err(err) if self.should_fallback(&err) => err,
+            Err(err) => return Err(err),
+        };
+
+        self.on_fallback(Operation::BlockingRead, path, &err);
+        self.secondary
+            .blocking_read(path, args)
+            .map_err(|e| both_failed(err, e))
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let err = match self.inner.blocking_stat(path, args.clone()) {
+            Ok(rp) => return Ok(rp),
+            Err(err) if self.should_fallback(&err) => err,
+            Err(err) => return Err(err),
+        };
+
+        self.on_fallback(Operation::BlockingStat, path, &err);
+        self.secondary
+            .blocking_stat(path, args)
+            .map_err(|e| both_failed(err, e))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    /// MockService is a primary service whose read, stat and head always fail.
+    #[derive(Debug)]
+    struct MockService(ErrorKind);
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = oio::Reader;
+        type BlockingReader = oio::BlockingReader;
+        type Writer = oio::Writer;
+        type BlockingWriter = oio::BlockingWriter;
+        type Appender = oio::Appender;
+        type Pager = oio::Pager;
+        type BlockingPager = oio::BlockingPager;
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.capability_mut().read = true;
+            am.capability_mut().stat = true;
+            am.capability_mut().write = true;
+            am
+        }
+
+        async fn read(&self, _: &str, _: OpRead) -> Result<(RpRead, Self::Reader)> {
+            Err(Error::new(self.0, "primary is unavailable"))
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            Err(Error::new(self.0, "primary is unavailable"))
+        }
+
+        async fn write(&self, _: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+            Err(Error::new(self.0, "primary is unavailable"))
+        }
+    }
+
+    fn new_operator(kind: ErrorKind, secondary: Operator) -> Operator {
+        OperatorBuilder::new(MockService(kind))
+            .layer(FallbackLayer::new(secondary))
+            .finish()
+    }
+
+    #[tokio::test]
+    async fn test_fallback() -> Result<()> {
+        let secondary = Operator::new(Memory::default())?.finish();
+        secondary.write("file", "Hello, World!").await?;
+
+        let op = new_operator(ErrorKind::Unexpected, secondary.clone());
+        assert_eq!(op.read("file").await?, b"Hello, World!");
+        assert_eq!(op.stat("file").await?.content_length(), 13);
+        assert_eq!(op.head("file").await?.0, 13);
+
+        // Writes should never be redirected.
+        let err = op
+            .write("new", "Hello")
+            .await
+            .expect_err("must not fall back");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(!secondary.is_exist("new").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fallback_both_failed() -> Result<()> {
+        let secondary = Operator::new(Memory::default())?.finish();
+
+        let op = new_operator(ErrorKind::Unexpected, secondary);
+        let err = op.stat("file").await.expect_err("both must fail");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert!(err.to_string().contains("primary is unavailable"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_no_fallback() -> Result<()> {
+        let secondary = Operator::new(Memory::default())?.finish();
+        secondary.write("file", "Hello, World!").await?;
+
+        let op = new_operator(ErrorKind::PermissionDenied, secondary);
+        let err = op.stat("file").await.expect_err("must not fall back");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        Ok(())
+    }
+}
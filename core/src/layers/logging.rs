@@ -505,6 +505,44 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
             })
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.scheme,
+            Operation::ObjectExists,
+            path
+        );
+
+        self.inner
+            .object_exists(path)
+            .await
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} -> finished: {v}",
+                    self.scheme,
+                    Operation::ObjectExists,
+                    path
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.err_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} -> {}: {err:?}",
+                        self.scheme,
+                        Operation::ObjectExists,
+                        path,
+                        self.err_status(&err)
+                    );
+                };
+                err
+            })
+    }
+
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         debug!(
             target: LOGGING_TARGET,
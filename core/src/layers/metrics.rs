@@ -153,6 +153,9 @@ struct MetricsHandler {
     requests_total_stat: Counter,
     requests_duration_seconds_stat: Histogram,
 
+    requests_total_object_exists: Counter,
+    requests_duration_seconds_object_exists: Histogram,
+
     requests_total_delete: Counter,
     requests_duration_seconds_delete: Histogram,
 
@@ -257,6 +260,17 @@ impl MetricsHandler {
                 LABEL_OPERATION => Operation::Stat.into_static(),
             ),
 
+            requests_total_object_exists: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::ObjectExists.into_static(),
+            ),
+            requests_duration_seconds_object_exists: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::ObjectExists.into_static(),
+            ),
+
             requests_total_delete: register_counter!(
                 METRIC_REQUESTS_TOTAL,
                 LABEL_SERVICE => service,
@@ -533,6 +547,27 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
             .await
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        self.handle.requests_total_object_exists.increment(1);
+
+        let start = Instant::now();
+
+        self.inner
+            .object_exists(path)
+            .inspect_ok(|_| {
+                let dur = start.elapsed().as_secs_f64();
+
+                self.handle
+                    .requests_duration_seconds_object_exists
+                    .record(dur);
+            })
+            .inspect_err(|e| {
+                self.handle
+                    .increment_errors_total(Operation::ObjectExists, e.kind());
+            })
+            .await
+    }
+
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.handle.requests_total_delete.increment(1);
 
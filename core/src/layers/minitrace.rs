@@ -210,6 +210,11 @@ impl<A: Accessor> LayeredAccessor for MinitraceAccessor<A> {
         self.inner.stat(path, args).await
     }
 
+    #[trace(enter_on_poll = true)]
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        self.inner.object_exists(path).await
+    }
+
     #[trace(enter_on_poll = true)]
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.inner.delete(path, args).await
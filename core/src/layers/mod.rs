@@ -17,9 +17,16 @@
 
 //! `Layer` is the mechanism to intercept operations.
 
+mod change_feed;
+pub use change_feed::ChangeEvent;
+pub use change_feed::ChangeFeedLayer;
+
 mod concurrent_limit;
 pub use concurrent_limit::ConcurrentLimitLayer;
 
+mod fallback;
+pub use fallback::FallbackLayer;
+
 mod immutable_index;
 pub use immutable_index::ImmutableIndexLayer;
 
@@ -29,6 +36,13 @@ pub use logging::LoggingLayer;
 mod timeout;
 pub use timeout::TimeoutLayer;
 
+mod quota;
+pub use quota::QuotaLayer;
+
+mod stats;
+pub use stats::OperationStats;
+pub use stats::StatsLayer;
+
 #[cfg(feature = "layers-chaos")]
 mod chaos;
 #[cfg(feature = "layers-chaos")]
@@ -84,3 +98,13 @@ mod throttle;
 pub use self::oteltrace::OtelTraceLayer;
 #[cfg(feature = "layers-throttle")]
 pub use self::throttle::ThrottleLayer;
+
+#[cfg(feature = "layers-small-object-cache")]
+mod small_object_cache;
+#[cfg(feature = "layers-small-object-cache")]
+pub use self::small_object_cache::SmallObjectCacheLayer;
+
+#[cfg(feature = "layers-auto-decompress")]
+mod auto_decompress;
+#[cfg(feature = "layers-auto-decompress")]
+pub use self::auto_decompress::AutoDecompressLayer;
@@ -149,6 +149,14 @@ impl<A: Accessor> LayeredAccessor for OtelTraceAccessor<A> {
         self.inner().stat(path, args).with_context(cx).await
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        let tracer = global::tracer("opendal");
+        let mut span = tracer.start("object_exists");
+        span.set_attribute(KeyValue::new("path", path.to_string()));
+        let cx = Context::current_with_span(span);
+        self.inner().object_exists(path).with_context(cx).await
+    }
+
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let tracer = global::tracer("opendal");
         let mut span = tracer.start("delete");
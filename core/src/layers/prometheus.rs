@@ -238,10 +238,13 @@ impl<A: Accessor> LayeredAccessor for PrometheusAccessor<A> {
             .read(path, args)
             .map(|v| {
                 v.map(|(rp, r)| {
-                    self.stats
-                        .bytes_total
-                        .with_label_values(&[&self.scheme, Operation::Read.into_static()])
-                        .observe(rp.metadata().content_length() as f64);
+                    // Length of some readers like decompressed ones is unknown.
+                    if rp.metadata().bit().contains(Metakey::ContentLength) {
+                        self.stats
+                            .bytes_total
+                            .with_label_values(&[&self.scheme, Operation::Read.into_static()])
+                            .observe(rp.metadata().content_length() as f64);
+                    }
                     (
                         rp,
                         PrometheusMetricWrapper::new(
@@ -327,6 +330,26 @@ impl<A: Accessor> LayeredAccessor for PrometheusAccessor<A> {
         })
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        self.stats
+            .requests_total
+            .with_label_values(&[&self.scheme, Operation::ObjectExists.into_static()])
+            .inc();
+        let timer = self
+            .stats
+            .requests_duration_seconds
+            .with_label_values(&[&self.scheme, Operation::ObjectExists.into_static()])
+            .start_timer();
+
+        let res = self.inner.object_exists(path).await;
+        timer.observe_duration();
+        res.map_err(|e| {
+            self.stats
+                .increment_errors_total(Operation::ObjectExists, e.kind());
+            e
+        })
+    }
+
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.stats
             .requests_total
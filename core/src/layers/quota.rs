@@ -0,0 +1,494 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// Add a quota of total written bytes for [`Operator`].
+///
+/// # Notes
+///
+/// - Writes that exceed the quota will fail with [`ErrorKind::QuotaExceeded`].
+///   If the content length is known, the check happens before sending any data.
+///   Otherwise it happens before every chunk is written.
+/// - Bytes are reserved before they are written, so concurrent writers can't
+///   exceed the quota together. Reserved bytes are released if the write is
+///   aborted or not closed successfully. Appended bytes are reserved before
+///   every append.
+/// - Files to be overwritten are `stat`ed before writing, their bytes are
+///   counted as available for the new content and reclaimed after the write
+///   has been closed.
+/// - Bytes of deleted files are reclaimed via a `stat` before `delete`.
+///   Files removed by `batch`, and files created by `copy` are not tracked.
+///
+/// QuotaLayer is cheap to clone, all clones share the same used bytes.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::QuotaLayer;
+/// use opendal::services;
+/// use opendal::ErrorKind;
+/// use opendal::Operator;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// // Used bytes could be loaded from a database.
+/// let layer = QuotaLayer::new(16, 8);
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(layer.clone())
+///     .finish();
+///
+/// op.write("a", "Hello").await?;
+/// assert_eq!(layer.used(), 13);
+///
+/// let err = op.write("b", "World").await.unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::QuotaExceeded);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuotaLayer {
+    max_bytes: u64,
+    used: Arc<AtomicU64>,
+}
+
+impl QuotaLayer {
+    /// Create a new QuotaLayer which allows at most `max_bytes` in total.
+    ///
+    /// `used_bytes` is the bytes that have been used before.
+    pub fn new(max_bytes: u64, used_bytes: u64) -> Self {
+        Self {
+            max_bytes,
+            used: Arc::new(AtomicU64::new(used_bytes)),
+        }
+    }
+
+    /// Get the max bytes allowed by this quota.
+    pub fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    /// Get the bytes that have been used, including the bytes reserved by
+    /// ongoing writes.
+    pub fn used(&self) -> u64 {
+        self.used.load(Ordering::Relaxed)
+    }
+}
+
+impl<A: Accessor> Layer<A> for QuotaLayer {
+    type LayeredAccessor = QuotaAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        QuotaAccessor {
+            inner,
+            quota: Quota {
+                max_bytes: self.max_bytes,
+                used: self.used.clone(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Quota {
+    max_bytes: u64,
+    used: Arc<AtomicU64>,
+}
+
+impl Quota {
+    /// Reserve `size` bytes, check and update happen in one step so that
+    /// concurrent reservations can't exceed the quota.
+    fn reserve(&self, size: u64) -> Result<()> {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(size).filter(|v| *v <= self.max_bytes)
+            })
+            .map(|_| ())
+            .map_err(|used| {
+                Error::new(ErrorKind::QuotaExceeded, "write exceeds quota")
+                    .with_context("max_bytes", self.max_bytes.to_string())
+                    .with_context("used_bytes", used.to_string())
+                    .with_context("write_bytes", size.to_string())
+            })
+    }
+
+    fn release(&self, size: u64) {
+        let _ = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+                Some(v.saturating_sub(size))
+            });
+    }
+
+    /// Get the size of file at path which will be reclaimed after delete.
+    fn reclaimable(rp: Result<RpStat>) -> u64 {
+        match rp {
+            Ok(rp) => {
+                let meta = rp.into_metadata();
+                if meta.is_file() {
+                    meta.content_length_raw().unwrap_or_default()
+                } else {
+                    0
+                }
+            }
+            Err(_) => 0,
+        }
+    }
+}
+
+/// Reservation tracks the bytes reserved by a write.
+///
+/// Bytes of the overwritten file are available for the new content
+/// without reserving again. Reserved bytes will be released while dropped
+/// unless the reservation is committed.
+struct Reservation {
+    quota: Quota,
+    /// Bytes of new content that can be written without reserving more,
+    /// including the bytes of the overwritten file.
+    allowance: u64,
+    /// Bytes reserved in quota by this reservation.
+    reserved: u64,
+}
+
+impl Reservation {
+    fn new(quota: Quota, reclaimable: u64) -> Self {
+        Self {
+            quota,
+            allowance: reclaimable,
+            reserved: 0,
+        }
+    }
+
+    /// Make sure `total` bytes of new content can be written.
+    fn reserve_to(&mut self, total: u64) -> Result<()> {
+        let need = total.saturating_sub(self.allowance);
+        if need > 0 {
+            self.quota.reserve(need)?;
+            self.reserved += need;
+            self.allowance += need;
+        }
+        Ok(())
+    }
+
+    /// Commit the reservation with `written` bytes of new content, the
+    /// remaining allowance will be released.
+    fn commit(&mut self, written: u64) {
+        self.quota.release(self.allowance.saturating_sub(written));
+        self.allowance = 0;
+        self.reserved = 0;
+    }
+
+    /// Release all bytes reserved by this reservation.
+    fn release(&mut self) {
+        self.quota.release(self.reserved);
+        self.allowance = self.allowance.saturating_sub(self.reserved);
+        self.reserved = 0;
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        self.release()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuotaAccessor<A: Accessor> {
+    inner: A,
+    quota: Quota,
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for QuotaAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = QuotaWrapper<A::Writer>;
+    type BlockingWriter = QuotaWrapper<A::BlockingWriter>;
+    type Appender = QuotaWrapper<A::Appender>;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let size = Quota::reclaimable(self.inner.stat(path, OpStat::new()).await);
+        let mut reservation = Reservation::new(self.quota.clone(), size);
+        if let Some(size) = args.content_length() {
+            reservation.reserve_to(size)?;
+        }
+
+        self.inner
+            .write(path, args)
+            .await
+            .map(|(rp, w)| (rp, QuotaWrapper::new(w, reservation)))
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        let reservation = Reservation::new(self.quota.clone(), 0);
+
+        self.inner
+            .append(path, args)
+            .await
+            .map(|(rp, a)| (rp, QuotaWrapper::new(a, reservation)))
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let size = Quota::reclaimable(self.inner.stat(path, OpStat::new()).await);
+
+        let rp = self.inner.delete(path, args).await?;
+        self.quota.release(size);
+        Ok(rp)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let size = Quota::reclaimable(self.inner.blocking_stat(path, OpStat::new()));
+        let mut reservation = Reservation::new(self.quota.clone(), size);
+        if let Some(size) = args.content_length() {
+            reservation.reserve_to(size)?;
+        }
+
+        self.inner
+            .blocking_write(path, args)
+            .map(|(rp, w)| (rp, QuotaWrapper::new(w, reservation)))
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let size = Quota::reclaimable(self.inner.blocking_stat(path, OpStat::new()));
+
+        let rp = self.inner.blocking_delete(path, args)?;
+        self.quota.release(size);
+        Ok(rp)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+pub struct QuotaWrapper<R> {
+    inner: R,
+    reservation: Reservation,
+    /// Bytes that have been written but not committed into quota yet.
+    written: u64,
+}
+
+impl<R> QuotaWrapper<R> {
+    fn new(inner: R, reservation: Reservation) -> Self {
+        Self {
+            inner,
+            reservation,
+            written: 0,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: oio::Write> oio::Write for QuotaWrapper<R> {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        let size = bs.len() as u64;
+        self.reservation.reserve_to(self.written + size)?;
+
+        self.inner.write(bs).await?;
+        self.written += size;
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await?;
+        self.reservation.release();
+        self.written = 0;
+        Ok(())
+    }
+
+    async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+        self.reservation.reserve_to(self.written + size)?;
+
+        self.inner.sink(size, s).await?;
+        self.written += size;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        if let Err(err) = self.inner.close().await {
+            self.reservation.release();
+            return Err(err);
+        }
+        self.reservation.commit(self.written);
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl<R: oio::BlockingWrite> oio::BlockingWrite for QuotaWrapper<R> {
+    fn write(&mut self, bs: Bytes) -> Result<()> {
+        let size = bs.len() as u64;
+        self.reservation.reserve_to(self.written + size)?;
+
+        self.inner.write(bs)?;
+        self.written += size;
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        if let Err(err) = self.inner.close() {
+            self.reservation.release();
+            return Err(err);
+        }
+        self.reservation.commit(self.written);
+        self.written = 0;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: oio::Append> oio::Append for QuotaWrapper<R> {
+    async fn append(&mut self, bs: Bytes) -> Result<()> {
+        let size = bs.len() as u64;
+        self.reservation.reserve_to(size)?;
+
+        if let Err(err) = self.inner.append(bs).await {
+            self.reservation.release();
+            return Err(err);
+        }
+        self.reservation.commit(size);
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_quota() -> Result<()> {
+        let layer = QuotaLayer::new(10, 2);
+        let op = Operator::new(Memory::default())?
+            .layer(layer.clone())
+            .finish();
+
+        op.write("a", "Hello").await?;
+        assert_eq!(layer.used(), 7);
+
+        let err = op.write("b", "World").await.expect_err("must exceed quota");
+        assert_eq!(err.kind(), ErrorKind::QuotaExceeded);
+        assert!(!op.is_exist("b").await?);
+        assert_eq!(layer.used(), 7);
+
+        op.delete("a").await?;
+        assert_eq!(layer.used(), 2);
+
+        // Delete a not exist file should not change used bytes.
+        op.delete("a").await?;
+        assert_eq!(layer.used(), 2);
+
+        op.write("b", "World").await?;
+        assert_eq!(layer.used(), 7);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_quota_writer() -> Result<()> {
+        let layer = QuotaLayer::new(8, 0);
+        let op = Operator::new(Memory::default())?
+            .layer(layer.clone())
+            .finish();
+
+        let mut w = op.writer("a").await?;
+        w.write("Hello").await?;
+        let err = w.write("World").await.expect_err("must exceed quota");
+        assert_eq!(err.kind(), ErrorKind::QuotaExceeded);
+        w.close().await?;
+        assert_eq!(layer.used(), 5);
+
+        op.blocking().delete("a").expect("delete must succeed");
+        assert_eq!(layer.used(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_quota_overwrite() -> Result<()> {
+        let layer = QuotaLayer::new(10, 2);
+        let op = Operator::new(Memory::default())?
+            .layer(layer.clone())
+            .finish();
+
+        op.write("a", "Hello").await?;
+        assert_eq!(layer.used(), 7);
+
+        // Bytes of the overwritten file are reclaimed.
+        op.write("a", "Hi").await?;
+        assert_eq!(layer.used(), 4);
+
+        // Bytes of the overwritten file are available for the new content.
+        op.write("a", "Hello, W").await?;
+        assert_eq!(layer.used(), 10);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_quota_concurrent_writers() -> Result<()> {
+        let layer = QuotaLayer::new(6, 0);
+        let op = Operator::new(Memory::default())?
+            .layer(layer.clone())
+            .finish();
+
+        let mut wa = op.writer("a").await?;
+        let mut wb = op.writer("b").await?;
+        wa.write("Hell").await?;
+        let err = wb.write("Worl").await.expect_err("must exceed quota");
+        assert_eq!(err.kind(), ErrorKind::QuotaExceeded);
+        assert_eq!(layer.used(), 4);
+
+        // Reserved bytes are released if writer is aborted or dropped.
+        wa.abort().await?;
+        assert_eq!(layer.used(), 0);
+        wb.write("Worl").await?;
+        assert_eq!(layer.used(), 4);
+        drop(wb);
+        assert_eq!(layer.used(), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,457 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use bytes::BytesMut;
+use lru::LruCache;
+
+use crate::raw::oio::ReadExt;
+use crate::raw::*;
+use crate::*;
+
+/// Cache the content of small objects in memory to avoid re-fetching
+/// frequently accessed bytes like config files and lookup tables.
+///
+/// # Notes
+///
+/// - Only full reads of objects with an `ETag` and no larger than
+///   `max_object_size` are cached. Range reads always go to the
+///   underlying storage.
+/// - Cached objects are still read from the underlying storage to make
+///   sure the cached `ETag` is still the latest one, so stale data is
+///   never returned. If the service supports `If-None-Match`, the read
+///   will carry the cached `ETag` and an unchanged object will not be
+///   transferred again.
+/// - At most `max_cache_entries` objects will be cached, the least
+///   recently used one will be evicted first.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::SmallObjectCacheLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(SmallObjectCacheLayer::new(64 * 1024, 1024))
+///     .finish();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SmallObjectCacheLayer {
+    max_object_size: u64,
+    cache: Arc<Mutex<LruCache<String, (String, Bytes)>>>,
+}
+
+impl SmallObjectCacheLayer {
+    /// Create a new SmallObjectCacheLayer.
+    ///
+    /// - `max_object_size`: objects larger than this will not be cached.
+    /// - `max_cache_entries`: the max count of cached objects.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_cache_entries` is zero.
+    pub fn new(max_object_size: u64, max_cache_entries: usize) -> Self {
+        let cap =
+            NonZeroUsize::new(max_cache_entries).expect("max_cache_entries must be greater than 0");
+
+        Self {
+            max_object_size,
+            cache: Arc::new(Mutex::new(LruCache::new(cap))),
+        }
+    }
+}
+
+impl Debug for SmallObjectCacheLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmallObjectCacheLayer")
+            .field("max_object_size", &self.max_object_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Accessor> Layer<A> for SmallObjectCacheLayer {
+    type LayeredAccessor = SmallObjectCacheAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        let read_with_if_none_match = inner.info().capability().read_with_if_none_match;
+
+        SmallObjectCacheAccessor {
+            inner,
+            read_with_if_none_match,
+            max_object_size: self.max_object_size,
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+pub struct SmallObjectCacheAccessor<A: Accessor> {
+    inner: A,
+    read_with_if_none_match: bool,
+    max_object_size: u64,
+    cache: Arc<Mutex<LruCache<String, (String, Bytes)>>>,
+}
+
+impl<A: Accessor> Debug for SmallObjectCacheAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmallObjectCacheAccessor")
+            .field("inner", &self.inner)
+            .field("max_object_size", &self.max_object_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Accessor> SmallObjectCacheAccessor<A> {
+    /// Returns the etag of the read response if it's cacheable.
+    fn cacheable_etag(&self, meta: &Metadata) -> Option<String> {
+        if !meta.bit().contains(Metakey::Etag) || meta.content_length() > self.max_object_size {
+            return None;
+        }
+        meta.etag().map(|v| v.to_string())
+    }
+
+    /// Get the cached etag and content of path.
+    fn get(&self, path: &str) -> Option<(String, Bytes)> {
+        let mut cache = self.cache.lock().expect("lock must succeed");
+        cache.get(path).cloned()
+    }
+
+    fn insert(&self, path: &str, etag: String, bs: Bytes) {
+        let mut cache = self.cache.lock().expect("lock must succeed");
+        cache.put(path.to_string(), (etag, bs));
+    }
+
+    fn remove(&self, path: &str) {
+        let mut cache = self.cache.lock().expect("lock must succeed");
+        cache.pop(path);
+    }
+
+    /// Build the args of a full read.
+    ///
+    /// Returns the cached content if the read will carry its etag as
+    /// `If-None-Match`. Reads with user specified conditions are never
+    /// served from the cache.
+    fn prepare_read(&self, path: &str, args: OpRead) -> (OpRead, Option<(String, Bytes)>) {
+        if args.if_match().is_some() || args.if_none_match().is_some() {
+            return (args, None);
+        }
+
+        match self.get(path) {
+            Some((etag, bs)) if self.read_with_if_none_match => {
+                (args.with_if_none_match(&etag), Some((etag, bs)))
+            }
+            cached => (args, cached),
+        }
+    }
+
+    fn cached(bs: Bytes) -> (RpRead, oio::Cursor) {
+        (RpRead::new(bs.len() as u64), oio::Cursor::from(bs))
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for SmallObjectCacheAccessor<A> {
+    type Inner = A;
+    type Reader = oio::Reader;
+    type BlockingReader = oio::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Appender = A::Appender;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        if !args.range().is_full() {
+            return self
+                .inner
+                .read(path, args)
+                .await
+                .map(|(rp, r)| (rp, Box::new(r) as oio::Reader));
+        }
+
+        let (args, cached) = self.prepare_read(path, args);
+        let (rp, mut r) = match self.inner.read(path, args).await {
+            Ok(v) => v,
+            Err(err) if err.kind() == ErrorKind::ConditionNotMatch && cached.is_some() => {
+                let (_, bs) = cached.expect("cached must be valid");
+                let (rp, r) = Self::cached(bs);
+                return Ok((rp, Box::new(r)));
+            }
+            Err(err) => return Err(err),
+        };
+
+        let Some(etag) = self.cacheable_etag(rp.metadata()) else {
+            return Ok((rp, Box::new(r)));
+        };
+        if let Some((_, bs)) = cached.filter(|(v, _)| v == &etag) {
+            let (rp, r) = Self::cached(bs);
+            return Ok((rp, Box::new(r)));
+        }
+
+        let mut buf = BytesMut::new();
+        while let Some(bs) = r.next().await {
+            buf.extend_from_slice(&bs?);
+        }
+        let bs = buf.freeze();
+        self.insert(path, etag, bs.clone());
+
+        let (rp, r) = Self::cached(bs);
+        Ok((rp, Box::new(r)))
+    }
+
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        let exists = self.inner.object_exists(path).await?;
+        // Drop the cached content of removed objects to free the memory.
+        if !exists {
+            self.remove(path);
+        }
+        Ok(exists)
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        if !args.range().is_full() {
+            return self
+                .inner
+                .blocking_read(path, args)
+                .map(|(rp, r)| (rp, Box::new(r) as oio::BlockingReader));
+        }
+
+        let (args, cached) = self.prepare_read(path, args);
+        let (rp, mut r) = match self.inner.blocking_read(path, args) {
+            Ok(v) => v,
+            Err(err) if err.kind() == ErrorKind::ConditionNotMatch && cached.is_some() => {
+                let (_, bs) = cached.expect("cached must be valid");
+                let (rp, r) = Self::cached(bs);
+                return Ok((rp, Box::new(r)));
+            }
+            Err(err) => return Err(err),
+        };
+
+        let Some(etag) = self.cacheable_etag(rp.metadata()) else {
+            return Ok((rp, Box::new(r)));
+        };
+        if let Some((_, bs)) = cached.filter(|(v, _)| v == &etag) {
+            let (rp, r) = Self::cached(bs);
+            return Ok((rp, Box::new(r)));
+        }
+
+        let mut buf = BytesMut::new();
+        while let Some(bs) = oio::BlockingRead::next(&mut r) {
+            buf.extend_from_slice(&bs?);
+        }
+        let bs = buf.freeze();
+        self.insert(path, etag, bs.clone());
+
+        let (rp, r) = Self::cached(bs);
+        Ok((rp, Box::new(r)))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+
+    /// MockService stores objects in memory with etag and counts the
+    /// reads which transfer content.
+    #[derive(Debug, Default)]
+    struct MockService {
+        objects: Mutex<HashMap<String, (String, Bytes)>>,
+        reads: AtomicUsize,
+        read_with_if_none_match: bool,
+    }
+
+    #[async_trait]
+    impl Accessor for Arc<MockService> {
+        type Reader = oio::Cursor;
+        type BlockingReader = oio::Cursor;
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = ();
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.capability_mut().read = true;
+            am.capability_mut().read_with_if_none_match = self.read_with_if_none_match;
+            am.capability_mut().read_can_seek = true;
+            am.capability_mut().read_can_next = true;
+            am.capability_mut().blocking = true;
+            am
+        }
+
+        async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.read_object(path, args)
+        }
+
+        fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.read_object(path, args)
+        }
+    }
+
+    impl MockService {
+        fn new(read_with_if_none_match: bool) -> Arc<Self> {
+            Arc::new(Self {
+                read_with_if_none_match,
+                ..Default::default()
+            })
+        }
+
+        fn read_object(&self, path: &str, args: OpRead) -> Result<(RpRead, oio::Cursor)> {
+            let (etag, bs) = self.get(path)?;
+            if args.if_none_match() == Some(etag.as_str()) {
+                return Err(Error::new(ErrorKind::ConditionNotMatch, "not modified"));
+            }
+
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            let meta = Metadata::new(EntryMode::FILE)
+                .with_content_length(bs.len() as u64)
+                .with_etag(etag);
+            Ok((RpRead::with_metadata(meta), oio::Cursor::from(bs)))
+        }
+
+        fn get(&self, path: &str) -> Result<(String, Bytes)> {
+            self.objects
+                .lock()
+                .unwrap()
+                .get(path)
+                .cloned()
+                .ok_or_else(|| Error::new(ErrorKind::NotFound, "not found"))
+        }
+
+        fn put(&self, path: &str, etag: &str, bs: &'static [u8]) {
+            self.objects
+                .lock()
+                .unwrap()
+                .insert(path.to_string(), (etag.to_string(), Bytes::from(bs)));
+        }
+
+        fn reads(&self) -> usize {
+            self.reads.load(Ordering::SeqCst)
+        }
+    }
+
+    fn new_operator(srv: Arc<MockService>) -> Operator {
+        OperatorBuilder::new(srv)
+            .layer(SmallObjectCacheLayer::new(16, 2))
+            .finish()
+    }
+
+    #[tokio::test]
+    async fn test_cache_hit() -> Result<()> {
+        let srv = MockService::new(true);
+        srv.put("config", "v1", b"Hello");
+        let op = new_operator(srv.clone());
+
+        assert_eq!(op.read("config").await?, b"Hello");
+        assert_eq!(op.read("config").await?, b"Hello");
+        assert_eq!(srv.reads(), 1);
+
+        // Range reads are never cached.
+        op.range_read("config", 1..3).await?;
+        assert_eq!(srv.reads(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cache_etag_changed() -> Result<()> {
+        let srv = MockService::new(true);
+        srv.put("config", "v1", b"Hello");
+        let op = new_operator(srv.clone());
+
+        assert_eq!(op.read("config").await?, b"Hello");
+        srv.put("config", "v2", b"World");
+        assert_eq!(op.read("config").await?, b"World");
+        assert_eq!(op.read("config").await?, b"World");
+        assert_eq!(srv.reads(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cache_limits() -> Result<()> {
+        let srv = MockService::new(true);
+        srv.put("large", "v1", b"This content is larger than 16 bytes");
+        srv.put("a", "v1", b"a");
+        srv.put("b", "v1", b"b");
+        srv.put("c", "v1", b"c");
+        let op = new_operator(srv.clone());
+
+        op.read("large").await?;
+        op.read("large").await?;
+        assert_eq!(srv.reads(), 2);
+
+        // Only 2 entries will be kept, "a" is evicted by "c".
+        for path in ["a", "b", "c", "b", "a"] {
+            op.read(path).await?;
+        }
+        assert_eq!(srv.reads(), 2 + 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cache_without_if_none_match() -> Result<()> {
+        let srv = MockService::new(false);
+        srv.put("config", "v1", b"Hello");
+        let op = new_operator(srv.clone());
+
+        assert_eq!(op.read("config").await?, b"Hello");
+        assert_eq!(op.read("config").await?, b"Hello");
+        srv.put("config", "v2", b"World");
+        assert_eq!(op.read("config").await?, b"World");
+        assert_eq!(op.blocking().read("config")?, b"World");
+        Ok(())
+    }
+}
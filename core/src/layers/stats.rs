@@ -0,0 +1,485 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::SeekFrom;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// Add in-process operation statistics for [`Operator`].
+///
+/// # Notes
+///
+/// Unlike [`MetricsLayer`](crate::layers::MetricsLayer) which pushes metrics
+/// to external systems, StatsLayer only keeps counters inside current process,
+/// which is useful in tests and benchmarks.
+///
+/// StatsLayer is cheap to clone, all clones share the same counters.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::StatsLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let layer = StatsLayer::default();
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(layer.clone())
+///     .finish();
+///
+/// op.write("test", "Hello, World!").await?;
+/// let stats = layer.snapshot();
+/// assert_eq!(stats.write, 1);
+/// assert_eq!(stats.bytes_written, 13);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StatsLayer {
+    stats: Arc<Stats>,
+}
+
+impl StatsLayer {
+    /// Get a point-in-time copy of all counters.
+    pub fn snapshot(&self) -> OperationStats {
+        self.stats.snapshot()
+    }
+
+    /// Reset all counters to zero.
+    pub fn reset(&self) {
+        self.stats.reset()
+    }
+}
+
+impl<A: Accessor> Layer<A> for StatsLayer {
+    type LayeredAccessor = StatsAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        StatsAccessor {
+            inner,
+            stats: self.stats.clone(),
+        }
+    }
+}
+
+/// OperationStats is a point-in-time copy of counters collected by
+/// [`StatsLayer`].
+///
+/// Blocking operations are counted together with their async versions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationStats {
+    /// Count of `create_dir` operations.
+    pub create_dir: u64,
+    /// Count of `read` operations.
+    pub read: u64,
+    /// Count of `write` operations.
+    pub write: u64,
+    /// Count of `append` operations.
+    pub append: u64,
+    /// Count of `copy` operations.
+    pub copy: u64,
+    /// Count of `rename` operations.
+    pub rename: u64,
+    /// Count of `stat` operations, including `object_exists`.
+    pub stat: u64,
+    /// Count of `delete` operations.
+    pub delete: u64,
+    /// Count of `list` operations.
+    pub list: u64,
+    /// Count of `batch` operations.
+    pub batch: u64,
+    /// Count of `presign` operations.
+    pub presign: u64,
+    /// Bytes read from readers.
+    pub bytes_read: u64,
+    /// Bytes written into writers and appenders.
+    pub bytes_written: u64,
+}
+
+#[derive(Debug, Default)]
+struct Stats {
+    create_dir: AtomicU64,
+    read: AtomicU64,
+    write: AtomicU64,
+    append: AtomicU64,
+    copy: AtomicU64,
+    rename: AtomicU64,
+    stat: AtomicU64,
+    delete: AtomicU64,
+    list: AtomicU64,
+    batch: AtomicU64,
+    presign: AtomicU64,
+    bytes_read: AtomicU64,
+    bytes_written: AtomicU64,
+}
+
+impl Stats {
+    fn counters(&self) -> [&AtomicU64; 13] {
+        [
+            &self.create_dir,
+            &self.read,
+            &self.write,
+            &self.append,
+            &self.copy,
+            &self.rename,
+            &self.stat,
+            &self.delete,
+            &self.list,
+            &self.batch,
+            &self.presign,
+            &self.bytes_read,
+            &self.bytes_written,
+        ]
+    }
+
+    fn snapshot(&self) -> OperationStats {
+        let [create_dir, read, write, append, copy, rename, stat, delete, list, batch, presign, bytes_read, bytes_written] =
+            self.counters().map(|v| v.load(Ordering::Relaxed));
+
+        OperationStats {
+            create_dir,
+            read,
+            write,
+            append,
+            copy,
+            rename,
+            stat,
+            delete,
+            list,
+            batch,
+            presign,
+            bytes_read,
+            bytes_written,
+        }
+    }
+
+    fn reset(&self) {
+        for v in self.counters() {
+            v.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+fn incr(counter: &AtomicU64, n: u64) {
+    counter.fetch_add(n, Ordering::Relaxed);
+}
+
+#[derive(Debug, Clone)]
+pub struct StatsAccessor<A: Accessor> {
+    inner: A,
+    stats: Arc<Stats>,
+}
+
+impl<A: Accessor> StatsAccessor<A> {
+    /// Get a point-in-time copy of all counters.
+    pub fn snapshot(&self) -> OperationStats {
+        self.stats.snapshot()
+    }
+
+    /// Reset all counters to zero.
+    pub fn reset(&self) {
+        self.stats.reset()
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for StatsAccessor<A> {
+    type Inner = A;
+    type Reader = StatsWrapper<A::Reader>;
+    type BlockingReader = StatsWrapper<A::BlockingReader>;
+    type Writer = StatsWrapper<A::Writer>;
+    type BlockingWriter = StatsWrapper<A::BlockingWriter>;
+    type Appender = StatsWrapper<A::Appender>;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        incr(&self.stats.create_dir, 1);
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        incr(&self.stats.read, 1);
+        self.inner
+            .read(path, args)
+            .await
+            .map(|(rp, r)| (rp, StatsWrapper::new(r, self.stats.clone())))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        incr(&self.stats.write, 1);
+        self.inner
+            .write(path, args)
+            .await
+            .map(|(rp, w)| (rp, StatsWrapper::new(w, self.stats.clone())))
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        incr(&self.stats.append, 1);
+        self.inner
+            .append(path, args)
+            .await
+            .map(|(rp, a)| (rp, StatsWrapper::new(a, self.stats.clone())))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        incr(&self.stats.copy, 1);
+        self.inner.copy(from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        incr(&self.stats.rename, 1);
+        self.inner.rename(from, to, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        incr(&self.stats.stat, 1);
+        self.inner.stat(path, args).await
+    }
+
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        incr(&self.stats.stat, 1);
+        self.inner.object_exists(path).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        incr(&self.stats.delete, 1);
+        self.inner.delete(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        incr(&self.stats.list, 1);
+        self.inner.list(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        incr(&self.stats.batch, 1);
+        self.inner.batch(args).await
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        incr(&self.stats.presign, 1);
+        self.inner.presign(path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        incr(&self.stats.create_dir, 1);
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        incr(&self.stats.read, 1);
+        self.inner
+            .blocking_read(path, args)
+            .map(|(rp, r)| (rp, StatsWrapper::new(r, self.stats.clone())))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        incr(&self.stats.write, 1);
+        self.inner
+            .blocking_write(path, args)
+            .map(|(rp, w)| (rp, StatsWrapper::new(w, self.stats.clone())))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        incr(&self.stats.copy, 1);
+        self.inner.blocking_copy(from, to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        incr(&self.stats.rename, 1);
+        self.inner.blocking_rename(from, to, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        incr(&self.stats.stat, 1);
+        self.inner.blocking_stat(path, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        incr(&self.stats.delete, 1);
+        self.inner.blocking_delete(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        incr(&self.stats.list, 1);
+        self.inner.blocking_list(path, args)
+    }
+}
+
+pub struct StatsWrapper<R> {
+    inner: R,
+    stats: Arc<Stats>,
+}
+
+impl<R> StatsWrapper<R> {
+    fn new(inner: R, stats: Arc<Stats>) -> Self {
+        Self { inner, stats }
+    }
+}
+
+impl<R: oio::Read> oio::Read for StatsWrapper<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        self.inner.poll_read(cx, buf).map_ok(|n| {
+            incr(&self.stats.bytes_read, n as u64);
+            n
+        })
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        self.inner.poll_seek(cx, pos)
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        self.inner.poll_next(cx).map(|v| {
+            v.map(|res| {
+                res.map(|bs| {
+                    incr(&self.stats.bytes_read, bs.len() as u64);
+                    bs
+                })
+            })
+        })
+    }
+}
+
+impl<R: oio::BlockingRead> oio::BlockingRead for StatsWrapper<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.inner.read(buf).map(|n| {
+            incr(&self.stats.bytes_read, n as u64);
+            n
+        })
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        self.inner.seek(pos)
+    }
+
+    fn next(&mut self) -> Option<Result<Bytes>> {
+        self.inner.next().map(|res| {
+            res.map(|bs| {
+                incr(&self.stats.bytes_read, bs.len() as u64);
+                bs
+            })
+        })
+    }
+}
+
+#[async_trait]
+impl<R: oio::Write> oio::Write for StatsWrapper<R> {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        let size = bs.len() as u64;
+        self.inner.write(bs).await?;
+        incr(&self.stats.bytes_written, size);
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+
+    async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+        self.inner.sink(size, s).await?;
+        incr(&self.stats.bytes_written, size);
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await
+    }
+}
+
+impl<R: oio::BlockingWrite> oio::BlockingWrite for StatsWrapper<R> {
+    fn write(&mut self, bs: Bytes) -> Result<()> {
+        let size = bs.len() as u64;
+        self.inner.write(bs)?;
+        incr(&self.stats.bytes_written, size);
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()
+    }
+}
+
+#[async_trait]
+impl<R: oio::Append> oio::Append for StatsWrapper<R> {
+    async fn append(&mut self, bs: Bytes) -> Result<()> {
+        let size = bs.len() as u64;
+        self.inner.append(bs).await?;
+        incr(&self.stats.bytes_written, size);
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_stats() -> Result<()> {
+        let layer = StatsLayer::default();
+        let op = Operator::new(Memory::default())?
+            .layer(layer.clone())
+            .finish();
+
+        op.write("file", "Hello, World!").await?;
+        let bs = op.read("file").await?;
+        assert_eq!(bs, b"Hello, World!");
+        op.stat("file").await?;
+        assert!(op.is_exist("file").await?);
+        op.delete("file").await?;
+        op.blocking().stat("dir/").expect("stat must succeed");
+
+        assert_eq!(
+            layer.snapshot(),
+            OperationStats {
+                write: 1,
+                read: 1,
+                stat: 3,
+                delete: 1,
+                bytes_read: 13,
+                bytes_written: 13,
+                ..Default::default()
+            }
+        );
+
+        layer.reset();
+        assert_eq!(layer.snapshot(), OperationStats::default());
+        Ok(())
+    }
+}
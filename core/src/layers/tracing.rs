@@ -190,6 +190,11 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
         self.inner.stat(path, args).await
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        self.inner.object_exists(path).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.inner.delete(path, args).await
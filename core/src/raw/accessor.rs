@@ -16,9 +16,12 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Bytes;
 
 use crate::raw::*;
 use crate::*;
@@ -103,6 +106,31 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `ensure_dir` operation on the specified path.
+    ///
+    /// Services with real directories SHOULD override it to create all
+    /// parent dirs of `path`. The default implementation does nothing for
+    /// services whose dirs are virtual (see [`Capability::directory_create`]),
+    /// and returns `Unsupported` for others.
+    ///
+    /// # Behavior
+    ///
+    /// - If `path` is a file path, all its parent dirs will be created.
+    /// - If `path` is a dir path, the dir itself will also be created.
+    /// - `ensure_dir` SHOULD return `Ok(())` if dirs exist already.
+    async fn ensure_dir(&self, path: &str) -> Result<()> {
+        let _ = path;
+
+        if self.info().capability().directory_create {
+            return Ok(());
+        }
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
     /// Invoke the `read` operation on the specified path, returns a
     /// [`Reader`][crate::Reader] if operate successful.
     ///
@@ -185,6 +213,25 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `create_symlink` operation, creates a symlink at `dst`
+    /// that points to `src`.
+    ///
+    /// Require [`Capability::symlink`]
+    ///
+    /// # Behavior
+    ///
+    /// - `src` and `dst` MUST be file paths, DON'T NEED to check mode.
+    /// - `src` is NOT required to exist.
+    /// - `AlreadyExists` SHOULD be returned if `dst` exists.
+    async fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        let (_, _) = (src, dst);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
     /// Invoke the `stat` operation on the specified path.
     ///
     /// Require [`Capability::stat`]
@@ -203,6 +250,23 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `object_exists` operation on the specified path.
+    ///
+    /// Services with [`Capability::exists`] SHOULD override it to check
+    /// existence without fetching the whole metadata. The default
+    /// implementation falls back to `stat`.
+    ///
+    /// # Behavior
+    ///
+    /// - Returns `false` instead of `NotFound` error if path doesn't exist.
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        match self.stat(path, OpStat::new()).await {
+            Ok(_) => Ok(true),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Invoke the `delete` operation on the specified path.
     ///
     /// Require [`Capability::delete`]
@@ -237,6 +301,374 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `list_versions` operation on the specified path.
+    ///
+    /// Require [`Capability::versioning`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path will be used as prefix, both file and dir path are allowed.
+    /// - Every returned entry MUST carry its version in metadata.
+    /// - List versions of non-exist path should return Empty.
+    async fn list_versions(
+        &self,
+        path: &str,
+        args: OpListVersions,
+    ) -> Result<(RpListVersions, oio::Pager)> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `create_multipart` operation on the specified path.
+    ///
+    /// Require [`Capability::write_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - The returning upload_id MUST be used in following `write_multipart`,
+    ///   `complete_multipart` and `abort_multipart` operations.
+    async fn create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `write_multipart` operation on the specified path.
+    ///
+    /// Require [`Capability::write_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Upload `bs` as the part of given part number.
+    /// - Services MAY have limits on the size of parts except the last one.
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        bs: Bytes,
+    ) -> Result<RpWriteMultipart> {
+        let (_, _, _) = (path, args, bs);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `complete_multipart` operation on the specified path.
+    ///
+    /// Require [`Capability::write_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - The file MUST be visible only after `complete_multipart` succeeded.
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `abort_multipart` operation on the specified path.
+    ///
+    /// Require [`Capability::write_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - All uploaded parts SHOULD be cleaned up by services.
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `touch` operation on the specified path.
+    ///
+    /// Require [`Capability::touch`]
+    ///
+    /// # Behavior
+    ///
+    /// - `touch` updates the last modified time of the file without changing its content.
+    /// - `touch` returns `NotFound` if the file doesn't exist.
+    async fn touch(&self, path: &str, args: OpTouch) -> Result<RpTouch> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `get_lifecycle_rules` operation on current bucket.
+    ///
+    /// Require [`Capability::lifecycle`]
+    ///
+    /// # Behavior
+    ///
+    /// - `get_lifecycle_rules` returns an empty list if no rules configured.
+    async fn get_lifecycle_rules(&self, args: OpGetLifecycleRules) -> Result<RpGetLifecycleRules> {
+        let _ = args;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `set_lifecycle_rules` operation on current bucket.
+    ///
+    /// Require [`Capability::lifecycle`]
+    ///
+    /// # Behavior
+    ///
+    /// - `set_lifecycle_rules` replaces all existing rules with given rules.
+    async fn set_lifecycle_rules(&self, args: OpSetLifecycleRules) -> Result<RpSetLifecycleRules> {
+        let _ = args;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `get_cors_config` operation on current bucket.
+    ///
+    /// Require [`Capability::cors`]
+    ///
+    /// # Behavior
+    ///
+    /// - `get_cors_config` returns an empty list if no rules configured.
+    async fn get_cors_config(&self, args: OpGetCorsConfig) -> Result<RpGetCorsConfig> {
+        let _ = args;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `set_cors_config` operation on current bucket.
+    ///
+    /// Require [`Capability::cors`]
+    ///
+    /// # Behavior
+    ///
+    /// - `set_cors_config` replaces all existing rules with given rules.
+    async fn set_cors_config(&self, args: OpSetCorsConfig) -> Result<RpSetCorsConfig> {
+        let _ = args;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `get_bucket_acl` operation on current bucket.
+    ///
+    /// Require [`Capability::bucket_acl`]
+    async fn get_bucket_acl(&self, args: OpGetBucketAcl) -> Result<RpGetBucketAcl> {
+        let _ = args;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `set_bucket_acl` operation on current bucket.
+    ///
+    /// Require [`Capability::bucket_acl`]
+    ///
+    /// # Behavior
+    ///
+    /// - `set_bucket_acl` replaces all existing grants with given grants.
+    async fn set_bucket_acl(&self, args: OpSetBucketAcl) -> Result<RpSetBucketAcl> {
+        let _ = args;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `head` operation on the specified path.
+    ///
+    /// Require [`Capability::stat`]
+    ///
+    /// # Behavior
+    ///
+    /// - `head` only returns the content length and etag of the path.
+    /// - `head` is a cheaper alternative of `stat` for callers that don't
+    ///   need the full metadata.
+    ///
+    /// The default implementation calls `stat` and extracts the fields,
+    /// services that can fetch them cheaper should override it.
+    async fn head(&self, path: &str, args: OpHead) -> Result<RpHead> {
+        let _ = args;
+
+        let meta = self.stat(path, OpStat::new()).await?.into_metadata();
+        Ok(RpHead::new(
+            meta.content_length_raw().unwrap_or_default(),
+            meta.etag_raw(),
+        ))
+    }
+
+    /// Invoke the `get_tags` operation on the specified path.
+    ///
+    /// Require [`Capability::tagging`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - `get_tags` returns an empty map if no tags set.
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `set_tags` operation on the specified path.
+    ///
+    /// Require [`Capability::tagging`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - `set_tags` replaces all existing tags with given tags.
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `update_metadata` operation on the specified path.
+    ///
+    /// Require [`Capability::update_metadata`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - `update_metadata` replaces all existing user metadata with given
+    ///   metadata without changing the content.
+    /// - `update_metadata` returns `NotFound` if the file doesn't exist.
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `get_object_lock` operation on the specified path.
+    ///
+    /// Require [`Capability::object_lock`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - `get_object_lock` returns `None` if the file is not locked.
+    async fn get_object_lock(&self, path: &str, args: OpGetObjectLock) -> Result<RpGetObjectLock> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `lock_object` operation on the specified path.
+    ///
+    /// Require [`Capability::object_lock`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - `lock_object` returns `Unsupported` if the mode is not supported
+    ///   by the service.
+    async fn lock_object(&self, path: &str, args: OpLockObject) -> Result<RpLockObject> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `presign_post` operation on the specified path.
+    ///
+    /// Require [`Capability::presign_post`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - The returning fields MUST include all fields in args.
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `get_restore_status` operation on the specified path.
+    ///
+    /// Require [`Capability::restore_status`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - Files that are not being restored or restored should return
+    ///   `in_progress` as `false` without expiry.
+    async fn get_restore_status(
+        &self,
+        path: &str,
+        args: OpGetRestoreStatus,
+    ) -> Result<RpGetRestoreStatus> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
     /// Invoke the `presign` operation on the specified path.
     ///
     /// Require [`Capability::presign`]
@@ -335,6 +767,21 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `blocking_create_symlink` operation, creates a symlink at
+    /// `dst` that points to `src`.
+    ///
+    /// This operation is the blocking version of [`Accessor::create_symlink`]
+    ///
+    /// Require [`Capability::symlink`] and [`Capability::blocking`]
+    fn blocking_create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        let (_, _) = (src, dst);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
     /// Invoke the `blocking_stat` operation on the specified path.
     ///
     /// This operation is the blocking version of [`Accessor::stat`]
@@ -380,6 +827,16 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
             "operation is not supported",
         ))
     }
+    /// Wrap this accessor so that all `read` and `write` calls carry the
+    /// given [`RequestContext`].
+    ///
+    /// Contexts set on the args explicitly will not be overwritten.
+    fn with_context(self, ctx: RequestContext) -> ContextualAccessor<Self>
+    where
+        Self: Sized,
+    {
+        ContextualAccessor::new(self, ctx)
+    }
 }
 
 /// Dummy implementation of accessor.
@@ -398,7 +855,12 @@ impl Accessor for () {
             scheme: Scheme::Custom("dummy"),
             root: "".to_string(),
             name: "dummy".to_string(),
+            endpoint: None,
             capability: Capability::default(),
+            max_object_size: None,
+            max_single_put_size: None,
+            bytes_read: None,
+            bytes_written: None,
         }
     }
 }
@@ -423,6 +885,10 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
         self.as_ref().create_dir(path, args).await
     }
 
+    async fn ensure_dir(&self, path: &str) -> Result<()> {
+        self.as_ref().ensure_dir(path).await
+    }
+
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         self.as_ref().read(path, args).await
     }
@@ -442,15 +908,130 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
         self.as_ref().rename(from, to, args).await
     }
 
+    async fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        self.as_ref().create_symlink(src, dst).await
+    }
+
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         self.as_ref().stat(path, args).await
     }
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        self.as_ref().object_exists(path).await
+    }
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.as_ref().delete(path, args).await
     }
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
         self.as_ref().list(path, args).await
     }
+    async fn list_versions(
+        &self,
+        path: &str,
+        args: OpListVersions,
+    ) -> Result<(RpListVersions, oio::Pager)> {
+        self.as_ref().list_versions(path, args).await
+    }
+
+    async fn create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        self.as_ref().create_multipart(path, args).await
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        bs: Bytes,
+    ) -> Result<RpWriteMultipart> {
+        self.as_ref().write_multipart(path, args, bs).await
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.as_ref().complete_multipart(path, args).await
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        self.as_ref().abort_multipart(path, args).await
+    }
+
+    async fn touch(&self, path: &str, args: OpTouch) -> Result<RpTouch> {
+        self.as_ref().touch(path, args).await
+    }
+
+    async fn get_lifecycle_rules(&self, args: OpGetLifecycleRules) -> Result<RpGetLifecycleRules> {
+        self.as_ref().get_lifecycle_rules(args).await
+    }
+
+    async fn set_lifecycle_rules(&self, args: OpSetLifecycleRules) -> Result<RpSetLifecycleRules> {
+        self.as_ref().set_lifecycle_rules(args).await
+    }
+
+    async fn get_cors_config(&self, args: OpGetCorsConfig) -> Result<RpGetCorsConfig> {
+        self.as_ref().get_cors_config(args).await
+    }
+
+    async fn set_cors_config(&self, args: OpSetCorsConfig) -> Result<RpSetCorsConfig> {
+        self.as_ref().set_cors_config(args).await
+    }
+
+    async fn get_bucket_acl(&self, args: OpGetBucketAcl) -> Result<RpGetBucketAcl> {
+        self.as_ref().get_bucket_acl(args).await
+    }
+
+    async fn set_bucket_acl(&self, args: OpSetBucketAcl) -> Result<RpSetBucketAcl> {
+        self.as_ref().set_bucket_acl(args).await
+    }
+
+    async fn head(&self, path: &str, args: OpHead) -> Result<RpHead> {
+        self.as_ref().head(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.as_ref().get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        self.as_ref().set_tags(path, args).await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        self.as_ref().update_metadata(path, args).await
+    }
+
+    async fn get_object_lock(&self, path: &str, args: OpGetObjectLock) -> Result<RpGetObjectLock> {
+        self.as_ref().get_object_lock(path, args).await
+    }
+
+    async fn lock_object(&self, path: &str, args: OpLockObject) -> Result<RpLockObject> {
+        self.as_ref().lock_object(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.as_ref().presign_post(path, args).await
+    }
+
+    async fn get_restore_status(
+        &self,
+        path: &str,
+        args: OpGetRestoreStatus,
+    ) -> Result<RpGetRestoreStatus> {
+        self.as_ref().get_restore_status(path, args).await
+    }
 
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         self.as_ref().batch(args).await
@@ -478,6 +1059,10 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
         self.as_ref().blocking_rename(from, to, args)
     }
 
+    fn blocking_create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        self.as_ref().blocking_create_symlink(src, dst)
+    }
+
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         self.as_ref().blocking_stat(path, args)
     }
@@ -508,8 +1093,14 @@ pub struct AccessorInfo {
     scheme: Scheme,
     root: String,
     name: String,
+    endpoint: Option<String>,
 
     capability: Capability,
+    max_object_size: Option<u64>,
+    max_single_put_size: Option<u64>,
+
+    bytes_read: Option<Arc<AtomicU64>>,
+    bytes_written: Option<Arc<AtomicU64>>,
 }
 
 impl AccessorInfo {
@@ -553,6 +1144,20 @@ impl AccessorInfo {
         self
     }
 
+    /// Endpoint that backend sends requests to, `None` if backend doesn't
+    /// have an endpoint or doesn't report it.
+    pub fn endpoint(&self) -> Option<&str> {
+        self.endpoint.as_deref()
+    }
+
+    /// Set endpoint of this backend.
+    ///
+    /// Note: endpoint must not contain any credentials.
+    pub fn set_endpoint(&mut self, endpoint: &str) -> &mut Self {
+        self.endpoint = Some(endpoint.to_string());
+        self
+    }
+
     /// Get backend's capabilities.
     pub fn capability(&self) -> Capability {
         self.capability
@@ -568,4 +1173,65 @@ impl AccessorInfo {
         self.capability = capability;
         self
     }
+
+    /// Max size of a single object that backend allows, `None` means unknown
+    /// or unlimited.
+    pub fn max_object_size(&self) -> Option<u64> {
+        self.max_object_size
+    }
+
+    /// Set max size of a single object for backend.
+    pub fn set_max_object_size(&mut self, size: u64) -> &mut Self {
+        self.max_object_size = Some(size);
+        self
+    }
+
+    /// Max size of object that can be written in a single request, `None`
+    /// means unknown or unlimited.
+    ///
+    /// Objects larger than it must be written by multipart upload.
+    pub fn max_single_put_size(&self) -> Option<u64> {
+        self.max_single_put_size
+    }
+
+    /// Set max size of object that can be written in a single request for backend.
+    pub fn set_max_single_put_size(&mut self, size: u64) -> &mut Self {
+        self.max_single_put_size = Some(size);
+        self
+    }
+
+    /// Total bytes read from backend, `None` means backend doesn't track it.
+    pub fn bytes_read(&self) -> Option<u64> {
+        self.bytes_read.as_ref().map(|v| v.load(Ordering::Relaxed))
+    }
+
+    /// Total bytes written into backend, `None` means backend doesn't track it.
+    pub fn bytes_written(&self) -> Option<u64> {
+        self.bytes_written
+            .as_ref()
+            .map(|v| v.load(Ordering::Relaxed))
+    }
+
+    /// Set counters of bytes read and written for backend.
+    ///
+    /// Counters are shared with backend, so the values are always up to date.
+    pub fn set_bytes_counters(
+        &mut self,
+        bytes_read: Arc<AtomicU64>,
+        bytes_written: Arc<AtomicU64>,
+    ) -> &mut Self {
+        self.bytes_read = Some(bytes_read);
+        self.bytes_written = Some(bytes_written);
+        self
+    }
+
+    /// Reset counters of bytes read and written to zero.
+    pub fn reset_bytes_counters(&self) {
+        for v in [&self.bytes_read, &self.bytes_written]
+            .into_iter()
+            .flatten()
+        {
+            v.store(0, Ordering::Relaxed);
+        }
+    }
 }
@@ -53,6 +53,14 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
     /// Get a value from adapter.
     fn blocking_get(&self, path: &str) -> Result<Option<Value>>;
 
+    /// Check if a value exists in adapter.
+    ///
+    /// Adapters SHOULD override it if they can check existence without
+    /// getting the value.
+    async fn contains(&self, path: &str) -> Result<bool> {
+        Ok(self.get(path).await?.is_some())
+    }
+
     /// Set a value into adapter.
     async fn set(&self, path: &str, value: Value) -> Result<()>;
 
@@ -76,6 +76,7 @@ impl<S: Adapter> Accessor for Backend<S> {
             cap.read_can_next = true;
             cap.read_with_range = true;
             cap.stat = true;
+            cap.exists = true;
         }
 
         if kv_cap.set {
@@ -172,6 +173,16 @@ impl<S: Adapter> Accessor for Backend<S> {
         }
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        let p = build_abs_path(&self.root, path);
+
+        if p.is_empty() || p.ends_with('/') {
+            Ok(true)
+        } else {
+            self.kv.contains(&p).await
+        }
+    }
+
     fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
         let p = build_abs_path(&self.root, path);
 
@@ -0,0 +1,76 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use futures::future;
+use futures::stream;
+use futures::Stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::raw::oio::Page;
+use crate::raw::*;
+use crate::*;
+
+/// Scan all files under given prefix recursively, returns files whose
+/// content md5 matches `checksum`.
+///
+/// `checksum` is the hex encoded md5 of file content, compared case
+/// insensitively. Services only return md5 in [`Metadata::content_md5`],
+/// so sha256 checksums will never match.
+///
+/// # Notes
+///
+/// - Checksums are taken from list responses, which costs O(N) list
+///   requests for N pages of files and no extra stat calls. Files
+///   without content md5 in list responses will never match.
+/// - Matching is done on client side, services like obs don't support
+///   querying by checksum.
+/// - For obs, files uploaded by multipart or encrypted with SSE-C don't
+///   have content md5 since their etags are not md5 of the content. List
+///   responses don't tell whether a file is encrypted with SSE-KMS, so
+///   files encrypted by bucket's default SSE-KMS won't match.
+/// - Listing stops at the first error, which is the last item of the
+///   stream.
+pub fn scan_by_checksum(
+    op: FusedAccessor,
+    prefix: &str,
+    checksum: &str,
+) -> impl Stream<Item = Result<oio::Entry>> {
+    let prefix = normalize_path(prefix);
+    let checksum = checksum.trim_matches('"').to_ascii_lowercase();
+
+    stream::once(async move { op.list(&prefix, OpList::new().with_delimiter("")).await })
+        .map_ok(|(_, pager)| {
+            stream::try_unfold(pager, |mut pager| async move {
+                Ok(pager.next().await?.map(|entries| (entries, pager)))
+            })
+        })
+        .try_flatten()
+        .map_ok(|entries| stream::iter(entries.into_iter().map(Ok)))
+        .try_flatten()
+        .try_filter(move |de| {
+            let meta = de.metadata();
+            let matched = de.mode().is_file()
+                && meta.bit().contains(Metakey::ContentMd5)
+                && meta
+                    .content_md5()
+                    .map(|v| v.eq_ignore_ascii_case(&checksum))
+                    .unwrap_or_default();
+            future::ready(matched)
+        })
+        .boxed()
+}
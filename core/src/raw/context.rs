@@ -0,0 +1,178 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+
+use crate::raw::*;
+use crate::*;
+
+/// TraceContext is the [W3C Trace Context](https://www.w3.org/TR/trace-context/)
+/// of the caller, which will be sent as `traceparent` and `tracestate` headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+    traceparent: String,
+    tracestate: Option<String>,
+}
+
+impl TraceContext {
+    /// Create a new TraceContext with given `traceparent` like
+    /// `00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01`.
+    pub fn new(traceparent: &str) -> Self {
+        Self {
+            traceparent: traceparent.to_string(),
+            tracestate: None,
+        }
+    }
+
+    /// Set the vendor-specific `tracestate`.
+    pub fn with_tracestate(mut self, tracestate: &str) -> Self {
+        self.tracestate = Some(tracestate.to_string());
+        self
+    }
+
+    /// Get the `traceparent`.
+    pub fn traceparent(&self) -> &str {
+        &self.traceparent
+    }
+
+    /// Get the `tracestate`.
+    pub fn tracestate(&self) -> Option<&str> {
+        self.tracestate.as_deref()
+    }
+}
+
+/// RequestContext carries the caller's context that services should
+/// propagate with their requests.
+///
+/// Services that don't support propagation will ignore it. For now, only
+/// `obs` sends it with `read` and `write` requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestContext {
+    trace_context: Option<TraceContext>,
+}
+
+impl RequestContext {
+    /// Create a new empty RequestContext.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the trace context.
+    pub fn with_trace_context(mut self, trace_context: TraceContext) -> Self {
+        self.trace_context = Some(trace_context);
+        self
+    }
+
+    /// Get the trace context.
+    pub fn trace_context(&self) -> Option<&TraceContext> {
+        self.trace_context.as_ref()
+    }
+}
+
+/// ContextualAccessor attaches the [`RequestContext`] to all `read` and
+/// `write` calls which don't carry one.
+///
+/// Build it via [`Accessor::with_context`].
+///
+/// # Examples
+///
+/// ```
+/// use opendal::raw::Accessor;
+/// use opendal::raw::RequestContext;
+/// use opendal::raw::TraceContext;
+/// use opendal::services::Memory;
+/// use opendal::Builder;
+/// use opendal::OperatorBuilder;
+///
+/// # fn main() -> opendal::Result<()> {
+/// let ctx = RequestContext::new().with_trace_context(TraceContext::new(
+///     "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+/// ));
+/// let acc = Memory::default().build()?.with_context(ctx);
+/// let op = OperatorBuilder::new(acc).finish();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContextualAccessor<A: Accessor> {
+    inner: A,
+    ctx: RequestContext,
+}
+
+impl<A: Accessor> ContextualAccessor<A> {
+    pub(crate) fn new(inner: A, ctx: RequestContext) -> Self {
+        Self { inner, ctx }
+    }
+
+    fn read_args(&self, args: OpRead) -> OpRead {
+        match args.request_context() {
+            Some(_) => args,
+            None => args.with_request_context(self.ctx.clone()),
+        }
+    }
+
+    fn write_args(&self, args: OpWrite) -> OpWrite {
+        match args.request_context() {
+            Some(_) => args,
+            None => args.with_request_context(self.ctx.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for ContextualAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Appender = A::Appender;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, self.read_args(args)).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, self.write_args(args)).await
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, self.read_args(args))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, self.write_args(args))
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
@@ -0,0 +1,219 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use futures::StreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// The max count of `delete` calls that will be sent at the same time for
+/// services that don't support batch delete.
+const MAX_CONCURRENT: usize = 32;
+
+/// Delete all given paths and return the result of every path in the
+/// same order.
+///
+/// # Notes
+///
+/// - Duplicated paths will be deleted only once, the result of them will
+///   only be returned at their first occurrence.
+/// - Services with `batch_delete` capability will delete paths in batches
+///   of `batch_max_operations` (default to 1000). If a whole batch request
+///   failed, paths in it will be deleted one by one instead.
+/// - Services without `batch_delete` will delete paths one by one, at most
+///   32 paths will be deleted at the same time.
+/// - Failures don't stop the deletion, all paths will be attempted.
+pub async fn delete_many<'a>(
+    op: FusedAccessor,
+    paths: impl IntoIterator<Item = &'a str>,
+) -> Vec<(String, Option<Error>)> {
+    let mut seen = HashSet::new();
+    let paths: Vec<String> = paths
+        .into_iter()
+        .filter(|v| seen.insert(*v))
+        .map(|v| v.to_string())
+        .collect();
+
+    let cap = op.info().capability();
+    if !cap.batch_delete {
+        return delete_one_by_one(&op, paths).await;
+    }
+
+    let limit = cap.batch_max_operations.unwrap_or(1000).max(1);
+    let mut results = Vec::with_capacity(paths.len());
+    for chunk in paths.chunks(limit) {
+        results.extend(delete_batch(&op, chunk.to_vec()).await);
+    }
+    results
+}
+
+async fn delete_batch(op: &FusedAccessor, paths: Vec<String>) -> Vec<(String, Option<Error>)> {
+    let ops = paths
+        .iter()
+        .map(|v| (v.clone(), OpDelete::default().into()))
+        .collect();
+    let mut replied: HashMap<String, Result<BatchedReply>> = match op.batch(OpBatch::new(ops)).await
+    {
+        Ok(rp) => rp.into_results().into_iter().collect(),
+        Err(_) => return delete_one_by_one(op, paths).await,
+    };
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let err = match replied.remove(&path) {
+                Some(Ok(_)) => None,
+                Some(Err(err)) => Some(err),
+                None => Some(
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "batch delete didn't return result of this path",
+                    )
+                    .with_operation("delete_many")
+                    .with_context("path", &path),
+                ),
+            };
+            (path, err)
+        })
+        .collect()
+}
+
+async fn delete_one_by_one(op: &FusedAccessor, paths: Vec<String>) -> Vec<(String, Option<Error>)> {
+    futures::stream::iter(paths)
+        .map(|path| async move {
+            let err = op.delete(&path, OpDelete::default()).await.err();
+            (path, err)
+        })
+        .buffered(MAX_CONCURRENT)
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// MockService supports batch delete of at most 2 paths, paths start
+    /// with `bad` can't be deleted.
+    #[derive(Debug, Default)]
+    struct MockService {
+        batches: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Accessor for Arc<MockService> {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = ();
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.capability_mut().batch = true;
+            am.capability_mut().batch_delete = true;
+            am.capability_mut().batch_max_operations = Some(2);
+            am
+        }
+
+        async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+            self.batches.fetch_add(1, Ordering::SeqCst);
+            let results = args
+                .into_operation()
+                .into_iter()
+                .map(|(path, _)| {
+                    let res = if path.starts_with("bad") {
+                        Err(Error::new(ErrorKind::PermissionDenied, "denied"))
+                    } else {
+                        Ok(RpDelete::default().into())
+                    };
+                    (path, res)
+                })
+                .collect();
+            Ok(RpBatch::new(results))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_batch() {
+        let srv = Arc::new(MockService::default());
+        let op = OperatorBuilder::new(srv.clone()).finish();
+
+        let results = delete_many(op.into(), ["a", "bad-b", "c", "d", "bad-e"]).await;
+        assert_eq!(srv.batches.load(Ordering::SeqCst), 3);
+
+        let failed: Vec<_> = results
+            .iter()
+            .filter(|(_, err)| err.is_some())
+            .map(|(path, _)| path.as_str())
+            .collect();
+        assert_eq!(results.len(), 5);
+        assert_eq!(failed, vec!["bad-b", "bad-e"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_duplicated() {
+        let srv = Arc::new(MockService::default());
+        let op = OperatorBuilder::new(srv.clone()).finish();
+
+        let results = delete_many(op.into(), ["a", "bad-b", "a", "c", "bad-b"]).await;
+        assert_eq!(srv.batches.load(Ordering::SeqCst), 2);
+
+        let results: Vec<_> = results
+            .iter()
+            .map(|(path, err)| (path.as_str(), err.as_ref().map(|err| err.kind())))
+            .collect();
+        assert_eq!(
+            results,
+            vec![
+                ("a", None),
+                ("bad-b", Some(ErrorKind::PermissionDenied)),
+                ("c", None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_one_by_one() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        let paths: Vec<String> = (0..100).map(|i| format!("file-{i}")).collect();
+        for path in &paths {
+            op.write(path, "Hello").await?;
+        }
+
+        let results = delete_many(op.clone().into(), paths.iter().map(|v| v.as_str())).await;
+        assert_eq!(results.len(), 100);
+        for ((path, err), expected) in results.iter().zip(&paths) {
+            assert_eq!(path, expected);
+            assert!(err.is_none());
+            assert!(!op.is_exist(path).await?);
+        }
+        Ok(())
+    }
+}
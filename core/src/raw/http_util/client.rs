@@ -36,6 +36,12 @@ use crate::Result;
 #[derive(Clone)]
 pub struct HttpClient {
     client: reqwest::Client,
+    /// Client with middleware chain, all requests will be dispatched
+    /// through it if set.
+    #[cfg(feature = "reqwest-middleware")]
+    middleware: Option<reqwest_middleware::ClientWithMiddleware>,
+    #[cfg(feature = "reqwest-middleware")]
+    middlewares: Vec<std::sync::Arc<dyn reqwest_middleware::Middleware>>,
 }
 
 /// We don't want users to know details about our clients.
@@ -52,13 +58,18 @@ impl HttpClient {
     }
 
     /// Build a new http client in async context.
+    #[allow(unused_mut)]
     pub fn build(mut builder: reqwest::ClientBuilder) -> Result<Self> {
-        // Make sure we don't enable auto gzip decompress.
-        builder = builder.no_gzip();
-        // Make sure we don't enable auto brotli decompress.
-        builder = builder.no_brotli();
-        // Make sure we don't enable auto deflate decompress.
-        builder = builder.no_deflate();
+        // Decompression on wasm32 is handled by the `fetch` API itself.
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            // Make sure we don't enable auto gzip decompress.
+            builder = builder.no_gzip();
+            // Make sure we don't enable auto brotli decompress.
+            builder = builder.no_brotli();
+            // Make sure we don't enable auto deflate decompress.
+            builder = builder.no_deflate();
+        }
 
         #[cfg(feature = "trust-dns")]
         let builder = builder.trust_dns(true);
@@ -67,9 +78,32 @@ impl HttpClient {
             client: builder.build().map_err(|err| {
                 Error::new(ErrorKind::Unexpected, "async client build failed").set_source(err)
             })?,
+            #[cfg(feature = "reqwest-middleware")]
+            middleware: None,
+            #[cfg(feature = "reqwest-middleware")]
+            middlewares: vec![],
         })
     }
 
+    /// Add a middleware into the middleware chain of this http client.
+    ///
+    /// All requests will be dispatched through the middleware chain in
+    /// the order they are added.
+    ///
+    /// # Notes
+    ///
+    /// Request with streaming body can't be cloned, so middlewares that
+    /// retry the request (like `reqwest-retry`) may not work as expected.
+    #[cfg(feature = "reqwest-middleware")]
+    pub fn with_middleware(mut self, m: impl reqwest_middleware::Middleware) -> Self {
+        self.middlewares.push(std::sync::Arc::new(m));
+        self.middleware = Some(reqwest_middleware::ClientWithMiddleware::new(
+            self.client.clone(),
+            self.middlewares.clone(),
+        ));
+        self
+    }
+
     /// Get the async client from http client.
     pub fn client(&self) -> reqwest::Client {
         self.client.clone()
@@ -84,45 +118,34 @@ impl HttpClient {
 
         let (parts, body) = req.into_parts();
 
-        let mut req_builder = self
+        let body = match body {
+            AsyncBody::Empty => reqwest::Body::from(""),
+            AsyncBody::Bytes(bs) => reqwest::Body::from(bs),
+            #[cfg(not(target_arch = "wasm32"))]
+            AsyncBody::Stream(s) => reqwest::Body::wrap_stream(s),
+            // `fetch` can't send streaming body, collect it instead.
+            #[cfg(target_arch = "wasm32")]
+            AsyncBody::Stream(s) => {
+                let bs: Vec<bytes::Bytes> = s.try_collect().await?;
+                reqwest::Body::from(bs.concat())
+            }
+        };
+
+        let req_builder = self
             .client
             .request(
                 parts.method,
                 reqwest::Url::from_str(&uri.to_string()).expect("input request url must be valid"),
             )
-            .version(parts.version)
-            .headers(parts.headers);
-
-        req_builder = match body {
-            AsyncBody::Empty => req_builder.body(reqwest::Body::from("")),
-            AsyncBody::Bytes(bs) => req_builder.body(reqwest::Body::from(bs)),
-            AsyncBody::Stream(s) => req_builder.body(reqwest::Body::wrap_stream(s)),
-        };
-
-        let mut resp = req_builder.send().await.map_err(|err| {
-            let is_temporary = !(
-                // Builder related error should not be retried.
-                err.is_builder() ||
-                // Error returned by RedirectPolicy.
-                //
-                // We don't set this by hand, just don't allow retry.
-                err.is_redirect() ||
-                 // We never use `Response::error_for_status`, just don't allow retry.
-                //
-                // Status should be checked by our services.
-                err.is_status()
-            );
-
-            let mut oerr = Error::new(ErrorKind::Unexpected, "send async request")
-                .with_operation("http_util::Client::send_async")
-                .with_context("url", uri.to_string())
-                .set_source(err);
-            if is_temporary {
-                oerr = oerr.set_temporary();
-            }
+            .headers(parts.headers)
+            .body(body);
+        #[cfg(not(target_arch = "wasm32"))]
+        let req_builder = req_builder.version(parts.version);
 
-            oerr
-        })?;
+        let req = req_builder
+            .build()
+            .map_err(|err| new_send_async_error(err, &uri))?;
+        let mut resp = self.execute(req, &uri).await?;
 
         // Get content length from header so that we can check it.
         // If the request method is HEAD, we will ignore this.
@@ -132,8 +155,10 @@ impl HttpClient {
             parse_content_length(resp.headers()).expect("response content length must be valid")
         };
 
-        let mut hr = Response::builder()
-            .version(resp.version())
+        let hr = Response::builder();
+        #[cfg(not(target_arch = "wasm32"))]
+        let hr = hr.version(resp.version());
+        let mut hr = hr
             .status(resp.status())
             // Insert uri into response extension so that we can fetch
             // it later.
@@ -150,6 +175,9 @@ impl HttpClient {
                 .set_source(err)
         });
 
+        #[cfg(target_arch = "wasm32")]
+        let stream = WasmSend(stream);
+
         let body = IncomingAsyncBody::new(
             Box::new(into_stream::from_futures_stream(stream)),
             content_length,
@@ -159,4 +187,149 @@ impl HttpClient {
 
         Ok(resp)
     }
+
+    #[cfg(not(feature = "reqwest-middleware"))]
+    async fn execute(&self, req: reqwest::Request, uri: &http::Uri) -> Result<reqwest::Response> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let fut = self.client.execute(req);
+        #[cfg(target_arch = "wasm32")]
+        let fut = WasmSend(self.client.execute(req));
+
+        fut.await.map_err(|err| new_send_async_error(err, uri))
+    }
+
+    #[cfg(feature = "reqwest-middleware")]
+    async fn execute(&self, req: reqwest::Request, uri: &http::Uri) -> Result<reqwest::Response> {
+        let client = match &self.middleware {
+            Some(client) => client,
+            None => {
+                return self
+                    .client
+                    .execute(req)
+                    .await
+                    .map_err(|err| new_send_async_error(err, uri))
+            }
+        };
+
+        client.execute(req).await.map_err(|err| match err {
+            reqwest_middleware::Error::Reqwest(err) => new_send_async_error(err, uri),
+            reqwest_middleware::Error::Middleware(err) => {
+                Error::new(ErrorKind::Unexpected, "send async request via middleware")
+                    .with_operation("http_util::Client::send_async")
+                    .with_context("url", uri.to_string())
+                    .set_source(err)
+            }
+        })
+    }
+}
+
+fn new_send_async_error(err: reqwest::Error, uri: &http::Uri) -> Error {
+    let is_temporary = !(
+        // Builder related error should not be retried.
+        err.is_builder() ||
+        // Error returned by RedirectPolicy.
+        //
+        // We don't set this by hand, just don't allow retry.
+        err.is_redirect() ||
+         // We never use `Response::error_for_status`, just don't allow retry.
+        //
+        // Status should be checked by our services.
+        err.is_status()
+    );
+
+    let mut oerr = Error::new(ErrorKind::Unexpected, "send async request")
+        .with_operation("http_util::Client::send_async")
+        .with_context("url", uri.to_string())
+        .set_source(err);
+    if is_temporary {
+        oerr = oerr.set_temporary();
+    }
+
+    oerr
+}
+
+/// WasmSend marks futures and streams returned by reqwest as `Send`.
+///
+/// reqwest's wasm backend is built on `JsValue` which is `!Send`, but
+/// wasm32 without `atomics` is single threaded so they will never be
+/// moved across threads.
+///
+/// With `atomics` enabled, wasm could be multi-threaded, so the wrapper
+/// is not `Send` there and users will get a compile error instead.
+#[cfg(target_arch = "wasm32")]
+#[pin_project::pin_project]
+struct WasmSend<T>(#[pin] T);
+
+#[cfg(all(target_arch = "wasm32", not(target_feature = "atomics")))]
+unsafe impl<T> Send for WasmSend<T> {}
+
+#[cfg(all(target_arch = "wasm32", not(target_feature = "atomics")))]
+unsafe impl<T> Sync for WasmSend<T> {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: std::future::Future> std::future::Future for WasmSend<T> {
+    type Output = T::Output;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        self.project().0.poll(cx)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: futures::Stream> futures::Stream for WasmSend<T> {
+    type Item = T::Item;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.project().0.poll_next(cx)
+    }
+}
+
+#[cfg(all(test, feature = "reqwest-middleware"))]
+mod tests {
+    use async_trait::async_trait;
+    use reqwest_middleware::Middleware;
+    use reqwest_middleware::Next;
+    use task_local_extensions::Extensions;
+
+    use super::*;
+
+    /// Middleware that returns response directly without sending request.
+    struct MockMiddleware;
+
+    #[async_trait]
+    impl Middleware for MockMiddleware {
+        async fn handle(
+            &self,
+            req: reqwest::Request,
+            _: &mut Extensions,
+            _: Next<'_>,
+        ) -> reqwest_middleware::Result<reqwest::Response> {
+            let resp = http::Response::builder()
+                .status(http::StatusCode::OK)
+                .header("x-mock-path", req.url().path())
+                .body("")
+                .expect("response must build succeed");
+            Ok(resp.into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_with_middleware() -> Result<()> {
+        let client = HttpClient::new()?.with_middleware(MockMiddleware);
+
+        let req = Request::get("http://127.0.0.1:1/path/to/file")
+            .body(AsyncBody::Empty)
+            .expect("request must build succeed");
+        let resp = client.send(req).await?;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(resp.headers()["x-mock-path"], "/path/to/file");
+        Ok(())
+    }
 }
@@ -249,6 +249,31 @@ pub fn parse_into_metadata(path: &str, headers: &HeaderMap) -> Result<Metadata>
     Ok(m)
 }
 
+/// Parse given headers into the custom metadata of Metadata.
+///
+/// Headers that are not present will be ignored.
+pub fn parse_into_custom_metadata(
+    headers: &HeaderMap,
+    names: &[&str],
+    m: &mut Metadata,
+) -> Result<()> {
+    for name in names {
+        if let Some(v) = headers.get(*name) {
+            let v = v.to_str().map_err(|e| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "header value is not valid utf-8 string",
+                )
+                .with_operation("http_util::parse_into_custom_metadata")
+                .set_source(e)
+            })?;
+            m.set_custom(name, v);
+        }
+    }
+
+    Ok(())
+}
+
 /// format content md5 header by given input.
 pub fn format_content_md5(bs: &[u8]) -> String {
     let mut hasher = md5::Md5::new();
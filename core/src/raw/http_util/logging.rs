@@ -0,0 +1,275 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use http::HeaderMap;
+use http::Request;
+use http::Response;
+use http::Uri;
+use log::debug;
+
+use super::parse_content_length;
+use crate::raw::Operation;
+
+/// Query parameters that carry credentials or signatures and must not
+/// be logged.
+///
+/// Both OBS/OSS style (`Signature`, `AccessKeyId`) and AWS SigV4 style
+/// (`X-Amz-Signature`, `X-Amz-Credential`) names are covered. Matching
+/// is case-insensitive.
+const SENSITIVE_QUERY_PARAMS: &[&str] = &[
+    "signature",
+    "accesskeyid",
+    "x-amz-signature",
+    "x-amz-credential",
+    "x-amz-security-token",
+    "x-obs-security-token",
+];
+
+/// Header name patterns that will be masked by [`MaskedHeaders`] by default.
+pub const DEFAULT_MASKED_HEADERS: &[HeaderPattern] = &[
+    HeaderPattern::Exact("authorization"),
+    HeaderPattern::Exact("proxy-authorization"),
+    HeaderPattern::Exact("cookie"),
+    HeaderPattern::Exact("set-cookie"),
+    HeaderPattern::Exact("x-amz-security-token"),
+    HeaderPattern::Exact("x-obs-security-token"),
+    HeaderPattern::Prefix("x-amz-server-side-encryption-customer-key"),
+    HeaderPattern::Prefix("x-amz-copy-source-server-side-encryption-customer-key"),
+    HeaderPattern::Prefix("x-obs-server-side-encryption-customer-key"),
+    HeaderPattern::Prefix("x-obs-copy-source-server-side-encryption-customer-key"),
+];
+
+/// HeaderPattern decides whether a header name should be masked.
+///
+/// Header names are compared in lowercase, so patterns must be lowercase too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderPattern {
+    /// Header name must equal to the given value.
+    Exact(&'static str),
+    /// Header name must start with the given value.
+    Prefix(&'static str),
+}
+
+impl HeaderPattern {
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            HeaderPattern::Exact(v) => name == *v,
+            HeaderPattern::Prefix(v) => name.starts_with(v),
+        }
+    }
+}
+
+/// MaskedHeaders wraps a [`HeaderMap`] so that it's safe to be logged.
+///
+/// Values of headers matching any of the patterns will be replaced
+/// by `<redacted>`. [`DEFAULT_MASKED_HEADERS`] is used unless
+/// [`MaskedHeaders::with_patterns`] is called.
+pub struct MaskedHeaders<'a> {
+    headers: &'a HeaderMap,
+    patterns: &'a [HeaderPattern],
+}
+
+impl<'a> MaskedHeaders<'a> {
+    /// Create a new MaskedHeaders with default patterns.
+    pub fn new(headers: &'a HeaderMap) -> Self {
+        Self {
+            headers,
+            patterns: DEFAULT_MASKED_HEADERS,
+        }
+    }
+
+    /// Replace the patterns used to decide which headers to mask.
+    pub fn with_patterns(mut self, patterns: &'a [HeaderPattern]) -> Self {
+        self.patterns = patterns;
+        self
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.headers.iter().map(|(k, v)| {
+            let name = k.as_str();
+            let value = if self.patterns.iter().any(|p| p.matches(name)) {
+                "<redacted>"
+            } else {
+                v.to_str().unwrap_or("<non-utf8>")
+            };
+            (name, value)
+        })
+    }
+}
+
+impl Debug for MaskedHeaders<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.entries()).finish()
+    }
+}
+
+impl Display for MaskedHeaders<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (idx, (k, v)) in self.entries().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{k}: {v}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Log the given request at debug level.
+///
+/// Only method, sanitized url and content-length are logged. Headers are
+/// never logged since they carry `Authorization`, SSE-C keys and any
+/// header injected by users, which can't be masked reliably. Use
+/// [`MaskedHeaders`] to log headers explicitly when needed.
+pub fn log_request_debug<T>(op: Operation, path: &str, req: &Request<T>) {
+    debug!(
+        "service request: op={} path={} method={} url={} content_length={:?}",
+        op,
+        path,
+        req.method(),
+        sanitize_uri(req.uri()),
+        parse_content_length(req.headers()).ok().flatten(),
+    );
+}
+
+/// Log the given response at debug level.
+///
+/// Only status and content-length are logged. Headers are never logged
+/// since they could contain `Set-Cookie` or other sensitive values.
+pub fn log_response_debug<T>(op: Operation, path: &str, resp: &Response<T>) {
+    debug!(
+        "service response: op={} path={} status={} content_length={:?}",
+        op,
+        path,
+        resp.status(),
+        parse_content_length(resp.headers()).ok().flatten(),
+    );
+}
+
+/// Strip sensitive query params from uri so that it's safe to be logged.
+pub(crate) fn sanitize_uri(uri: &Uri) -> String {
+    let query = match uri.query() {
+        Some(query) => query,
+        None => return uri.to_string(),
+    };
+
+    let query = query
+        .split('&')
+        .filter(|kv| {
+            let key = kv.split_once('=').map(|(k, _)| k).unwrap_or(kv);
+            !SENSITIVE_QUERY_PARAMS
+                .iter()
+                .any(|v| key.eq_ignore_ascii_case(v))
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let mut s = String::new();
+    if let Some(scheme) = uri.scheme_str() {
+        s.push_str(scheme);
+        s.push_str("://");
+    }
+    if let Some(authority) = uri.authority() {
+        s.push_str(authority.as_str());
+    }
+    s.push_str(uri.path());
+    if !query.is_empty() {
+        s.push('?');
+        s.push_str(&query);
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::AUTHORIZATION;
+    use http::header::CONTENT_TYPE;
+    use http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_sanitize_uri() {
+        let cases = vec![
+            (
+                "no query",
+                "https://bucket.obs.example.com/path/to/file",
+                "https://bucket.obs.example.com/path/to/file",
+            ),
+            (
+                "obs query sign",
+                "https://bucket.obs.example.com/file?AccessKeyId=ak&Expires=1&Signature=sig",
+                "https://bucket.obs.example.com/file?Expires=1",
+            ),
+            (
+                "s3 query sign",
+                "https://bucket.s3.example.com/file?X-Amz-Credential=ak&X-Amz-Signature=sig",
+                "https://bucket.s3.example.com/file",
+            ),
+            (
+                "normal query",
+                "https://bucket.obs.example.com/?versions&prefix=abc&max-keys=10",
+                "https://bucket.obs.example.com/?versions&prefix=abc&max-keys=10",
+            ),
+        ];
+
+        for (name, input, expected) in cases {
+            let uri: Uri = input.parse().expect("uri must be valid");
+            assert_eq!(sanitize_uri(&uri), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_masked_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_static("OBS access_key:signature"),
+        );
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        headers.insert(
+            "x-obs-server-side-encryption-customer-key",
+            HeaderValue::from_static("c2VjcmV0"),
+        );
+        headers.insert(
+            "x-obs-copy-source-server-side-encryption-customer-key",
+            HeaderValue::from_static("c2VjcmV0"),
+        );
+
+        for output in [
+            format!("{}", MaskedHeaders::new(&headers)),
+            format!("{:?}", MaskedHeaders::new(&headers)),
+        ] {
+            assert!(!output.contains("signature"), "{output}");
+            assert!(!output.contains("c2VjcmV0"), "{output}");
+            assert!(output.contains("text/plain"), "{output}");
+            assert_eq!(output.matches("<redacted>").count(), 3, "{output}");
+        }
+
+        let output = format!(
+            "{}",
+            MaskedHeaders::new(&headers).with_patterns(&[HeaderPattern::Exact("content-type")])
+        );
+        assert!(output.contains("OBS access_key:signature"), "{output}");
+        assert!(!output.contains("text/plain"), "{output}");
+    }
+}
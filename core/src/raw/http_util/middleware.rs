@@ -0,0 +1,41 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use http::Request;
+
+use crate::raw::*;
+
+/// RequestMiddleware processes requests before they are signed and sent.
+///
+/// It's used to inject custom headers like audit trail IDs into requests.
+/// Headers added by middleware participate in the signature since it's
+/// called before signing.
+///
+/// Closures like `Fn(&mut Request<AsyncBody>)` implement it directly.
+pub trait RequestMiddleware: Send + Sync + 'static {
+    /// Process the request before signing.
+    fn process(&self, req: &mut Request<AsyncBody>);
+}
+
+impl<F> RequestMiddleware for F
+where
+    F: Fn(&mut Request<AsyncBody>) + Send + Sync + 'static,
+{
+    fn process(&self, req: &mut Request<AsyncBody>) {
+        self(req)
+    }
+}
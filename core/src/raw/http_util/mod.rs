@@ -40,6 +40,7 @@ pub use header::parse_content_md5;
 pub use header::parse_content_range;
 pub use header::parse_content_type;
 pub use header::parse_etag;
+pub use header::parse_into_custom_metadata;
 pub use header::parse_into_metadata;
 pub use header::parse_last_modified;
 pub use header::parse_location;
@@ -47,12 +48,22 @@ pub use header::parse_location;
 mod uri;
 pub use uri::percent_encode_path;
 
+mod middleware;
+pub use middleware::RequestMiddleware;
+
 mod error;
 pub use error::new_request_build_error;
 pub use error::new_request_credential_error;
 pub use error::new_request_sign_error;
 pub use error::with_error_response_context;
 
+mod logging;
+pub use logging::log_request_debug;
+pub use logging::log_response_debug;
+pub use logging::HeaderPattern;
+pub use logging::MaskedHeaders;
+pub use logging::DEFAULT_MASKED_HEADERS;
+
 mod bytes_range;
 pub use bytes_range::BytesRange;
 
@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 
 use async_trait::async_trait;
+use bytes::Bytes;
 
 use crate::raw::*;
 use crate::*;
@@ -151,6 +152,10 @@ pub trait LayeredAccessor: Send + Sync + Debug + Unpin + 'static {
         self.inner().create_dir(path, args).await
     }
 
+    async fn ensure_dir(&self, path: &str) -> Result<()> {
+        self.inner().ensure_dir(path).await
+    }
+
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)>;
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)>;
@@ -165,16 +170,133 @@ pub trait LayeredAccessor: Send + Sync + Debug + Unpin + 'static {
         self.inner().rename(from, to, args).await
     }
 
+    async fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        self.inner().create_symlink(src, dst).await
+    }
+
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         self.inner().stat(path, args).await
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        self.inner().object_exists(path).await
+    }
+
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.inner().delete(path, args).await
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)>;
 
+    async fn list_versions(
+        &self,
+        path: &str,
+        args: OpListVersions,
+    ) -> Result<(RpListVersions, oio::Pager)> {
+        self.inner().list_versions(path, args).await
+    }
+
+    async fn create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        self.inner().create_multipart(path, args).await
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        bs: Bytes,
+    ) -> Result<RpWriteMultipart> {
+        self.inner().write_multipart(path, args, bs).await
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.inner().complete_multipart(path, args).await
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        self.inner().abort_multipart(path, args).await
+    }
+
+    async fn touch(&self, path: &str, args: OpTouch) -> Result<RpTouch> {
+        self.inner().touch(path, args).await
+    }
+
+    async fn get_lifecycle_rules(&self, args: OpGetLifecycleRules) -> Result<RpGetLifecycleRules> {
+        self.inner().get_lifecycle_rules(args).await
+    }
+
+    async fn set_lifecycle_rules(&self, args: OpSetLifecycleRules) -> Result<RpSetLifecycleRules> {
+        self.inner().set_lifecycle_rules(args).await
+    }
+
+    async fn get_cors_config(&self, args: OpGetCorsConfig) -> Result<RpGetCorsConfig> {
+        self.inner().get_cors_config(args).await
+    }
+
+    async fn set_cors_config(&self, args: OpSetCorsConfig) -> Result<RpSetCorsConfig> {
+        self.inner().set_cors_config(args).await
+    }
+
+    async fn get_bucket_acl(&self, args: OpGetBucketAcl) -> Result<RpGetBucketAcl> {
+        self.inner().get_bucket_acl(args).await
+    }
+
+    async fn set_bucket_acl(&self, args: OpSetBucketAcl) -> Result<RpSetBucketAcl> {
+        self.inner().set_bucket_acl(args).await
+    }
+
+    async fn head(&self, path: &str, args: OpHead) -> Result<RpHead> {
+        self.inner().head(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.inner().get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        self.inner().set_tags(path, args).await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        self.inner().update_metadata(path, args).await
+    }
+
+    async fn get_object_lock(&self, path: &str, args: OpGetObjectLock) -> Result<RpGetObjectLock> {
+        self.inner().get_object_lock(path, args).await
+    }
+
+    async fn lock_object(&self, path: &str, args: OpLockObject) -> Result<RpLockObject> {
+        self.inner().lock_object(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.inner().presign_post(path, args).await
+    }
+
+    async fn get_restore_status(
+        &self,
+        path: &str,
+        args: OpGetRestoreStatus,
+    ) -> Result<RpGetRestoreStatus> {
+        self.inner().get_restore_status(path, args).await
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         self.inner().batch(args).await
     }
@@ -199,6 +321,10 @@ pub trait LayeredAccessor: Send + Sync + Debug + Unpin + 'static {
         self.inner().blocking_rename(from, to, args)
     }
 
+    fn blocking_create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        self.inner().blocking_create_symlink(src, dst)
+    }
+
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         self.inner().blocking_stat(path, args)
     }
@@ -228,6 +354,10 @@ impl<L: LayeredAccessor> Accessor for L {
         (self as &L).create_dir(path, args).await
     }
 
+    async fn ensure_dir(&self, path: &str) -> Result<()> {
+        (self as &L).ensure_dir(path).await
+    }
+
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         (self as &L).read(path, args).await
     }
@@ -248,10 +378,18 @@ impl<L: LayeredAccessor> Accessor for L {
         (self as &L).rename(from, to, args).await
     }
 
+    async fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        (self as &L).create_symlink(src, dst).await
+    }
+
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         (self as &L).stat(path, args).await
     }
 
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        (self as &L).object_exists(path).await
+    }
+
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         (self as &L).delete(path, args).await
     }
@@ -260,6 +398,115 @@ impl<L: LayeredAccessor> Accessor for L {
         (self as &L).list(path, args).await
     }
 
+    async fn list_versions(
+        &self,
+        path: &str,
+        args: OpListVersions,
+    ) -> Result<(RpListVersions, oio::Pager)> {
+        (self as &L).list_versions(path, args).await
+    }
+
+    async fn create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        (self as &L).create_multipart(path, args).await
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        bs: Bytes,
+    ) -> Result<RpWriteMultipart> {
+        (self as &L).write_multipart(path, args, bs).await
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        (self as &L).complete_multipart(path, args).await
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        (self as &L).abort_multipart(path, args).await
+    }
+
+    async fn touch(&self, path: &str, args: OpTouch) -> Result<RpTouch> {
+        (self as &L).touch(path, args).await
+    }
+
+    async fn get_lifecycle_rules(&self, args: OpGetLifecycleRules) -> Result<RpGetLifecycleRules> {
+        (self as &L).get_lifecycle_rules(args).await
+    }
+
+    async fn set_lifecycle_rules(&self, args: OpSetLifecycleRules) -> Result<RpSetLifecycleRules> {
+        (self as &L).set_lifecycle_rules(args).await
+    }
+
+    async fn get_cors_config(&self, args: OpGetCorsConfig) -> Result<RpGetCorsConfig> {
+        (self as &L).get_cors_config(args).await
+    }
+
+    async fn set_cors_config(&self, args: OpSetCorsConfig) -> Result<RpSetCorsConfig> {
+        (self as &L).set_cors_config(args).await
+    }
+
+    async fn get_bucket_acl(&self, args: OpGetBucketAcl) -> Result<RpGetBucketAcl> {
+        (self as &L).get_bucket_acl(args).await
+    }
+
+    async fn set_bucket_acl(&self, args: OpSetBucketAcl) -> Result<RpSetBucketAcl> {
+        (self as &L).set_bucket_acl(args).await
+    }
+
+    async fn head(&self, path: &str, args: OpHead) -> Result<RpHead> {
+        (self as &L).head(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        (self as &L).get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        (self as &L).set_tags(path, args).await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        (self as &L).update_metadata(path, args).await
+    }
+
+    async fn get_object_lock(&self, path: &str, args: OpGetObjectLock) -> Result<RpGetObjectLock> {
+        (self as &L).get_object_lock(path, args).await
+    }
+
+    async fn lock_object(&self, path: &str, args: OpLockObject) -> Result<RpLockObject> {
+        (self as &L).lock_object(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        (self as &L).presign_post(path, args).await
+    }
+
+    async fn get_restore_status(
+        &self,
+        path: &str,
+        args: OpGetRestoreStatus,
+    ) -> Result<RpGetRestoreStatus> {
+        (self as &L).get_restore_status(path, args).await
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         (self as &L).batch(args).await
     }
@@ -288,6 +535,10 @@ impl<L: LayeredAccessor> Accessor for L {
         (self as &L).blocking_rename(from, to, args)
     }
 
+    fn blocking_create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        (self as &L).blocking_create_symlink(src, dst)
+    }
+
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         (self as &L).blocking_stat(path, args)
     }
@@ -0,0 +1,199 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::ops::AddAssign;
+
+use futures::TryStreamExt;
+use log::warn;
+
+use crate::raw::oio::Page;
+use crate::raw::*;
+use crate::*;
+
+/// ListBatchStats is the result of [`ListBatchProcessor::process_all`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListBatchStats {
+    /// Count of entries processed successfully.
+    pub processed: usize,
+    /// Count of entries that the processing function returned an error.
+    pub errored: usize,
+    /// Count of dirs skipped without processing.
+    pub skipped: usize,
+}
+
+impl AddAssign for ListBatchStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.processed += rhs.processed;
+        self.errored += rhs.errored;
+        self.skipped += rhs.skipped;
+    }
+}
+
+/// ListBatchProcessor processes files returned by a pager in batches.
+///
+/// Entries are pulled from the pager only when there is room for a new
+/// batch, so a slow processing function will pause listing instead of
+/// buffering all entries in memory.
+///
+/// # Notes
+///
+/// - Dirs are skipped, files are passed to the processing function.
+/// - Errors returned by the processing function will be logged and
+///   counted, errors returned by the pager will stop processing.
+pub struct ListBatchProcessor<F> {
+    pager: oio::Pager,
+    f: F,
+}
+
+impl<F, Fut> ListBatchProcessor<F>
+where
+    F: Fn(oio::Entry) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    /// Create a new ListBatchProcessor which calls `f` on every file
+    /// returned by `pager`.
+    pub fn new(pager: oio::Pager, f: F) -> Self {
+        Self { pager, f }
+    }
+
+    /// Process all entries, files in the same batch are processed
+    /// concurrently.
+    ///
+    /// At most `max_concurrent_batches` batches of `batch_size` entries
+    /// will be processed at the same time. Both of them will be treated
+    /// as `1` if `0` is given.
+    pub async fn process_all(
+        &mut self,
+        batch_size: usize,
+        max_concurrent_batches: usize,
+    ) -> Result<ListBatchStats> {
+        let batch_size = batch_size.max(1);
+        let f = &self.f;
+
+        let state = (&mut self.pager, VecDeque::new(), false);
+        let batches = futures::stream::try_unfold(state, |(pager, mut buf, mut done)| async move {
+            while !done && buf.len() < batch_size {
+                match pager.next().await? {
+                    Some(entries) => buf.extend(entries),
+                    None => done = true,
+                }
+            }
+            if buf.is_empty() {
+                return Ok(None);
+            }
+
+            let batch: Vec<oio::Entry> = buf.drain(..batch_size.min(buf.len())).collect();
+            Ok(Some((batch, (pager, buf, done))))
+        });
+
+        batches
+            .map_ok(|batch| process_batch(f, batch))
+            .try_buffer_unordered(max_concurrent_batches.max(1))
+            .try_fold(ListBatchStats::default(), |mut stats, v| async move {
+                stats += v;
+                Ok(stats)
+            })
+            .await
+    }
+}
+
+/// Process all files in given batch concurrently.
+async fn process_batch<F, Fut>(f: &F, batch: Vec<oio::Entry>) -> Result<ListBatchStats>
+where
+    F: Fn(oio::Entry) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let mut stats = ListBatchStats::default();
+
+    let mut tasks = vec![];
+    for de in batch {
+        if de.mode().is_dir() {
+            stats.skipped += 1;
+            continue;
+        }
+
+        let path = de.path().to_string();
+        let fut = f(de);
+        tasks.push(async move { (path, fut.await) });
+    }
+
+    for (path, res) in futures::future::join_all(tasks).await {
+        match res {
+            Ok(()) => stats.processed += 1,
+            Err(err) => {
+                warn!("list batch process {path} failed: {err}");
+                stats.errored += 1;
+            }
+        }
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_list_batch_processor() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        for i in 0..10 {
+            op.write(&format!("dir/file-{i}"), "Hello").await?;
+        }
+        op.create_dir("dir/sub/").await?;
+
+        let acc: FusedAccessor = op.into();
+        let (_, pager) = acc.list("dir/", OpList::new()).await?;
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let mut processor = ListBatchProcessor::new(pager, |de: oio::Entry| {
+            let running = running.clone();
+            let max_running = max_running.clone();
+            async move {
+                let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(current, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+                running.fetch_sub(1, Ordering::SeqCst);
+
+                if de.path().ends_with("-3") {
+                    return Err(Error::new(ErrorKind::Unexpected, "process failed"));
+                }
+                Ok(())
+            }
+        });
+
+        let stats = processor.process_all(3, 2).await?;
+        assert_eq!(
+            stats,
+            ListBatchStats {
+                processed: 9,
+                errored: 1,
+                skipped: 1,
+            }
+        );
+        assert!(max_running.load(Ordering::SeqCst) <= 6);
+        Ok(())
+    }
+}
@@ -31,6 +31,14 @@ pub use accessor::Accessor;
 pub use accessor::AccessorInfo;
 pub use accessor::FusedAccessor;
 
+mod context;
+pub use context::ContextualAccessor;
+pub use context::RequestContext;
+pub use context::TraceContext;
+
+mod swappable;
+pub use swappable::SwappableAccessor;
+
 mod layer;
 pub use layer::Layer;
 pub use layer::LayeredAccessor;
@@ -41,6 +49,7 @@ mod path;
 pub use path::build_abs_path;
 pub use path::build_rel_path;
 pub use path::build_rooted_abs_path;
+pub use path::common_prefix;
 pub use path::get_basename;
 pub use path::get_parent;
 pub use path::normalize_path;
@@ -53,6 +62,30 @@ pub use operation::Operation;
 mod version;
 pub use version::VERSION;
 
+mod versions;
+pub use versions::list_all_versions_sorted;
+pub use versions::VersionedEntry;
+
+mod delete_many;
+pub use delete_many::delete_many;
+
+mod prefix_size;
+pub use prefix_size::scan_prefix_size;
+
+mod parallel_list;
+pub use parallel_list::parallel_list;
+
+mod list_batch;
+pub use list_batch::ListBatchProcessor;
+pub use list_batch::ListBatchStats;
+
+mod checksum_scan;
+pub use checksum_scan::scan_by_checksum;
+
+mod pool;
+pub use pool::ConnectionPool;
+pub use pool::ConnectionPoolConfig;
+
 mod rps;
 pub use rps::*;
 
@@ -75,6 +75,17 @@ impl Entry {
         self.meta.mode()
     }
 
+    /// Set metadata for entry.
+    pub fn set_metadata(&mut self, meta: Metadata) -> &mut Self {
+        self.meta = meta;
+        self
+    }
+
+    /// Get the metadata of entry.
+    pub fn metadata(&self) -> &Metadata {
+        &self.meta
+    }
+
     /// Consume self to convert into an Entry.
     ///
     /// NOTE: implement this by hand to avoid leaking raw entry to end-users.
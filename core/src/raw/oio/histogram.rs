@@ -0,0 +1,261 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::SeekFrom;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Instant;
+
+use bytes::Bytes;
+use metrics::register_histogram;
+use metrics::Histogram;
+
+use crate::raw::*;
+use crate::*;
+
+/// read_chunk_bytes records the size of bytes requested by every read.
+static METRIC_READ_CHUNK_BYTES: &str = "opendal.read_chunk.bytes";
+/// read_chunk_latency records the seconds until bytes are available for
+/// every read.
+static METRIC_READ_CHUNK_LATENCY: &str = "opendal.read_chunk.latency";
+
+/// HistogramReader records the size and latency of every chunk read from
+/// the inner reader via the [metrics](https://docs.rs/metrics/) facade.
+///
+/// # Metrics
+///
+/// - `opendal.read_chunk.bytes`: Size of bytes requested by `poll_read`,
+///   or size of bytes returned by `poll_next`.
+/// - `opendal.read_chunk.latency`: Seconds from the first poll until the
+///   read is ready, including the time spent on `Pending`.
+///
+/// # Notes
+///
+/// Histograms are registered while creating the reader, polling doesn't
+/// buffer bytes or allocate. Readers are usually created per range read,
+/// so wrapping the reader returned by each range read will record chunks
+/// of that range.
+pub struct HistogramReader<R> {
+    inner: R,
+
+    bytes: Histogram,
+    latency: Histogram,
+    start: Option<Instant>,
+}
+
+impl<R> HistogramReader<R> {
+    /// Create a new HistogramReader.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            bytes: register_histogram!(METRIC_READ_CHUNK_BYTES),
+            latency: register_histogram!(METRIC_READ_CHUNK_LATENCY),
+            start: None,
+        }
+    }
+
+    /// Record a ready read of `size` bytes and reset the timer.
+    fn record(&mut self, size: usize) {
+        if let Some(start) = self.start.take() {
+            self.bytes.record(size as f64);
+            self.latency.record(start.elapsed().as_secs_f64());
+        }
+    }
+}
+
+impl<R: oio::Read> oio::Read for HistogramReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        // Keep the start of the first poll if last poll is pending.
+        self.start.get_or_insert_with(Instant::now);
+
+        let res = self.inner.poll_read(cx, buf);
+        if res.is_ready() {
+            self.record(buf.len());
+        }
+        res
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        // Pending read is abandoned by seek.
+        self.start = None;
+
+        self.inner.poll_seek(cx, pos)
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        self.start.get_or_insert_with(Instant::now);
+
+        let res = self.inner.poll_next(cx);
+        if let Poll::Ready(v) = &res {
+            let size = match v {
+                Some(Ok(bs)) => bs.len(),
+                _ => 0,
+            };
+            self.record(size);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use futures::task::noop_waker_ref;
+    use metrics::Counter;
+    use metrics::Gauge;
+    use metrics::HistogramFn;
+    use metrics::Key;
+    use metrics::KeyName;
+    use metrics::Recorder;
+    use metrics::SharedString;
+    use metrics::Unit;
+    use oio::Read;
+
+    use super::*;
+
+    thread_local! {
+        /// Samples of histograms recorded by current thread.
+        static SAMPLES: RefCell<HashMap<String, Vec<f64>>> = RefCell::new(HashMap::new());
+    }
+
+    /// Recorder keeps histogram samples per thread, so that tests running
+    /// in parallel won't affect each other.
+    struct TestRecorder;
+
+    struct TestHistogram(String);
+
+    impl HistogramFn for TestHistogram {
+        fn record(&self, value: f64) {
+            SAMPLES.with(|v| {
+                v.borrow_mut()
+                    .entry(self.0.clone())
+                    .or_default()
+                    .push(value)
+            });
+        }
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, _: &Key) -> Counter {
+            Counter::noop()
+        }
+
+        fn register_gauge(&self, _: &Key) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, key: &Key) -> Histogram {
+            Histogram::from_arc(Arc::new(TestHistogram(key.name().to_string())))
+        }
+    }
+
+    /// Install the test recorder and clean up samples of current thread.
+    fn install_recorder() {
+        // The recorder can only be installed once, later calls are ignored.
+        let _ = metrics::set_recorder(&TestRecorder);
+        SAMPLES.with(|v| v.borrow_mut().clear());
+    }
+
+    fn samples(name: &str) -> Vec<f64> {
+        SAMPLES.with(|v| v.borrow().get(name).cloned().unwrap_or_default())
+    }
+
+    /// Reader returns `Pending` once before every read.
+    struct PendingReader {
+        inner: oio::Cursor,
+        pending: bool,
+    }
+
+    impl Read for PendingReader {
+        fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+            self.pending = !self.pending;
+            if self.pending {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            self.inner.poll_read(cx, buf)
+        }
+
+        fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+            self.inner.poll_seek(cx, pos)
+        }
+
+        fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+            self.inner.poll_next(cx)
+        }
+    }
+
+    #[test]
+    fn test_histogram_reader_read() {
+        install_recorder();
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        let mut r = HistogramReader::new(PendingReader {
+            inner: oio::Cursor::from(b"Hello, World!".to_vec()),
+            pending: false,
+        });
+
+        let mut buf = [0; 8];
+        assert!(r.poll_read(&mut cx, &mut buf).is_pending());
+        assert!(samples(METRIC_READ_CHUNK_BYTES).is_empty());
+        assert!(samples(METRIC_READ_CHUNK_LATENCY).is_empty());
+
+        // Pending poll and the following ready poll are recorded as one read.
+        let n = match r.poll_read(&mut cx, &mut buf) {
+            Poll::Ready(res) => res.expect("read must success"),
+            Poll::Pending => panic!("read must be ready"),
+        };
+        assert_eq!(&buf[..n], b"Hello, W");
+        assert_eq!(samples(METRIC_READ_CHUNK_BYTES), vec![8.0]);
+        assert_eq!(samples(METRIC_READ_CHUNK_LATENCY).len(), 1);
+
+        let mut buf = [0; 4];
+        assert!(r.poll_read(&mut cx, &mut buf).is_pending());
+        assert!(r.poll_read(&mut cx, &mut buf).is_ready());
+        assert_eq!(&buf, b"orld");
+        assert_eq!(samples(METRIC_READ_CHUNK_BYTES), vec![8.0, 4.0]);
+        assert_eq!(samples(METRIC_READ_CHUNK_LATENCY).len(), 2);
+    }
+
+    #[test]
+    fn test_histogram_reader_next() {
+        install_recorder();
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        let mut r = HistogramReader::new(oio::Cursor::from(b"Hello, World!".to_vec()));
+
+        let bs = match r.poll_next(&mut cx) {
+            Poll::Ready(Some(res)) => res.expect("read must success"),
+            _ => panic!("next must be ready with bytes"),
+        };
+        assert_eq!(bs, Bytes::from("Hello, World!"));
+        assert!(matches!(r.poll_next(&mut cx), Poll::Ready(None)));
+
+        // EOF is recorded as an empty chunk.
+        assert_eq!(samples(METRIC_READ_CHUNK_BYTES), vec![13.0, 0.0]);
+        assert_eq!(samples(METRIC_READ_CHUNK_LATENCY).len(), 2);
+    }
+}
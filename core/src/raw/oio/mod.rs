@@ -33,6 +33,11 @@ pub use read::Reader;
 pub mod into_blocking_reader;
 pub mod into_reader;
 
+#[cfg(feature = "layers-metrics")]
+mod histogram;
+#[cfg(feature = "layers-metrics")]
+pub use histogram::HistogramReader;
+
 mod write;
 pub use write::BlockingWrite;
 pub use write::BlockingWriter;
@@ -40,6 +45,10 @@ pub use write::Write;
 pub use write::WriteOperation;
 pub use write::Writer;
 
+mod multipart;
+pub use multipart::ChunkedMultipartWriter;
+pub use multipart::MultipartWriter;
+
 mod append;
 pub use append::Append;
 pub use append::AppendOperation;
@@ -76,3 +85,11 @@ pub use to_flat_pager::ToFlatPager;
 mod to_hierarchy_pager;
 pub use to_hierarchy_pager::to_hierarchy_pager;
 pub use to_hierarchy_pager::ToHierarchyPager;
+
+mod to_size_filter_pager;
+pub use to_size_filter_pager::to_size_filter_pager;
+pub use to_size_filter_pager::ToSizeFilterPager;
+
+mod to_sorted_pager;
+pub use to_sorted_pager::to_sorted_pager;
+pub use to_sorted_pager::ToSortedPager;
@@ -0,0 +1,650 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::io;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// MultipartWriter provides a unified multipart write API on top of
+/// [`Accessor::create_multipart`], [`Accessor::write_multipart`],
+/// [`Accessor::complete_multipart`] and [`Accessor::abort_multipart`].
+///
+/// It works the same for all services that support
+/// [`Capability::write_multipart`], no matter they call it multipart upload
+/// (s3, obs) or something else.
+///
+/// Data passed to [`MultipartWriter::write_part`] will be buffered until
+/// `part_size` bytes are collected, so that every part except the last one
+/// will have the same size.
+///
+/// # Notes
+///
+/// The file will be visible only after [`MultipartWriter::complete`] succeeded.
+/// Users should call [`MultipartWriter::abort`] to clean up uploaded parts if
+/// they don't want to complete the upload.
+pub struct MultipartWriter {
+    acc: FusedAccessor,
+    path: String,
+    part_size: u64,
+
+    upload_id: Option<String>,
+    parts: Vec<MultipartPart>,
+    buffer: oio::VectorCursor,
+    completed: bool,
+}
+
+impl MultipartWriter {
+    /// Create a new MultipartWriter on given path.
+    ///
+    /// `part_size` MUST fit the limits of services, for example, s3 requires
+    /// parts except the last one to be larger than 5 MiB.
+    pub fn new(acc: FusedAccessor, path: &str, part_size: u64) -> MultipartWriter {
+        debug_assert!(part_size > 0, "part_size must be larger than 0");
+
+        MultipartWriter {
+            acc,
+            path: normalize_path(path),
+            part_size,
+
+            upload_id: None,
+            parts: vec![],
+            buffer: oio::VectorCursor::new(),
+            completed: false,
+        }
+    }
+
+    /// Get the upload id of current multipart upload.
+    ///
+    /// Returns `None` if the upload has not been created yet.
+    pub fn upload_id(&self) -> Option<&str> {
+        self.upload_id.as_deref()
+    }
+
+    /// Write data into the multipart upload.
+    ///
+    /// Parts will be uploaded once enough data has been buffered. An error
+    /// will be returned if the upload has been completed.
+    pub async fn write_part(&mut self, data: Bytes) -> Result<()> {
+        self.ensure_not_completed("MultipartWriter::write_part")?;
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        self.buffer.push(data);
+        self.flush().await
+    }
+
+    /// Upload all buffered data that fills a whole part.
+    ///
+    /// Data less than `part_size` will be kept in the buffer until more data
+    /// is written or the upload is completed.
+    pub async fn flush(&mut self) -> Result<()> {
+        let part_size = self.part_size as usize;
+        while self.buffer.len() >= part_size {
+            let bs = self.buffer.peak_exact(part_size);
+            self.upload(bs).await?;
+            self.buffer.take(part_size);
+        }
+
+        Ok(())
+    }
+
+    /// Flush all buffered data and complete the multipart upload.
+    ///
+    /// An error will be returned if the upload has been completed.
+    pub async fn complete(&mut self) -> Result<()> {
+        self.ensure_not_completed("MultipartWriter::complete")?;
+
+        // Services require at least one part to complete an upload, so we
+        // will upload the buffer even if it's empty.
+        if !self.buffer.is_empty() || self.parts.is_empty() {
+            let bs = self.buffer.peak_all();
+            self.upload(bs).await?;
+            self.buffer.clear();
+        }
+
+        // Keep the upload id until completed so that it can still be aborted.
+        let upload_id = self.upload_id.clone().expect("upload_id must be valid");
+        let parts = self.parts.clone();
+
+        self.acc
+            .complete_multipart(&self.path, OpCompleteMultipart::new(upload_id, parts))
+            .await?;
+
+        self.upload_id = None;
+        self.parts.clear();
+        self.completed = true;
+        Ok(())
+    }
+
+    /// Abort the multipart upload and drop all buffered data.
+    pub async fn abort(&mut self) -> Result<()> {
+        self.buffer.clear();
+        self.parts.clear();
+
+        let upload_id = match self.upload_id.take() {
+            Some(upload_id) => upload_id,
+            None => return Ok(()),
+        };
+
+        self.acc
+            .abort_multipart(&self.path, OpAbortMultipart::new(upload_id))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Data can't be written into a completed upload, otherwise a new
+    /// upload will be created silently.
+    fn ensure_not_completed(&self, op: &'static str) -> Result<()> {
+        if self.completed {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "multipart upload has been completed")
+                    .with_operation(op)
+                    .with_context("path", &self.path),
+            );
+        }
+        Ok(())
+    }
+
+    async fn upload(&mut self, bs: Bytes) -> Result<()> {
+        let upload_id = match &self.upload_id {
+            Some(upload_id) => upload_id.clone(),
+            None => {
+                let rp = self
+                    .acc
+                    .create_multipart(&self.path, OpCreateMultipart::new())
+                    .await?;
+                self.upload_id = Some(rp.into_upload_id());
+                self.upload_id.clone().expect("upload_id must be valid")
+            }
+        };
+
+        // Part number starts from 1.
+        let part_number = self.parts.len() + 1;
+        let size = bs.len() as u64;
+
+        let rp = self
+            .acc
+            .write_multipart(
+                &self.path,
+                OpWriteMultipart::new(upload_id, part_number, size),
+                bs,
+            )
+            .await?;
+        self.parts.push(rp.into_part());
+
+        Ok(())
+    }
+}
+
+/// ChunkedMultipartWriter implements [`tokio::io::AsyncWrite`] on top of
+/// [`MultipartWriter`], so that streams of any size can be uploaded with
+/// `tokio::io::copy`.
+///
+/// - `poll_write` buffers data and uploads a part every `part_size` bytes.
+/// - `poll_flush` uploads buffered parts that are full, the remaining data
+///   stays in the buffer.
+/// - `poll_shutdown` uploads the remaining data as the last part and
+///   completes the upload.
+///
+/// # Notes
+///
+/// `tokio::io::copy` doesn't shutdown the writer, users must call
+/// `shutdown` after the copy to complete the upload. Writes after a
+/// successful shutdown will return an error.
+///
+/// Use [`ChunkedMultipartWriter::abort`] to clean up uploaded parts if the
+/// upload should not be completed.
+pub struct ChunkedMultipartWriter {
+    state: ChunkedState,
+    completed: bool,
+}
+
+impl ChunkedMultipartWriter {
+    /// Create a new ChunkedMultipartWriter on given path.
+    ///
+    /// See [`MultipartWriter::new`] for the limits of `part_size`.
+    pub fn new(acc: FusedAccessor, path: &str, part_size: u64) -> ChunkedMultipartWriter {
+        ChunkedMultipartWriter {
+            state: ChunkedState::Idle(Some(MultipartWriter::new(acc, path, part_size))),
+            completed: false,
+        }
+    }
+
+    /// Returns `true` if the upload has been completed by shutdown.
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Abort the multipart upload and drop all buffered data.
+    pub async fn abort(&mut self) -> Result<()> {
+        match &mut self.state {
+            ChunkedState::Idle(Some(w)) => w.abort().await,
+            _ => Err(Error::new(
+                ErrorKind::Unexpected,
+                &format!("invalid state of writer: abort with State::{}", self.state),
+            )
+            .with_operation("ChunkedMultipartWriter::abort")),
+        }
+    }
+}
+
+enum ChunkedState {
+    Idle(Option<MultipartWriter>),
+    Write(BoxFuture<'static, (MultipartWriter, Result<usize>)>),
+    Flush(BoxFuture<'static, (MultipartWriter, Result<()>)>),
+    Shutdown(BoxFuture<'static, (MultipartWriter, Result<()>)>),
+}
+
+impl Display for ChunkedState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChunkedState::Idle(_) => write!(f, "Idle"),
+            ChunkedState::Write(_) => write!(f, "Write"),
+            ChunkedState::Flush(_) => write!(f, "Flush"),
+            ChunkedState::Shutdown(_) => write!(f, "Shutdown"),
+        }
+    }
+}
+
+impl ChunkedMultipartWriter {
+    fn take_writer(&mut self) -> MultipartWriter {
+        match &mut self.state {
+            ChunkedState::Idle(w) => w
+                .take()
+                .expect("invalid state of writer: Idle state with empty write"),
+            _ => unreachable!(
+                "invalid state of writer: expect Idle, actual {}",
+                self.state
+            ),
+        }
+    }
+
+    fn invalid_state(&self, op: &'static str) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::Other,
+            Error::new(
+                ErrorKind::Unexpected,
+                &format!("invalid state of writer: {op} with State::{}", self.state),
+            )
+            .with_operation(op),
+        )
+    }
+}
+
+impl tokio::io::AsyncWrite for ChunkedMultipartWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            match &mut self.state {
+                ChunkedState::Idle(_) => {
+                    if self.completed {
+                        return Poll::Ready(Err(io::Error::new(
+                            io::ErrorKind::Other,
+                            Error::new(
+                                ErrorKind::Unexpected,
+                                "multipart upload has been completed",
+                            )
+                            .with_operation("ChunkedMultipartWriter::poll_write"),
+                        )));
+                    }
+
+                    let mut w = self.take_writer();
+                    let bs = Bytes::from(buf.to_vec());
+                    let size = bs.len();
+                    let fut = async move {
+                        let res = w.write_part(bs).await.map(|_| size);
+                        (w, res)
+                    };
+                    self.state = ChunkedState::Write(Box::pin(fut));
+                }
+                ChunkedState::Write(fut) => {
+                    let (w, res) = ready!(fut.poll_unpin(cx));
+                    self.state = ChunkedState::Idle(Some(w));
+                    return Poll::Ready(
+                        res.map_err(|err| io::Error::new(io::ErrorKind::Other, err)),
+                    );
+                }
+                _ => {
+                    return Poll::Ready(Err(
+                        self.invalid_state("ChunkedMultipartWriter::poll_write")
+                    ))
+                }
+            }
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            match &mut self.state {
+                ChunkedState::Idle(_) => {
+                    if self.completed {
+                        return Poll::Ready(Ok(()));
+                    }
+
+                    let mut w = self.take_writer();
+                    let fut = async move {
+                        let res = w.flush().await;
+                        (w, res)
+                    };
+                    self.state = ChunkedState::Flush(Box::pin(fut));
+                }
+                ChunkedState::Flush(fut) => {
+                    let (w, res) = ready!(fut.poll_unpin(cx));
+                    self.state = ChunkedState::Idle(Some(w));
+                    return Poll::Ready(
+                        res.map_err(|err| io::Error::new(io::ErrorKind::Other, err)),
+                    );
+                }
+                _ => {
+                    return Poll::Ready(Err(
+                        self.invalid_state("ChunkedMultipartWriter::poll_flush")
+                    ))
+                }
+            }
+        }
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            match &mut self.state {
+                ChunkedState::Idle(_) => {
+                    if self.completed {
+                        return Poll::Ready(Ok(()));
+                    }
+
+                    let mut w = self.take_writer();
+                    let fut = async move {
+                        let res = w.complete().await;
+                        (w, res)
+                    };
+                    self.state = ChunkedState::Shutdown(Box::pin(fut));
+                }
+                ChunkedState::Shutdown(fut) => {
+                    let (w, res) = ready!(fut.poll_unpin(cx));
+                    self.state = ChunkedState::Idle(Some(w));
+                    self.completed = res.is_ok();
+                    return Poll::Ready(
+                        res.map_err(|err| io::Error::new(io::ErrorKind::Other, err)),
+                    );
+                }
+                _ => {
+                    return Poll::Ready(Err(
+                        self.invalid_state("ChunkedMultipartWriter::poll_shutdown")
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct MockService {
+        parts: Mutex<Vec<(usize, Bytes)>>,
+        completed: Mutex<Option<Vec<MultipartPart>>>,
+        aborted: Mutex<bool>,
+    }
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = oio::Reader;
+        type BlockingReader = oio::BlockingReader;
+        type Writer = oio::Writer;
+        type BlockingWriter = oio::BlockingWriter;
+        type Appender = oio::Appender;
+        type Pager = oio::Pager;
+        type BlockingPager = oio::BlockingPager;
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.capability_mut().write_multipart = true;
+            am
+        }
+
+        async fn create_multipart(
+            &self,
+            _: &str,
+            _: OpCreateMultipart,
+        ) -> Result<RpCreateMultipart> {
+            Ok(RpCreateMultipart::new("upload"))
+        }
+
+        async fn write_multipart(
+            &self,
+            _: &str,
+            args: OpWriteMultipart,
+            bs: Bytes,
+        ) -> Result<RpWriteMultipart> {
+            assert_eq!(args.upload_id(), "upload");
+            assert_eq!(args.size(), bs.len() as u64);
+
+            self.parts.lock().unwrap().push((args.part_number(), bs));
+            Ok(RpWriteMultipart::new(MultipartPart::new(
+                args.part_number(),
+                &format!("etag-{}", args.part_number()),
+            )))
+        }
+
+        async fn complete_multipart(
+            &self,
+            _: &str,
+            args: OpCompleteMultipart,
+        ) -> Result<RpCompleteMultipart> {
+            *self.completed.lock().unwrap() = Some(args.parts().to_vec());
+            Ok(RpCompleteMultipart::new())
+        }
+
+        async fn abort_multipart(&self, _: &str, _: OpAbortMultipart) -> Result<RpAbortMultipart> {
+            *self.aborted.lock().unwrap() = true;
+            Ok(RpAbortMultipart::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multipart_writer() -> Result<()> {
+        let srv = Arc::new(MockService::default());
+
+        let mut w = MultipartWriter::new(srv.clone(), "test", 4);
+        w.write_part(Bytes::from("hello")).await?;
+        w.write_part(Bytes::from(" world")).await?;
+        w.complete().await?;
+
+        let parts = srv.parts.lock().unwrap().clone();
+        assert_eq!(
+            parts,
+            vec![
+                (1, Bytes::from("hell")),
+                (2, Bytes::from("o wo")),
+                (3, Bytes::from("rld")),
+            ]
+        );
+
+        let completed = srv.completed.lock().unwrap().clone().unwrap();
+        assert_eq!(
+            completed,
+            vec![
+                MultipartPart::new(1, "etag-1"),
+                MultipartPart::new(2, "etag-2"),
+                MultipartPart::new(3, "etag-3"),
+            ]
+        );
+
+        // Completed upload can't be completed or written again.
+        let err = w.complete().await.expect_err("complete must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        w.write_part(Bytes::from("hello"))
+            .await
+            .expect_err("write must fail");
+        assert_eq!(srv.parts.lock().unwrap().len(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_multipart_writer_abort() -> Result<()> {
+        let srv = Arc::new(MockService::default());
+
+        // Abort before any part uploaded should be a no-op.
+        let mut w = MultipartWriter::new(srv.clone(), "test", 4);
+        w.write_part(Bytes::from("he")).await?;
+        w.abort().await?;
+        assert!(!*srv.aborted.lock().unwrap());
+
+        let mut w = MultipartWriter::new(srv.clone(), "test", 4);
+        w.write_part(Bytes::from("hello")).await?;
+        w.abort().await?;
+        assert!(*srv.aborted.lock().unwrap());
+        assert!(srv.completed.lock().unwrap().is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chunked_multipart_writer() -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let srv = Arc::new(MockService::default());
+
+        let mut w = ChunkedMultipartWriter::new(srv.clone(), "test", 4);
+        let mut src: &[u8] = b"hello world";
+        tokio::io::copy(&mut src, &mut w)
+            .await
+            .expect("copy must succeed");
+        assert!(!w.is_completed());
+        w.shutdown().await.expect("shutdown must succeed");
+        assert!(w.is_completed());
+
+        let parts = srv.parts.lock().unwrap().clone();
+        assert_eq!(
+            parts,
+            vec![
+                (1, Bytes::from("hell")),
+                (2, Bytes::from("o wo")),
+                (3, Bytes::from("rld")),
+            ]
+        );
+        assert_eq!(
+            srv.completed.lock().unwrap().as_ref().map(|v| v.len()),
+            Some(3)
+        );
+        assert!(!*srv.aborted.lock().unwrap());
+
+        // Writes after shutdown must fail.
+        w.write_all(b"hello").await.expect_err("write must fail");
+        Ok(())
+    }
+
+    /// PendingReader returns `Pending` once between chunks.
+    struct PendingReader {
+        chunks: Vec<&'static [u8]>,
+        pending: bool,
+    }
+
+    impl tokio::io::AsyncRead for PendingReader {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            if self.chunks.is_empty() {
+                return Poll::Ready(Ok(()));
+            }
+            if self.pending {
+                self.pending = false;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+
+            let chunk = self.chunks.remove(0);
+            buf.put_slice(chunk);
+            self.pending = true;
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chunked_multipart_writer_pending_reader() -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let srv = Arc::new(MockService::default());
+
+        let mut w = ChunkedMultipartWriter::new(srv.clone(), "test", 4);
+        let mut src = PendingReader {
+            chunks: vec![b"hello", b" world"],
+            pending: false,
+        };
+        tokio::io::copy(&mut src, &mut w)
+            .await
+            .expect("copy must succeed");
+        assert!(srv.completed.lock().unwrap().is_none());
+        w.shutdown().await.expect("shutdown must succeed");
+
+        let parts = srv.parts.lock().unwrap().clone();
+        assert_eq!(
+            parts,
+            vec![
+                (1, Bytes::from("hell")),
+                (2, Bytes::from("o wo")),
+                (3, Bytes::from("rld")),
+            ]
+        );
+        assert_eq!(
+            srv.completed.lock().unwrap().as_ref().map(|v| v.len()),
+            Some(3)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chunked_multipart_writer_abort() -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let srv = Arc::new(MockService::default());
+
+        let mut w = ChunkedMultipartWriter::new(srv.clone(), "test", 4);
+        w.write_all(b"hello").await.expect("write must succeed");
+        w.flush().await.expect("flush must succeed");
+        w.abort().await?;
+
+        assert!(!w.is_completed());
+        assert!(*srv.aborted.lock().unwrap());
+        assert!(srv.completed.lock().unwrap().is_none());
+        Ok(())
+    }
+}
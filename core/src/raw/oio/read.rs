@@ -154,6 +154,7 @@ fn convert_to_io_error(err: Error) -> io::Error {
         ErrorKind::NotFound => io::ErrorKind::NotFound,
         ErrorKind::PermissionDenied => io::ErrorKind::PermissionDenied,
         ErrorKind::InvalidInput => io::ErrorKind::InvalidInput,
+        ErrorKind::InvalidData => io::ErrorKind::InvalidData,
         _ => io::ErrorKind::Interrupted,
     };
 
@@ -0,0 +1,183 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+
+use crate::raw::*;
+use crate::*;
+
+/// to_size_filter_pager is used to filter entries of a pager by their
+/// content length.
+pub fn to_size_filter_pager<A: Accessor, P>(
+    acc: A,
+    pager: P,
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+) -> ToSizeFilterPager<A, P> {
+    ToSizeFilterPager {
+        acc,
+        pager,
+        min_size,
+        max_size,
+    }
+}
+
+/// ToSizeFilterPager will only return files whose content length is in
+/// `[min_size, max_size]`.
+///
+/// # Notes
+///
+/// - Filtering happens at client side, all pages are still fetched from
+///   the underlying service.
+/// - Dirs are always returned since they don't have a size.
+/// - Content length returned by list will be used directly, only entries
+///   without content length will be `stat`ed.
+///
+/// ToSizeFilterPager filter entries after fetch entries. So it's possible
+/// to return an empty vec. It doesn't mean the all pages have been
+/// returned.
+pub struct ToSizeFilterPager<A, P> {
+    acc: A,
+    pager: P,
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+}
+
+impl<A, P> ToSizeFilterPager<A, P> {
+    /// Check entry's size, returns `None` if we need to stat it first.
+    fn check(&self, e: &oio::Entry) -> Option<bool> {
+        if e.mode().is_dir() {
+            return Some(true);
+        }
+
+        let meta = e.metadata();
+        if !(meta.bit().contains(Metakey::ContentLength) || meta.bit().contains(Metakey::Complete))
+        {
+            return None;
+        }
+
+        Some(self.in_range(meta.content_length()))
+    }
+
+    fn in_range(&self, size: u64) -> bool {
+        self.min_size.map(|v| size >= v).unwrap_or(true)
+            && self.max_size.map(|v| size <= v).unwrap_or(true)
+    }
+}
+
+#[async_trait]
+impl<A, P> oio::Page for ToSizeFilterPager<A, P>
+where
+    A: Accessor,
+    P: oio::Page,
+{
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        let entries = match self.pager.next().await? {
+            Some(entries) => entries,
+            None => return Ok(None),
+        };
+
+        let mut res = Vec::with_capacity(entries.len());
+        for mut e in entries {
+            let matched = match self.check(&e) {
+                Some(matched) => matched,
+                None => {
+                    let meta = self
+                        .acc
+                        .stat(e.path(), OpStat::new())
+                        .await?
+                        .into_metadata();
+                    let matched = self.in_range(meta.content_length());
+                    e.set_metadata(meta);
+                    matched
+                }
+            };
+            if matched {
+                res.push(e);
+            }
+        }
+
+        Ok(Some(res))
+    }
+}
+
+impl<A, P> oio::BlockingPage for ToSizeFilterPager<A, P>
+where
+    A: Accessor,
+    P: oio::BlockingPage,
+{
+    fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        let entries = match self.pager.next()? {
+            Some(entries) => entries,
+            None => return Ok(None),
+        };
+
+        let mut res = Vec::with_capacity(entries.len());
+        for mut e in entries {
+            let matched = match self.check(&e) {
+                Some(matched) => matched,
+                None => {
+                    let meta = self
+                        .acc
+                        .blocking_stat(e.path(), OpStat::new())?
+                        .into_metadata();
+                    let matched = self.in_range(meta.content_length());
+                    e.set_metadata(meta);
+                    matched
+                }
+            };
+            if matched {
+                res.push(e);
+            }
+        }
+
+        Ok(Some(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use crate::services::Memory;
+    use crate::*;
+
+    #[tokio::test]
+    async fn test_list_with_size_range() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("dir/small", vec![0; 1]).await?;
+        op.write("dir/medium", vec![0; 10]).await?;
+        op.write("dir/large", vec![0; 100]).await?;
+
+        let mut lister = op.list_with("dir/").min_size(5).max_size(50).await?;
+        let mut paths = vec![];
+        while let Some(e) = lister.try_next().await? {
+            paths.push(e.path().to_string());
+        }
+        assert_eq!(paths, vec!["dir/medium"]);
+
+        let mut lister = op.list_with("dir/").min_size(10).await?;
+        let mut paths = vec![];
+        while let Some(e) = lister.try_next().await? {
+            paths.push(e.path().to_string());
+        }
+        paths.sort();
+        assert_eq!(paths, vec!["dir/large", "dir/medium"]);
+
+        Ok(())
+    }
+}
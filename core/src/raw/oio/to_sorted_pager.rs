@@ -0,0 +1,312 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+
+use async_trait::async_trait;
+
+use crate::raw::*;
+use crate::*;
+
+/// to_sorted_pager is used to sort all entries of a pager.
+pub fn to_sorted_pager<A: Accessor, P>(
+    acc: A,
+    pager: P,
+    by: SortBy,
+    order: SortOrder,
+) -> ToSortedPager<A, P> {
+    ToSortedPager {
+        acc,
+        pager,
+        by,
+        order,
+        done: false,
+    }
+}
+
+/// ToSortedPager will return all entries of underlying pager in one page
+/// sorted by given key and order.
+///
+/// # Notes
+///
+/// - All pages will be fetched and buffered in memory before returning,
+///   the memory usage grows with the count of entries.
+/// - Metadata returned by list will be used directly, only files without
+///   the required metadata will be `stat`ed.
+/// - Files deleted between listed and `stat`ed will be skipped.
+pub struct ToSortedPager<A, P> {
+    acc: A,
+    pager: P,
+    by: SortBy,
+    order: SortOrder,
+    done: bool,
+}
+
+impl<A, P> ToSortedPager<A, P> {
+    /// Check if we need to stat the entry before sorting.
+    fn need_stat(&self, e: &oio::Entry) -> bool {
+        if e.mode().is_dir() {
+            return false;
+        }
+
+        let key = match self.by {
+            SortBy::Name => return false,
+            SortBy::Size => Metakey::ContentLength,
+            SortBy::LastModified => Metakey::LastModified,
+        };
+        let bit = e.metadata().bit();
+        !(bit.contains(key) || bit.contains(Metakey::Complete))
+    }
+
+    fn sort(&self, entries: &mut [oio::Entry]) {
+        sort_entries(entries, self.by, self.order)
+    }
+}
+
+fn sort_entries(entries: &mut [oio::Entry], by: SortBy, order: SortOrder) {
+    let cmp = |a: &oio::Entry, b: &oio::Entry| -> Ordering {
+        let (am, bm) = (a.metadata(), b.metadata());
+        let ord = match by {
+            SortBy::Name => Ordering::Equal,
+            SortBy::Size => am.content_length_raw().cmp(&bm.content_length_raw()),
+            SortBy::LastModified => am.last_modified_raw().cmp(&bm.last_modified_raw()),
+        };
+        // Use path to make the order stable across listings.
+        ord.then_with(|| a.path().cmp(b.path()))
+    };
+
+    match order {
+        SortOrder::Ascending => entries.sort_by(cmp),
+        SortOrder::Descending => entries.sort_by(|a, b| cmp(b, a)),
+    }
+}
+
+#[async_trait]
+impl<A, P> oio::Page for ToSortedPager<A, P>
+where
+    A: Accessor,
+    P: oio::Page,
+{
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let mut entries = vec![];
+        while let Some(page) = self.pager.next().await? {
+            entries.extend(page);
+        }
+
+        let mut stated = Vec::with_capacity(entries.len());
+        for mut e in entries {
+            if self.need_stat(&e) {
+                match self.acc.stat(e.path(), OpStat::new()).await {
+                    Ok(rp) => e.set_metadata(rp.into_metadata()),
+                    Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err),
+                };
+            }
+            stated.push(e);
+        }
+        let mut entries = stated;
+
+        self.sort(&mut entries);
+        self.done = true;
+        Ok(Some(entries))
+    }
+}
+
+impl<A, P> oio::BlockingPage for ToSortedPager<A, P>
+where
+    A: Accessor,
+    P: oio::BlockingPage,
+{
+    fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let mut entries = vec![];
+        while let Some(page) = self.pager.next()? {
+            entries.extend(page);
+        }
+
+        let mut stated = Vec::with_capacity(entries.len());
+        for mut e in entries {
+            if self.need_stat(&e) {
+                match self.acc.blocking_stat(e.path(), OpStat::new()) {
+                    Ok(rp) => e.set_metadata(rp.into_metadata()),
+                    Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err),
+                };
+            }
+            stated.push(e);
+        }
+        let mut entries = stated;
+
+        self.sort(&mut entries);
+        self.done = true;
+        Ok(Some(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// MockService only has `a` and `c`, `b` has been deleted after listed.
+    #[derive(Debug, Clone)]
+    struct MockService;
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = ();
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            AccessorInfo::default()
+        }
+
+        fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+            match path {
+                "a" => Ok(RpStat::new(
+                    Metadata::new(EntryMode::FILE).with_content_length(2),
+                )),
+                "c" => Ok(RpStat::new(
+                    Metadata::new(EntryMode::FILE).with_content_length(1),
+                )),
+                _ => Err(Error::new(ErrorKind::NotFound, "file not found")),
+            }
+        }
+    }
+
+    struct MockPager {
+        done: bool,
+    }
+
+    impl oio::BlockingPage for MockPager {
+        fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+            if self.done {
+                return Ok(None);
+            }
+            self.done = true;
+
+            Ok(Some(
+                ["a", "b", "c"]
+                    .iter()
+                    .map(|path| oio::Entry::new(path, Metadata::new(EntryMode::FILE)))
+                    .collect(),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_sort_skip_deleted() -> Result<()> {
+        let mut pager = to_sorted_pager(
+            MockService,
+            MockPager { done: false },
+            SortBy::Size,
+            SortOrder::Ascending,
+        );
+
+        let entries = oio::BlockingPage::next(&mut pager)?.expect("must have entries");
+        let paths: Vec<&str> = entries.iter().map(|e| e.path()).collect();
+        assert_eq!(paths, vec!["c", "a"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_with_sort_by() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("dir/b", vec![0; 1]).await?;
+        op.write("dir/c", vec![0; 10]).await?;
+        op.write("dir/a", vec![0; 100]).await?;
+
+        let lister = op
+            .list_with("dir/")
+            .sort_by(SortBy::Name, SortOrder::Descending)
+            .await?;
+        let paths: Vec<String> = lister
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await?;
+        assert_eq!(paths, vec!["dir/c", "dir/b", "dir/a"]);
+
+        let lister = op
+            .list_with("dir/")
+            .sort_by(SortBy::Size, SortOrder::Ascending)
+            .await?;
+        let paths: Vec<String> = lister
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await?;
+        assert_eq!(paths, vec!["dir/b", "dir/c", "dir/a"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocking_list_with_sort_by() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish().blocking();
+        op.write("dir/b", vec![0; 1])?;
+        op.write("dir/c", vec![0; 10])?;
+        op.write("dir/a", vec![0; 100])?;
+
+        let paths = op
+            .list_with("dir/")
+            .min_size(10)
+            .sort_by(SortBy::Size, SortOrder::Descending)
+            .call()?
+            .map(|e| e.map(|e| e.path().to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(paths, vec!["dir/a", "dir/c"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_by_last_modified() {
+        let new_entry = |path: &str, secs: Option<i64>| {
+            let mut meta = Metadata::new(EntryMode::FILE);
+            if let Some(secs) = secs {
+                meta.set_last_modified(Utc.timestamp_opt(secs, 0).unwrap());
+            }
+            oio::Entry::new(path, meta)
+        };
+
+        let mut entries = vec![
+            new_entry("a", Some(1)),
+            new_entry("b", Some(3)),
+            new_entry("c", None),
+            new_entry("d", Some(2)),
+        ];
+        sort_entries(&mut entries, SortBy::LastModified, SortOrder::Descending);
+
+        let paths: Vec<&str> = entries.iter().map(|e| e.path()).collect();
+        assert_eq!(paths, vec!["b", "d", "a", "c"]);
+    }
+}
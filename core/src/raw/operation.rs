@@ -27,6 +27,8 @@ pub enum Operation {
     Info,
     /// Operation for [`crate::raw::Accessor::create_dir`]
     CreateDir,
+    /// Operation for [`crate::raw::Accessor::ensure_dir`]
+    EnsureDir,
     /// Operation for [`crate::raw::Accessor::read`]
     Read,
     /// Operation for [`crate::raw::Accessor::write`]
@@ -37,12 +39,56 @@ pub enum Operation {
     Copy,
     /// Operation for [`crate::raw::Accessor::rename`]
     Rename,
+    /// Operation for [`crate::raw::Accessor::create_symlink`]
+    CreateSymlink,
     /// Operation for [`crate::raw::Accessor::stat`]
     Stat,
     /// Operation for [`crate::raw::Accessor::delete`]
     Delete,
     /// Operation for [`crate::raw::Accessor::list`]
     List,
+    /// Operation for [`crate::raw::Accessor::list_versions`]
+    ListVersions,
+    /// Operation for [`crate::raw::Accessor::create_multipart`]
+    CreateMultipart,
+    /// Operation for [`crate::raw::Accessor::write_multipart`]
+    WriteMultipart,
+    /// Operation for [`crate::raw::Accessor::complete_multipart`]
+    CompleteMultipart,
+    /// Operation for [`crate::raw::Accessor::abort_multipart`]
+    AbortMultipart,
+    /// Operation for [`crate::raw::Accessor::touch`]
+    Touch,
+    /// Operation for [`crate::raw::Accessor::get_lifecycle_rules`]
+    GetLifecycleRules,
+    /// Operation for [`crate::raw::Accessor::set_lifecycle_rules`]
+    SetLifecycleRules,
+    /// Operation for [`crate::raw::Accessor::get_cors_config`]
+    GetCorsConfig,
+    /// Operation for [`crate::raw::Accessor::set_cors_config`]
+    SetCorsConfig,
+    /// Operation for [`crate::raw::Accessor::get_bucket_acl`]
+    GetBucketAcl,
+    /// Operation for [`crate::raw::Accessor::set_bucket_acl`]
+    SetBucketAcl,
+    /// Operation for [`crate::raw::Accessor::head`]
+    Head,
+    /// Operation for [`crate::raw::Accessor::get_tags`]
+    GetTags,
+    /// Operation for [`crate::raw::Accessor::set_tags`]
+    SetTags,
+    /// Operation for [`crate::raw::Accessor::update_metadata`]
+    UpdateMetadata,
+    /// Operation for [`crate::raw::Accessor::object_exists`]
+    ObjectExists,
+    /// Operation for [`crate::raw::Accessor::get_object_lock`]
+    GetObjectLock,
+    /// Operation for [`crate::raw::Accessor::lock_object`]
+    LockObject,
+    /// Operation for [`crate::raw::Accessor::presign_post`]
+    PresignPost,
+    /// Operation for [`crate::raw::Accessor::get_restore_status`]
+    GetRestoreStatus,
     /// Operation for [`crate::raw::Accessor::batch`]
     Batch,
     /// Operation for [`crate::raw::Accessor::presign`]
@@ -57,6 +103,8 @@ pub enum Operation {
     BlockingCopy,
     /// Operation for [`crate::raw::Accessor::blocking_rename`]
     BlockingRename,
+    /// Operation for [`crate::raw::Accessor::blocking_create_symlink`]
+    BlockingCreateSymlink,
     /// Operation for [`crate::raw::Accessor::blocking_stat`]
     BlockingStat,
     /// Operation for [`crate::raw::Accessor::blocking_delete`]
@@ -83,14 +131,37 @@ impl From<Operation> for &'static str {
         match v {
             Operation::Info => "metadata",
             Operation::CreateDir => "create_dir",
+            Operation::EnsureDir => "ensure_dir",
             Operation::Read => "read",
             Operation::Write => "write",
             Operation::Append => "append",
             Operation::Copy => "copy",
             Operation::Rename => "rename",
+            Operation::CreateSymlink => "create_symlink",
             Operation::Stat => "stat",
             Operation::Delete => "delete",
             Operation::List => "list",
+            Operation::ListVersions => "list_versions",
+            Operation::CreateMultipart => "create_multipart",
+            Operation::WriteMultipart => "write_multipart",
+            Operation::CompleteMultipart => "complete_multipart",
+            Operation::AbortMultipart => "abort_multipart",
+            Operation::Touch => "touch",
+            Operation::GetLifecycleRules => "get_lifecycle_rules",
+            Operation::SetLifecycleRules => "set_lifecycle_rules",
+            Operation::GetCorsConfig => "get_cors_config",
+            Operation::SetCorsConfig => "set_cors_config",
+            Operation::GetBucketAcl => "get_bucket_acl",
+            Operation::SetBucketAcl => "set_bucket_acl",
+            Operation::Head => "head",
+            Operation::GetTags => "get_tags",
+            Operation::SetTags => "set_tags",
+            Operation::UpdateMetadata => "update_metadata",
+            Operation::ObjectExists => "object_exists",
+            Operation::GetObjectLock => "get_object_lock",
+            Operation::LockObject => "lock_object",
+            Operation::PresignPost => "presign_post",
+            Operation::GetRestoreStatus => "get_restore_status",
             Operation::Presign => "presign",
             Operation::Batch => "batch",
             Operation::BlockingCreateDir => "blocking_create_dir",
@@ -98,6 +169,7 @@ impl From<Operation> for &'static str {
             Operation::BlockingWrite => "blocking_write",
             Operation::BlockingCopy => "blocking_copy",
             Operation::BlockingRename => "blocking_rename",
+            Operation::BlockingCreateSymlink => "blocking_create_symlink",
             Operation::BlockingStat => "blocking_stat",
             Operation::BlockingDelete => "blocking_delete",
             Operation::BlockingList => "blocking_list",
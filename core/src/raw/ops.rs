@@ -19,9 +19,38 @@
 //!
 //! By using ops, users can add more context for operation.
 
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::time::Duration;
 
+use chrono::DateTime;
+use chrono::Utc;
+use md5::Digest;
+use md5::Md5;
+
 use crate::raw::*;
+use crate::*;
+
+/// Parse the value of `key` in `map` into `T`.
+///
+/// Returns `Ok(None)` if key is not present and a `ConfigInvalid` error that
+/// carries the key and value if the value is invalid.
+fn parse_map_value<T>(map: &HashMap<String, String>, key: &str) -> Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    map.get(key)
+        .map(|v| {
+            v.parse::<T>().map_err(|err| {
+                Error::new(ErrorKind::ConfigInvalid, "invalid value in operation args")
+                    .with_context("key", key)
+                    .with_context("value", v)
+                    .set_source(err)
+            })
+        })
+        .transpose()
+}
 
 /// Args for `create` operation.
 ///
@@ -75,8 +104,25 @@ pub struct OpList {
     /// to start listing from.
     start_after: Option<String>,
 
+    /// The prefix passed to underlying service to narrow the listing.
+    prefix_filter: Option<String>,
+
     /// The delimiter used to for the list operation. Default to be `/`
     delimiter: String,
+
+    /// Only files whose content length is not less than min_size will
+    /// be returned.
+    min_size: Option<u64>,
+
+    /// Only files whose content length is not greater than max_size will
+    /// be returned.
+    max_size: Option<u64>,
+
+    /// The policy to apply while a list page returns error.
+    error_policy: ErrorPolicy,
+
+    /// The key and order to sort returned entries.
+    sort_by: Option<(SortBy, SortOrder)>,
 }
 
 impl Default for OpList {
@@ -84,7 +130,12 @@ impl Default for OpList {
         OpList {
             limit: None,
             start_after: None,
+            prefix_filter: None,
             delimiter: "/".to_string(),
+            min_size: None,
+            max_size: None,
+            error_policy: ErrorPolicy::FailFast,
+            sort_by: None,
         }
     }
 }
@@ -95,6 +146,41 @@ impl OpList {
         Self::default()
     }
 
+    /// Create a new `OpList` from key-value map.
+    ///
+    /// Supported keys are:
+    ///
+    /// - `recursive`: `true` or `false`, list without delimiter if `true`.
+    /// - `page_size`: the limit of results in one page.
+    /// - `start_after`: the key to start listing from.
+    /// - `min_size`: the min content length of files to return.
+    /// - `max_size`: the max content length of files to return.
+    ///
+    /// Other keys are ignored.
+    pub fn from_map(map: &HashMap<String, String>) -> Result<Self> {
+        let mut op = Self::new();
+
+        if let Some(recursive) = parse_map_value::<bool>(map, "recursive")? {
+            if recursive {
+                op = op.with_delimiter("");
+            }
+        }
+        if let Some(page_size) = parse_map_value(map, "page_size")? {
+            op = op.with_limit(page_size);
+        }
+        if let Some(start_after) = map.get("start_after") {
+            op = op.with_start_after(start_after);
+        }
+        if let Some(min_size) = parse_map_value(map, "min_size")? {
+            op = op.with_min_size(min_size);
+        }
+        if let Some(max_size) = parse_map_value(map, "max_size")? {
+            op = op.with_max_size(max_size);
+        }
+
+        Ok(op)
+    }
+
     /// Change the limit of this list operation.
     pub fn with_limit(mut self, limit: usize) -> Self {
         self.limit = Some(limit);
@@ -117,6 +203,20 @@ impl OpList {
         self.start_after.as_deref()
     }
 
+    /// Change the prefix filter of this list operation.
+    ///
+    /// `prefix` is relative to root like the listing path and must be
+    /// under it.
+    pub fn with_prefix_filter(mut self, prefix: &str) -> Self {
+        self.prefix_filter = Some(prefix.into());
+        self
+    }
+
+    /// Get the prefix filter of list operation.
+    pub fn prefix_filter(&self) -> Option<&str> {
+        self.prefix_filter.as_deref()
+    }
+
     /// Change the delimiter. The default delimiter is "/"
     pub fn with_delimiter(mut self, delimiter: &str) -> Self {
         self.delimiter = delimiter.to_string();
@@ -127,6 +227,98 @@ impl OpList {
     pub fn delimiter(&self) -> &str {
         &self.delimiter
     }
+
+    /// Change the min_size of this list operation.
+    ///
+    /// Size filtering happens at client side, all pages are still fetched.
+    pub fn with_min_size(mut self, min_size: u64) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    /// Get the min_size of list operation.
+    pub fn min_size(&self) -> Option<u64> {
+        self.min_size
+    }
+
+    /// Change the max_size of this list operation.
+    ///
+    /// Size filtering happens at client side, all pages are still fetched.
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Get the max_size of list operation.
+    pub fn max_size(&self) -> Option<u64> {
+        self.max_size
+    }
+
+    /// Change the error policy of this list operation.
+    pub fn with_error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
+    }
+
+    /// Get the error policy of list operation.
+    pub fn error_policy(&self) -> &ErrorPolicy {
+        &self.error_policy
+    }
+
+    /// Change the key and order to sort returned entries.
+    ///
+    /// Sorting happens at client side, all entries will be buffered in
+    /// memory before the first one is returned.
+    pub fn with_sort_by(mut self, by: SortBy, order: SortOrder) -> Self {
+        self.sort_by = Some((by, order));
+        self
+    }
+
+    /// Get the key and order to sort returned entries.
+    pub fn sort_by(&self) -> Option<(SortBy, SortOrder)> {
+        self.sort_by
+    }
+}
+
+/// Args for `list_versions` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpListVersions {
+    /// The limit passed to underlying service to specify the max results
+    /// that could return in one page.
+    limit: Option<usize>,
+    /// Whether to return delete markers.
+    delete_markers: bool,
+}
+
+impl OpListVersions {
+    /// Create a new `OpListVersions`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Change the limit of this list versions operation.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Get the limit of list versions operation.
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Change whether to return delete markers in this list versions operation.
+    ///
+    /// Delete markers will be returned with [`Metadata::is_delete_marker`] set.
+    pub fn with_delete_markers(mut self, delete_markers: bool) -> Self {
+        self.delete_markers = delete_markers;
+        self
+    }
+
+    /// Get whether to return delete markers.
+    pub fn delete_markers(&self) -> bool {
+        self.delete_markers
+    }
 }
 
 /// Args for `presign` operation.
@@ -159,6 +351,70 @@ impl OpPresign {
     }
 }
 
+/// Args for `presign_post` operation.
+///
+/// The presigned post allows users to upload a file via html form in the
+/// browser, restricted by the conditions in policy.
+#[derive(Debug, Clone)]
+pub struct OpPresignPost {
+    expire: Duration,
+
+    fields: HashMap<String, String>,
+    conditions: Vec<PolicyCondition>,
+}
+
+impl OpPresignPost {
+    /// Create a new `OpPresignPost`.
+    pub fn new(expire: Duration) -> Self {
+        Self {
+            expire,
+            fields: HashMap::new(),
+            conditions: vec![],
+        }
+    }
+
+    /// Get expire from op.
+    pub fn expire(&self) -> Duration {
+        self.expire
+    }
+
+    /// Add a form field, for example `content-type`.
+    ///
+    /// Every field will be added into policy as an exact match condition.
+    pub fn with_field(mut self, key: &str, value: &str) -> Self {
+        self.fields.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Get fields from op.
+    pub fn fields(&self) -> &HashMap<String, String> {
+        &self.fields
+    }
+
+    /// Add a condition into policy.
+    pub fn with_condition(mut self, condition: PolicyCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Get conditions from op.
+    pub fn conditions(&self) -> &[PolicyCondition] {
+        &self.conditions
+    }
+}
+
+/// PolicyCondition is the condition that form upload must match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PolicyCondition {
+    /// The form field must equal to given value.
+    Eq(String, String),
+    /// The form field must start with given prefix.
+    StartsWith(String, String),
+    /// The size of uploaded file must be in given range, both inclusive.
+    ContentLengthRange(u64, u64),
+}
+
 /// Presign operation used for presign.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -245,6 +501,9 @@ pub struct OpRead {
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    if_modified_since: Option<DateTime<Utc>>,
+    request_context: Option<RequestContext>,
+    buffer_size: Option<usize>,
 }
 
 impl OpRead {
@@ -253,6 +512,55 @@ impl OpRead {
         Self::default()
     }
 
+    /// Create a new `OpRead` from key-value map.
+    ///
+    /// Supported keys are:
+    ///
+    /// - `offset`: the offset of range to read.
+    /// - `size`: the size of range to read, starts from `0` if `offset` is
+    ///   not set.
+    /// - `if_match`: the If-Match of the read.
+    /// - `if_none_match`: the If-None-Match of the read.
+    /// - `if_modified_since`: the If-Modified-Since of the read in rfc3339 like
+    ///   `2014-11-28T21:00:09+09:00`.
+    /// - `version`: the version to read.
+    ///
+    /// Other keys are ignored.
+    pub fn from_map(map: &HashMap<String, String>) -> Result<Self> {
+        let mut op = Self::new();
+
+        let offset = parse_map_value(map, "offset")?;
+        let size = parse_map_value(map, "size")?;
+        // `BytesRange` treats range without offset as suffix range, but
+        // `size` alone here means reading from the start.
+        let offset = match (offset, size) {
+            (None, Some(_)) => Some(0),
+            _ => offset,
+        };
+        op = op.with_range(BytesRange::new(offset, size));
+
+        if let Some(if_match) = map.get("if_match") {
+            op = op.with_if_match(if_match);
+        }
+        if let Some(if_none_match) = map.get("if_none_match") {
+            op = op.with_if_none_match(if_none_match);
+        }
+        if let Some(v) = map.get("if_modified_since") {
+            let if_modified_since = parse_datetime_from_rfc3339(v).map_err(|err| {
+                Error::new(ErrorKind::ConfigInvalid, "invalid value in operation args")
+                    .with_context("key", "if_modified_since")
+                    .with_context("value", v)
+                    .set_source(err)
+            })?;
+            op = op.with_if_modified_since(if_modified_since);
+        }
+        if let Some(version) = map.get("version") {
+            op = op.with_version(version);
+        }
+
+        Ok(op)
+    }
+
     /// Create a new OpRead with range.
     pub fn with_range(mut self, range: BytesRange) -> Self {
         self.br = range;
@@ -309,6 +617,17 @@ impl OpRead {
         self.if_none_match.as_deref()
     }
 
+    /// Set the If-Modified-Since of the option
+    pub fn with_if_modified_since(mut self, if_modified_since: DateTime<Utc>) -> Self {
+        self.if_modified_since = Some(if_modified_since);
+        self
+    }
+
+    /// Get If-Modified-Since from option
+    pub fn if_modified_since(&self) -> Option<DateTime<Utc>> {
+        self.if_modified_since
+    }
+
     /// Set the version of the option
     pub fn with_version(mut self, version: &str) -> Self {
         self.version = Some(version.to_string());
@@ -319,6 +638,33 @@ impl OpRead {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set the request context of the option
+    pub fn with_request_context(mut self, ctx: RequestContext) -> Self {
+        self.request_context = Some(ctx);
+        self
+    }
+
+    /// Get request context from option
+    pub fn request_context(&self) -> Option<&RequestContext> {
+        self.request_context.as_ref()
+    }
+
+    /// Set the buffer size hint of the option
+    ///
+    /// Services that support it will buffer at least `buffer_size` bytes
+    /// from the underlying connection before returning them. Large buffers
+    /// like 8 MiB reduce overhead for sequential streaming, and small
+    /// buffers like 64 KiB reduce wasted prefetch for random access.
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = Some(buffer_size);
+        self
+    }
+
+    /// Get the buffer size hint from option
+    pub fn buffer_size(&self) -> Option<usize> {
+        self.buffer_size
+    }
 }
 
 /// Args for `stat` operation.
@@ -375,7 +721,12 @@ pub struct OpWrite {
     content_length: Option<u64>,
     content_type: Option<String>,
     content_disposition: Option<String>,
+    content_encoding: Option<String>,
     cache_control: Option<String>,
+    if_none_match: Option<String>,
+    expected_checksum: Option<Checksum>,
+    multipart_threshold: Option<u64>,
+    request_context: Option<RequestContext>,
 }
 
 impl OpWrite {
@@ -386,6 +737,39 @@ impl OpWrite {
         Self::default()
     }
 
+    /// Create a new `OpWrite` from key-value map.
+    ///
+    /// Supported keys are:
+    ///
+    /// - `content_length`: the total length of the data to be written.
+    /// - `content_type`: the content type of the file.
+    /// - `content_disposition`: the content disposition of the file.
+    /// - `content_encoding`: the content encoding of the file.
+    /// - `cache_control`: the cache control of the file.
+    ///
+    /// Other keys are ignored.
+    pub fn from_map(map: &HashMap<String, String>) -> Result<Self> {
+        let mut op = Self::new();
+
+        if let Some(content_length) = parse_map_value(map, "content_length")? {
+            op = op.with_content_length(content_length);
+        }
+        if let Some(content_type) = map.get("content_type") {
+            op = op.with_content_type(content_type);
+        }
+        if let Some(content_disposition) = map.get("content_disposition") {
+            op = op.with_content_disposition(content_disposition);
+        }
+        if let Some(content_encoding) = map.get("content_encoding") {
+            op = op.with_content_encoding(content_encoding);
+        }
+        if let Some(cache_control) = map.get("cache_control") {
+            op = op.with_cache_control(cache_control);
+        }
+
+        Ok(op)
+    }
+
     /// Get the content length from op.
     ///
     /// The content length is the total length of the data to be written.
@@ -424,6 +808,24 @@ impl OpWrite {
         self
     }
 
+    /// Get the content encoding from option
+    pub fn content_encoding(&self) -> Option<&str> {
+        self.content_encoding.as_deref()
+    }
+
+    /// Set the content encoding of option
+    ///
+    /// Content encoding like `gzip` tells clients the content has been
+    /// compressed and must be decoded before interpreting it as
+    /// content type, browsers will decompress it transparently. Use it for
+    /// pre-compressed uploads like a gzipped `text/html`, and use the
+    /// content type `application/gzip` instead if the file itself is a
+    /// gzip archive that should be downloaded as is.
+    pub fn with_content_encoding(mut self, content_encoding: &str) -> Self {
+        self.content_encoding = Some(content_encoding.to_string());
+        self
+    }
+
     /// Get the cache control from option
     pub fn cache_control(&self) -> Option<&str> {
         self.cache_control.as_deref()
@@ -434,6 +836,77 @@ impl OpWrite {
         self.cache_control = Some(cache_control.to_string());
         self
     }
+
+    /// Get the If-None-Match from option
+    pub fn if_none_match(&self) -> Option<&str> {
+        self.if_none_match.as_deref()
+    }
+
+    /// Set the If-None-Match of option
+    ///
+    /// Use `*` to write only if the file doesn't exist.
+    pub fn with_if_none_match(mut self, if_none_match: &str) -> Self {
+        self.if_none_match = Some(if_none_match.to_string());
+        self
+    }
+
+    /// Get the expected checksum from option
+    pub fn expected_checksum(&self) -> Option<&Checksum> {
+        self.expected_checksum.as_ref()
+    }
+
+    /// Set the expected checksum of option
+    ///
+    /// Services will reject the write if the content doesn't match it.
+    pub fn with_expected_checksum(mut self, checksum: Checksum) -> Self {
+        self.expected_checksum = Some(checksum);
+        self
+    }
+
+    /// Set the raw md5 digest of content as expected checksum of option
+    ///
+    /// The digest will be base64 encoded while sending as `Content-MD5`.
+    pub fn with_known_content_md5(self, md5: [u8; 16]) -> Self {
+        self.with_expected_checksum(Checksum::Md5(md5))
+    }
+
+    /// Compute the md5 of `data` and set it as expected checksum of option
+    ///
+    /// # Notes
+    ///
+    /// `data` must be the whole content to write. For streaming writes
+    /// like sink or writer, the content isn't known upfront, callers must
+    /// pre-compute the md5 and use [`OpWrite::with_known_content_md5`]
+    /// instead.
+    pub fn compute_content_md5(self, data: &[u8]) -> Self {
+        self.with_known_content_md5(Md5::digest(data).into())
+    }
+
+    /// Get the multipart threshold from option
+    pub fn multipart_threshold(&self) -> Option<u64> {
+        self.multipart_threshold
+    }
+
+    /// Set the multipart threshold of option
+    ///
+    /// Writes with content length larger than it will be switched to
+    /// multipart upload by services that support it. Writes exceeding the
+    /// max size of a single write are always switched even if it's unset.
+    pub fn with_multipart_threshold(mut self, threshold: u64) -> Self {
+        self.multipart_threshold = Some(threshold);
+        self
+    }
+
+    /// Get the request context from option
+    pub fn request_context(&self) -> Option<&RequestContext> {
+        self.request_context.as_ref()
+    }
+
+    /// Set the request context of option
+    pub fn with_request_context(mut self, ctx: RequestContext) -> Self {
+        self.request_context = Some(ctx);
+        self
+    }
 }
 
 /// Args for `append` operation.
@@ -505,3 +978,481 @@ impl OpRename {
         Self::default()
     }
 }
+
+/// Args for `touch` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpTouch {}
+
+impl OpTouch {
+    /// Create a new `OpTouch`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `head` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpHead {}
+
+impl OpHead {
+    /// Create a new `OpHead`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `get_lifecycle_rules` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetLifecycleRules {}
+
+impl OpGetLifecycleRules {
+    /// Create a new `OpGetLifecycleRules`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_lifecycle_rules` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetLifecycleRules {
+    rules: Vec<LifecycleRule>,
+}
+
+impl OpSetLifecycleRules {
+    /// Create a new `OpSetLifecycleRules`.
+    pub fn new(rules: Vec<LifecycleRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Get the rules from option.
+    pub fn rules(&self) -> &[LifecycleRule] {
+        &self.rules
+    }
+
+    /// Consume op to get the rules.
+    pub fn into_rules(self) -> Vec<LifecycleRule> {
+        self.rules
+    }
+}
+
+/// Args for `get_cors_config` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetCorsConfig {}
+
+impl OpGetCorsConfig {
+    /// Create a new `OpGetCorsConfig`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_cors_config` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetCorsConfig {
+    rules: Vec<CorsRule>,
+}
+
+impl OpSetCorsConfig {
+    /// Create a new `OpSetCorsConfig`.
+    pub fn new(rules: Vec<CorsRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Get the rules from option.
+    pub fn rules(&self) -> &[CorsRule] {
+        &self.rules
+    }
+
+    /// Consume op to get the rules.
+    pub fn into_rules(self) -> Vec<CorsRule> {
+        self.rules
+    }
+}
+
+/// Args for `get_bucket_acl` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetBucketAcl {}
+
+impl OpGetBucketAcl {
+    /// Create a new `OpGetBucketAcl`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_bucket_acl` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetBucketAcl {
+    acl: BucketAcl,
+}
+
+impl OpSetBucketAcl {
+    /// Create a new `OpSetBucketAcl`.
+    pub fn new(acl: BucketAcl) -> Self {
+        Self { acl }
+    }
+
+    /// Get the acl from option.
+    pub fn acl(&self) -> &BucketAcl {
+        &self.acl
+    }
+
+    /// Consume op to get the acl.
+    pub fn into_acl(self) -> BucketAcl {
+        self.acl
+    }
+}
+
+/// Args for `get_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetTags {}
+
+impl OpGetTags {
+    /// Create a new `OpGetTags`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `get_restore_status` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetRestoreStatus {}
+
+impl OpGetRestoreStatus {
+    /// Create a new `OpGetRestoreStatus`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetTags {
+    tags: HashMap<String, String>,
+}
+
+impl OpSetTags {
+    /// Create a new `OpSetTags`.
+    pub fn new(tags: HashMap<String, String>) -> Self {
+        Self { tags }
+    }
+
+    /// Get the tags from option.
+    pub fn tags(&self) -> &HashMap<String, String> {
+        &self.tags
+    }
+
+    /// Consume op to get the tags.
+    pub fn into_tags(self) -> HashMap<String, String> {
+        self.tags
+    }
+}
+
+/// Args for `update_metadata` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpUpdateMetadata {
+    metadata: HashMap<String, String>,
+}
+
+impl OpUpdateMetadata {
+    /// Create a new `OpUpdateMetadata`.
+    pub fn new(metadata: HashMap<String, String>) -> Self {
+        Self { metadata }
+    }
+
+    /// Get the user metadata from option.
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Consume op to get the user metadata.
+    pub fn into_metadata(self) -> HashMap<String, String> {
+        self.metadata
+    }
+}
+
+/// Args for `get_object_lock` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetObjectLock {}
+
+impl OpGetObjectLock {
+    /// Create a new `OpGetObjectLock`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `lock_object` operation.
+#[derive(Debug, Clone)]
+pub struct OpLockObject {
+    config: ObjectLockConfig,
+}
+
+impl OpLockObject {
+    /// Create a new `OpLockObject`.
+    pub fn new(config: ObjectLockConfig) -> Self {
+        Self { config }
+    }
+
+    /// Get the lock config from option.
+    pub fn config(&self) -> &ObjectLockConfig {
+        &self.config
+    }
+
+    /// Consume op to get the lock config.
+    pub fn into_config(self) -> ObjectLockConfig {
+        self.config
+    }
+}
+
+/// Args for `create_multipart` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpCreateMultipart {
+    content_type: Option<String>,
+    content_disposition: Option<String>,
+    cache_control: Option<String>,
+}
+
+impl OpCreateMultipart {
+    /// Create a new `OpCreateMultipart`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the content type from option
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Set the content type of option
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Get the content disposition from option
+    pub fn content_disposition(&self) -> Option<&str> {
+        self.content_disposition.as_deref()
+    }
+
+    /// Set the content disposition of option
+    pub fn with_content_disposition(mut self, content_disposition: &str) -> Self {
+        self.content_disposition = Some(content_disposition.to_string());
+        self
+    }
+
+    /// Get the cache control from option
+    pub fn cache_control(&self) -> Option<&str> {
+        self.cache_control.as_deref()
+    }
+
+    /// Set the cache control of option
+    pub fn with_cache_control(mut self, cache_control: &str) -> Self {
+        self.cache_control = Some(cache_control.to_string());
+        self
+    }
+}
+
+/// Args for `write_multipart` operation.
+#[derive(Debug, Clone)]
+pub struct OpWriteMultipart {
+    upload_id: String,
+    part_number: usize,
+    size: u64,
+}
+
+impl OpWriteMultipart {
+    /// Create a new `OpWriteMultipart`.
+    ///
+    /// `part_number` starts from `1`.
+    pub fn new(upload_id: String, part_number: usize, size: u64) -> Self {
+        Self {
+            upload_id,
+            part_number,
+            size,
+        }
+    }
+
+    /// Get upload_id from option.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get part_number from option.
+    pub fn part_number(&self) -> usize {
+        self.part_number
+    }
+
+    /// Get size from option.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// Args for `complete_multipart` operation.
+#[derive(Debug, Clone)]
+pub struct OpCompleteMultipart {
+    upload_id: String,
+    parts: Vec<MultipartPart>,
+}
+
+impl OpCompleteMultipart {
+    /// Create a new `OpCompleteMultipart`.
+    pub fn new(upload_id: String, parts: Vec<MultipartPart>) -> Self {
+        Self { upload_id, parts }
+    }
+
+    /// Get upload_id from option.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get parts from option.
+    pub fn parts(&self) -> &[MultipartPart] {
+        &self.parts
+    }
+}
+
+/// Args for `abort_multipart` operation.
+#[derive(Debug, Clone)]
+pub struct OpAbortMultipart {
+    upload_id: String,
+}
+
+impl OpAbortMultipart {
+    /// Create a new `OpAbortMultipart`.
+    pub fn new(upload_id: String) -> Self {
+        Self { upload_id }
+    }
+
+    /// Get upload_id from option.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+}
+
+/// MultipartPart is the part that has been uploaded in a multipart upload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultipartPart {
+    part_number: usize,
+    etag: String,
+}
+
+impl MultipartPart {
+    /// Create a new multipart part.
+    pub fn new(part_number: usize, etag: &str) -> Self {
+        Self {
+            part_number,
+            etag: etag.to_string(),
+        }
+    }
+
+    /// Get the part number of this part.
+    pub fn part_number(&self) -> usize {
+        self.part_number
+    }
+
+    /// Get the etag of this part returned by service.
+    pub fn etag(&self) -> &str {
+        &self.etag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(kvs: &[(&str, &str)]) -> HashMap<String, String> {
+        kvs.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_op_write_content_md5() {
+        let op = OpWrite::new().compute_content_md5(b"Hello, World!");
+        assert_eq!(
+            op.expected_checksum().map(|v| v.to_base64()),
+            Some("ZajifYh5KDgxtmS9i38K1A==".to_string())
+        );
+
+        let md5 = [0; 16];
+        let op = OpWrite::new().with_known_content_md5(md5);
+        assert_eq!(op.expected_checksum(), Some(&Checksum::Md5(md5)));
+    }
+
+    #[test]
+    fn test_op_read_from_map() {
+        let op = OpRead::from_map(&map(&[
+            ("offset", "1024"),
+            ("size", "2048"),
+            ("if_none_match", "\"etag\""),
+            ("if_modified_since", "2022-11-28T21:00:09+00:00"),
+            ("unknown", "ignored"),
+        ]))
+        .expect("from_map must succeed");
+
+        assert_eq!(op.range(), BytesRange::new(Some(1024), Some(2048)));
+        assert_eq!(op.if_none_match(), Some("\"etag\""));
+        assert_eq!(
+            op.if_modified_since(),
+            Some(parse_datetime_from_rfc3339("2022-11-28T21:00:09+00:00").unwrap())
+        );
+
+        let op = OpRead::from_map(&map(&[("size", "2048")])).expect("from_map must succeed");
+        assert_eq!(op.range(), BytesRange::new(Some(0), Some(2048)));
+
+        let err = OpRead::from_map(&map(&[("offset", "-1")])).expect_err("must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+        assert!(err.to_string().contains("offset"), "{err}");
+        assert!(err.to_string().contains("-1"), "{err}");
+
+        let err =
+            OpRead::from_map(&map(&[("if_modified_since", "yesterday")])).expect_err("must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+        assert!(err.to_string().contains("if_modified_since"), "{err}");
+    }
+
+    #[test]
+    fn test_op_write_from_map() {
+        let op = OpWrite::from_map(&map(&[
+            ("content_type", "text/plain"),
+            ("content_length", "42"),
+            ("cache_control", "no-cache"),
+        ]))
+        .expect("from_map must succeed");
+
+        assert_eq!(op.content_type(), Some("text/plain"));
+        assert_eq!(op.content_length(), Some(42));
+        assert_eq!(op.cache_control(), Some("no-cache"));
+
+        let err = OpWrite::from_map(&map(&[("content_length", "abc")])).expect_err("must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+        assert!(err.to_string().contains("content_length"), "{err}");
+    }
+
+    #[test]
+    fn test_op_list_from_map() {
+        let op = OpList::from_map(&map(&[
+            ("recursive", "true"),
+            ("page_size", "100"),
+            ("start_after", "abc"),
+            ("min_size", "1024"),
+            ("max_size", "4096"),
+        ]))
+        .expect("from_map must succeed");
+
+        assert_eq!(op.delimiter(), "");
+        assert_eq!(op.limit(), Some(100));
+        assert_eq!(op.start_after(), Some("abc"));
+        assert_eq!(op.min_size(), Some(1024));
+        assert_eq!(op.max_size(), Some(4096));
+
+        let op = OpList::from_map(&map(&[("recursive", "false")])).expect("must succeed");
+        assert_eq!(op.delimiter(), "/");
+
+        let err = OpList::from_map(&map(&[("recursive", "yes")])).expect_err("must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+        assert!(err.to_string().contains("recursive"), "{err}");
+    }
+}
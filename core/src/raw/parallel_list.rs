@@ -0,0 +1,159 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use futures::Stream;
+use tokio::sync::mpsc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::raw::oio::Page;
+use crate::raw::*;
+use crate::*;
+
+/// List all entries under given prefix recursively, dirs are listed
+/// concurrently.
+///
+/// `prefix` must be a dir path ends with `/`, use `/` for the whole
+/// storage.
+///
+/// # Notes
+///
+/// - Every dir found will be listed in a new task, at most
+///   `max_concurrent` dirs will be listed at the same time. Pages of the
+///   same dir are still fetched one by one.
+/// - Entries are returned as they arrive, so they are not in any order.
+/// - Listing stops at the first error, which is the last item of the
+///   stream.
+/// - Must be called inside a tokio runtime, listing stops once the
+///   returned stream is dropped.
+pub async fn parallel_list(
+    op: FusedAccessor,
+    prefix: &str,
+    max_concurrent: usize,
+) -> impl Stream<Item = Result<oio::Entry>> {
+    let (tx, rx) = mpsc::channel(1024);
+    let semaphore = Arc::new(Semaphore::new(max_concurrent.max(1)));
+
+    let prefix = normalize_path(prefix);
+    tokio::spawn(async move {
+        let mut tasks = JoinSet::new();
+        tasks.spawn(list_dir(op.clone(), prefix, semaphore.clone(), tx.clone()));
+
+        while let Some(res) = tasks.join_next().await {
+            // Remaining tasks will be aborted while `tasks` dropped.
+            if tx.is_closed() {
+                return;
+            }
+
+            let dirs = match res {
+                Ok(Ok(dirs)) => dirs,
+                Ok(Err(err)) => {
+                    let _ = tx.send(Err(err)).await;
+                    return;
+                }
+                Err(err) => {
+                    let err = Error::new(ErrorKind::Unexpected, "list task failed")
+                        .with_operation("parallel_list")
+                        .set_source(err);
+                    let _ = tx.send(Err(err)).await;
+                    return;
+                }
+            };
+            for dir in dirs {
+                tasks.spawn(list_dir(op.clone(), dir, semaphore.clone(), tx.clone()));
+            }
+        }
+    });
+
+    futures::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|entry| (entry, rx))
+    })
+}
+
+/// List given dir and send all entries in it, returns sub dirs.
+async fn list_dir(
+    op: FusedAccessor,
+    path: String,
+    semaphore: Arc<Semaphore>,
+    tx: mpsc::Sender<Result<oio::Entry>>,
+) -> Result<Vec<String>> {
+    let _permit = semaphore
+        .acquire_owned()
+        .await
+        .map_err(|err| Error::new(ErrorKind::Unexpected, "semaphore closed").set_source(err))?;
+
+    let (_, mut pager) = op.list(&path, OpList::new()).await?;
+
+    let mut dirs = vec![];
+    while let Some(entries) = pager.next().await? {
+        for de in entries {
+            // Some services will return the dir itself.
+            if de.path() == path {
+                continue;
+            }
+            if de.mode().is_dir() {
+                dirs.push(de.path().to_string());
+            }
+            if tx.send(Ok(de)).await.is_err() {
+                return Ok(vec![]);
+            }
+        }
+    }
+
+    Ok(dirs)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_parallel_list() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+
+        let mut expected = vec![];
+        for i in 0..30 {
+            let path = format!("data/{}/{}/file-{i}", i % 3, i % 7);
+            op.write(&path, "Hello").await?;
+            expected.push(path);
+        }
+        op.write("other", "Hello").await?;
+        for i in 0..3 {
+            expected.push(format!("data/{i}/"));
+            for j in 0..7 {
+                if (0..30).any(|v| v % 3 == i && v % 7 == j) {
+                    expected.push(format!("data/{i}/{j}/"));
+                }
+            }
+        }
+        expected.sort();
+
+        let mut paths: Vec<String> = parallel_list(op.into(), "data/", 2)
+            .await
+            .map_ok(|de| de.path().to_string())
+            .try_collect()
+            .await?;
+        paths.sort();
+        assert_eq!(paths, expected);
+        Ok(())
+    }
+}
@@ -210,6 +210,44 @@ pub fn get_parent(path: &str) -> &str {
     }
 }
 
+/// Get the longest common dir prefix of given paths.
+///
+/// The prefix always ends at a segment boundary, so it's either empty or
+/// ends with `/`.
+///
+/// - `["abc/def/x", "abc/def/y"]` => `Some("abc/def/")`
+/// - `["abc/def", "abc/deg"]` => `Some("abc/")`
+/// - `["abc", "def"]` => `Some("")`, which means they only share the root.
+///
+/// Returns `None` if `paths` is empty, or absolute and relative paths are
+/// mixed which don't share any prefix.
+pub fn common_prefix<'a>(paths: &[&'a str]) -> Option<&'a str> {
+    let (first, rest) = paths.split_first()?;
+
+    let is_abs = first.starts_with('/');
+    if rest.iter().any(|v| v.starts_with('/') != is_abs) {
+        return None;
+    }
+
+    let mut len = first.len();
+    for path in rest {
+        len = first.as_bytes()[..len]
+            .iter()
+            .zip(path.as_bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+    }
+
+    // Bytes are compared one by one which could stop inside a multi-bytes
+    // char, but `/` is always a char boundary.
+    let end = first.as_bytes()[..len]
+        .iter()
+        .rposition(|v| *v == b'/')
+        .map(|v| v + 1)
+        .unwrap_or_default();
+    Some(&first[..end])
+}
+
 /// Validate given path is match with given EntryMode.
 pub fn validate_path(path: &str, mode: EntryMode) -> bool {
     debug_assert!(!path.is_empty(), "input path should not be empty");
@@ -217,6 +255,7 @@ pub fn validate_path(path: &str, mode: EntryMode) -> bool {
     match mode {
         EntryMode::FILE => !path.ends_with('/'),
         EntryMode::DIR => path.ends_with('/'),
+        EntryMode::SYMLINK => !path.ends_with('/'),
         EntryMode::Unknown => false,
     }
 }
@@ -299,6 +338,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_common_prefix() {
+        let cases: Vec<(&str, Vec<&str>, Option<&str>)> = vec![
+            ("empty", vec![], None),
+            ("single file", vec!["abc/def"], Some("abc/")),
+            ("single dir", vec!["abc/def/"], Some("abc/def/")),
+            ("same paths", vec!["abc/def", "abc/def"], Some("abc/")),
+            (
+                "differ in last segment",
+                vec!["abc/def/x.txt", "abc/def/y.txt"],
+                Some("abc/def/"),
+            ),
+            ("not mid segment", vec!["abc/def", "abc/deg"], Some("abc/")),
+            (
+                "dir and its children",
+                vec!["abc/", "abc/def", "abc/def/x"],
+                Some("abc/"),
+            ),
+            ("only root", vec!["abc/def", "xyz/def"], Some("")),
+            ("only root files", vec!["abc", "abd"], Some("")),
+            ("abs paths", vec!["/abc/def", "/abc/xyz"], Some("/abc/")),
+            ("abs paths only root", vec!["/abc", "/xyz"], Some("/")),
+            ("mixed abs and rel", vec!["/abc/def", "abc/def"], None),
+            (
+                "unicode",
+                vec!["数据/2023/文件", "数据/2023/文档"],
+                Some("数据/2023/"),
+            ),
+            // `é` and `ê` share the first byte.
+            ("unicode mid char", vec!["a/é/x", "a/ê/x"], Some("a/")),
+        ];
+
+        for (name, input, expect) in cases {
+            let actual = common_prefix(&input);
+            assert_eq!(actual, expect, "{name}")
+        }
+    }
+
     #[test]
     fn test_build_abs_path() {
         let cases = vec![
@@ -0,0 +1,567 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use parking_lot::Mutex;
+use tokio::sync::OwnedSemaphorePermit;
+use tokio::sync::Semaphore;
+
+use crate::raw::*;
+use crate::*;
+
+/// ConnectionPoolConfig is the config of [`ConnectionPool`].
+#[derive(Debug, Clone)]
+pub struct ConnectionPoolConfig {
+    max_connections: usize,
+    min_connections: usize,
+    idle_timeout: Duration,
+}
+
+impl Default for ConnectionPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: 8,
+            min_connections: 0,
+            idle_timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+impl ConnectionPoolConfig {
+    /// Set the max count of backends, including both idle and in use
+    /// ones, default to `8`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `v` is zero.
+    pub fn with_max_connections(mut self, v: usize) -> Self {
+        assert!(v > 0, "max connections must be greater than 0");
+
+        self.max_connections = v;
+        self
+    }
+
+    /// Set the count of idle backends that will be kept even if they
+    /// have been idle for longer than `idle_timeout`, default to `0`.
+    pub fn with_min_connections(mut self, v: usize) -> Self {
+        self.min_connections = v;
+        self
+    }
+
+    /// Set how long a backend can be idle before it's closed, default to
+    /// 5 minutes.
+    pub fn with_idle_timeout(mut self, v: Duration) -> Self {
+        self.idle_timeout = v;
+        self
+    }
+}
+
+/// ConnectionPool keeps a pool of backends for services with stateful
+/// connections like `sftp` and `ftp`, so that operations are not
+/// bottlenecked by a single connection.
+///
+/// Every operation checks out a backend from the pool, or builds a new
+/// one if there are less than `max_connections` backends. Readers,
+/// writers, appenders and pagers hold their backend until they are
+/// dropped.
+///
+/// # Notes
+///
+/// - Backends are assumed broken and discarded once an operation returns
+///   [`ErrorKind::Unexpected`], which is the kind of network errors.
+///   Other errors like [`ErrorKind::NotFound`] are returned by healthy
+///   backends.
+/// - Idle backends are closed while backends are checked out or returned,
+///   there is no background task.
+/// - Only `create_dir`, `read`, `write`, `append`, `copy`, `rename`,
+///   `stat`, `delete` and `list` are pooled, blocking operations are not
+///   supported.
+///
+/// # Examples
+///
+/// ```
+/// use opendal::raw::ConnectionPool;
+/// use opendal::raw::ConnectionPoolConfig;
+/// use opendal::services::Memory;
+/// use opendal::Builder;
+/// use opendal::OperatorBuilder;
+///
+/// # fn main() -> opendal::Result<()> {
+/// let pool = ConnectionPool::new(
+///     || Memory::default().build(),
+///     ConnectionPoolConfig::default().with_max_connections(4),
+/// )?;
+/// let op = OperatorBuilder::new(pool).finish();
+/// # Ok(())
+/// # }
+/// ```
+pub struct ConnectionPool<A: Accessor> {
+    inner: Arc<PoolInner<A>>,
+    info: AccessorInfo,
+}
+
+type Factory<A> = Box<dyn Fn() -> Result<A> + Send + Sync>;
+
+struct PoolInner<A> {
+    factory: Factory<A>,
+    config: ConnectionPoolConfig,
+    semaphore: Arc<Semaphore>,
+    /// Idle backends with the time they are returned, the most recently
+    /// returned one is the last.
+    idle: Mutex<Vec<(A, Instant)>>,
+}
+
+impl<A> PoolInner<A> {
+    /// Close backends that have been idle for too long.
+    fn prune(&self, idle: &mut Vec<(A, Instant)>) {
+        while idle.len() > self.config.min_connections
+            && idle[0].1.elapsed() >= self.config.idle_timeout
+        {
+            idle.remove(0);
+        }
+    }
+}
+
+impl<A: Accessor> ConnectionPool<A> {
+    /// Create a new ConnectionPool which builds backends by `factory`.
+    ///
+    /// One backend will be built at once to get the info of the service.
+    pub fn new<F>(factory: F, config: ConnectionPoolConfig) -> Result<Self>
+    where
+        F: Fn() -> Result<A> + Send + Sync + 'static,
+    {
+        let backend = factory()?;
+        let info = pooled_info(backend.info());
+
+        let inner = PoolInner {
+            factory: Box::new(factory),
+            semaphore: Arc::new(Semaphore::new(config.max_connections)),
+            config,
+            idle: Mutex::new(vec![(backend, Instant::now())]),
+        };
+        Ok(Self {
+            inner: Arc::new(inner),
+            info,
+        })
+    }
+
+    /// Get the count of idle backends in the pool.
+    pub fn idle_connections(&self) -> usize {
+        self.inner.idle.lock().len()
+    }
+
+    /// Check out a backend from the pool, waits if all backends are in use.
+    async fn get(&self) -> Result<Pooled<A>> {
+        let permit = self
+            .inner
+            .semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|err| Error::new(ErrorKind::Unexpected, "semaphore closed").set_source(err))?;
+
+        let backend = {
+            let mut idle = self.inner.idle.lock();
+            self.inner.prune(&mut idle);
+            idle.pop().map(|(backend, _)| backend)
+        };
+        let backend = match backend {
+            Some(backend) => backend,
+            None => (self.inner.factory)()?,
+        };
+
+        Ok(Pooled {
+            backend: Some(backend),
+            pool: self.inner.clone(),
+            broken: false,
+            _permit: permit,
+        })
+    }
+}
+
+/// Only keep the capabilities of operations that are pooled.
+fn pooled_info(mut info: AccessorInfo) -> AccessorInfo {
+    let cap = info.capability();
+    info.set_capability(Capability {
+        stat: cap.stat,
+        stat_with_if_match: cap.stat_with_if_match,
+        stat_with_if_none_match: cap.stat_with_if_none_match,
+
+        read: cap.read,
+        read_can_seek: cap.read_can_seek,
+        read_can_next: cap.read_can_next,
+        read_with_range: cap.read_with_range,
+        read_with_if_match: cap.read_with_if_match,
+        read_with_if_none_match: cap.read_with_if_none_match,
+        read_with_override_cache_control: cap.read_with_override_cache_control,
+        read_with_override_content_disposition: cap.read_with_override_content_disposition,
+
+        write: cap.write,
+        write_can_sink: cap.write_can_sink,
+        write_without_content_length: cap.write_without_content_length,
+        write_with_content_type: cap.write_with_content_type,
+        write_with_content_disposition: cap.write_with_content_disposition,
+        write_with_cache_control: cap.write_with_cache_control,
+        write_with_if_none_match: cap.write_with_if_none_match,
+        write_with_expected_checksum: cap.write_with_expected_checksum,
+
+        append: cap.append,
+        append_with_content_type: cap.append_with_content_type,
+        append_with_content_disposition: cap.append_with_content_disposition,
+        append_with_cache_control: cap.append_with_cache_control,
+
+        create_dir: cap.create_dir,
+        directory_create: cap.directory_create,
+        delete: cap.delete,
+        copy: cap.copy,
+        rename: cap.rename,
+
+        list: cap.list,
+        list_with_limit: cap.list_with_limit,
+        list_with_start_after: cap.list_with_start_after,
+        list_with_prefix_filter: cap.list_with_prefix_filter,
+        list_with_delimiter_slash: cap.list_with_delimiter_slash,
+        list_without_delimiter: cap.list_without_delimiter,
+
+        ..Default::default()
+    });
+    info
+}
+
+impl<A: Accessor> Debug for ConnectionPool<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionPool")
+            .field("config", &self.inner.config)
+            .field("idle", &self.idle_connections())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A backend checked out from the pool, which will be returned while
+/// dropped unless it's broken.
+struct Pooled<A: Accessor> {
+    backend: Option<A>,
+    pool: Arc<PoolInner<A>>,
+    broken: bool,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl<A: Accessor> Pooled<A> {
+    /// Mark the backend as broken if the result is an unexpected error.
+    fn check<T>(&mut self, res: Result<T>) -> Result<T> {
+        if let Err(err) = &res {
+            if err.kind() == ErrorKind::Unexpected {
+                self.broken = true;
+            }
+        }
+        res
+    }
+}
+
+impl<A: Accessor> Deref for Pooled<A> {
+    type Target = A;
+
+    fn deref(&self) -> &A {
+        self.backend.as_ref().expect("pooled backend must be valid")
+    }
+}
+
+impl<A: Accessor> Drop for Pooled<A> {
+    fn drop(&mut self) {
+        let backend = match self.backend.take() {
+            Some(backend) if !self.broken => backend,
+            _ => return,
+        };
+
+        let mut idle = self.pool.idle.lock();
+        idle.push((backend, Instant::now()));
+        self.pool.prune(&mut idle);
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> Accessor for ConnectionPool<A> {
+    type Reader = PooledIo<A, A::Reader>;
+    type BlockingReader = ();
+    type Writer = PooledIo<A, A::Writer>;
+    type BlockingWriter = ();
+    type Appender = PooledIo<A, A::Appender>;
+    type Pager = PooledIo<A, A::Pager>;
+    type BlockingPager = ();
+
+    fn info(&self) -> AccessorInfo {
+        self.info.clone()
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let mut conn = self.get().await?;
+        let res = conn.create_dir(path, args).await;
+        conn.check(res)
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let mut conn = self.get().await?;
+        let res = conn.read(path, args).await;
+        let (rp, r) = conn.check(res)?;
+        Ok((rp, PooledIo { inner: r, conn }))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let mut conn = self.get().await?;
+        let res = conn.write(path, args).await;
+        let (rp, w) = conn.check(res)?;
+        Ok((rp, PooledIo { inner: w, conn }))
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        let mut conn = self.get().await?;
+        let res = conn.append(path, args).await;
+        let (rp, a) = conn.check(res)?;
+        Ok((rp, PooledIo { inner: a, conn }))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let mut conn = self.get().await?;
+        let res = conn.copy(from, to, args).await;
+        conn.check(res)
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let mut conn = self.get().await?;
+        let res = conn.rename(from, to, args).await;
+        conn.check(res)
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let mut conn = self.get().await?;
+        let res = conn.stat(path, args).await;
+        conn.check(res)
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let mut conn = self.get().await?;
+        let res = conn.delete(path, args).await;
+        conn.check(res)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        let mut conn = self.get().await?;
+        let res = conn.list(path, args).await;
+        let (rp, p) = conn.check(res)?;
+        Ok((rp, PooledIo { inner: p, conn }))
+    }
+}
+
+/// PooledIo holds the backend used to create `inner` until it's dropped.
+pub struct PooledIo<A: Accessor, T> {
+    inner: T,
+    conn: Pooled<A>,
+}
+
+impl<A: Accessor, R: oio::Read> oio::Read for PooledIo<A, R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let res = ready!(self.inner.poll_read(cx, buf));
+        Poll::Ready(self.conn.check(res))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: io::SeekFrom) -> Poll<Result<u64>> {
+        let res = ready!(self.inner.poll_seek(cx, pos));
+        Poll::Ready(self.conn.check(res))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        match ready!(self.inner.poll_next(cx)) {
+            Some(res) => Poll::Ready(Some(self.conn.check(res))),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Accessor, W: oio::Write> oio::Write for PooledIo<A, W> {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        let res = self.inner.write(bs).await;
+        self.conn.check(res)
+    }
+
+    async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+        let res = self.inner.sink(size, s).await;
+        self.conn.check(res)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let res = self.inner.abort().await;
+        self.conn.check(res)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let res = self.inner.close().await;
+        self.conn.check(res)
+    }
+}
+
+#[async_trait]
+impl<A: Accessor, W: oio::Append> oio::Append for PooledIo<A, W> {
+    async fn append(&mut self, bs: Bytes) -> Result<()> {
+        let res = self.inner.append(bs).await;
+        self.conn.check(res)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let res = self.inner.close().await;
+        self.conn.check(res)
+    }
+}
+
+#[async_trait]
+impl<A: Accessor, P: oio::Page> oio::Page for PooledIo<A, P> {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        let res = self.inner.next().await;
+        self.conn.check(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockService {
+        running: Arc<AtomicUsize>,
+        max_running: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = ();
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.capability_mut().stat = true;
+            am.capability_mut().presign = true;
+            am
+        }
+
+        async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+            let current = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+
+            match path {
+                "broken" => Err(Error::new(ErrorKind::Unexpected, "connection reset")),
+                "not_exist" => Err(Error::new(ErrorKind::NotFound, "not found")),
+                _ => Ok(RpStat::new(Metadata::new(EntryMode::FILE))),
+            }
+        }
+    }
+
+    fn new_pool(
+        config: ConnectionPoolConfig,
+    ) -> (
+        ConnectionPool<MockService>,
+        Arc<AtomicUsize>,
+        Arc<AtomicUsize>,
+    ) {
+        let built = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        let (b, m) = (built.clone(), max_running.clone());
+        let running = Arc::new(AtomicUsize::new(0));
+        let pool = ConnectionPool::new(
+            move || {
+                b.fetch_add(1, Ordering::SeqCst);
+                Ok(MockService {
+                    running: running.clone(),
+                    max_running: m.clone(),
+                })
+            },
+            config,
+        )
+        .expect("pool must be created");
+        (pool, built, max_running)
+    }
+
+    #[tokio::test]
+    async fn test_connection_pool() -> Result<()> {
+        let (pool, built, max_running) =
+            new_pool(ConnectionPoolConfig::default().with_max_connections(2));
+        assert!(pool.info().capability().stat);
+        assert!(!pool.info().capability().presign);
+
+        let tasks = (0..10).map(|_| pool.stat("file", OpStat::new()));
+        for res in futures::future::join_all(tasks).await {
+            res?;
+        }
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+        assert_eq!(pool.idle_connections(), 2);
+
+        // Connections are still healthy after `NotFound`.
+        pool.stat("not_exist", OpStat::new())
+            .await
+            .expect_err("must fail");
+        assert_eq!(pool.idle_connections(), 2);
+
+        // Connections are discarded after `Unexpected`.
+        pool.stat("broken", OpStat::new())
+            .await
+            .expect_err("must fail");
+        assert_eq!(pool.idle_connections(), 1);
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_connection_pool_idle_timeout() -> Result<()> {
+        let (pool, built, _) = new_pool(
+            ConnectionPoolConfig::default()
+                .with_min_connections(1)
+                .with_idle_timeout(Duration::ZERO),
+        );
+
+        let tasks = (0..3).map(|_| pool.stat("file", OpStat::new()));
+        for res in futures::future::join_all(tasks).await {
+            res?;
+        }
+        assert_eq!(built.load(Ordering::SeqCst), 3);
+        // Only `min_connections` idle connections are kept.
+        assert_eq!(pool.idle_connections(), 1);
+        Ok(())
+    }
+}
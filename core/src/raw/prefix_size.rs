@@ -0,0 +1,190 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+
+use crate::raw::oio::Page;
+use crate::raw::*;
+use crate::*;
+
+/// The max count of dirs that will be listed at the same time.
+const MAX_CONCURRENT: usize = 4;
+
+/// Scan all files under given prefix and return the total bytes of them.
+///
+/// `prefix` must be a dir path ends with `/`, use `/` for the whole
+/// storage.
+///
+/// # Notes
+///
+/// - Dirs are listed hierarchically, at most 4 dirs will be listed at
+///   the same time. Pages of the same dir are still fetched one by one.
+/// - Content length returned by list will be used directly, which is
+///   available on most object storage services like `obs`. Only files
+///   without content length will be `stat`ed.
+/// - Files deleted between listed and `stat`ed will be skipped.
+pub async fn scan_prefix_size(op: FusedAccessor, prefix: &str) -> Result<u64> {
+    let mut total = 0;
+    let mut pending = VecDeque::from([normalize_path(prefix)]);
+    let mut running = FuturesUnordered::new();
+
+    loop {
+        while running.len() < MAX_CONCURRENT {
+            match pending.pop_front() {
+                Some(dir) => running.push(scan_dir(op.clone(), dir)),
+                None => break,
+            }
+        }
+
+        match running.next().await {
+            Some(res) => {
+                let (size, dirs) = res?;
+                total += size;
+                pending.extend(dirs);
+            }
+            None => return Ok(total),
+        }
+    }
+}
+
+/// Scan given dir, returns the total bytes of files and sub dirs in it.
+async fn scan_dir(op: FusedAccessor, path: String) -> Result<(u64, Vec<String>)> {
+    let (_, mut pager) = op.list(&path, OpList::new()).await?;
+
+    let mut size = 0;
+    let mut dirs = vec![];
+    while let Some(entries) = pager.next().await? {
+        for de in entries {
+            // Some services will return the dir itself.
+            if de.path() == path {
+                continue;
+            }
+            if de.mode().is_dir() {
+                dirs.push(de.path().to_string());
+                continue;
+            }
+
+            size += match de.metadata().content_length_raw() {
+                Some(v) => v,
+                None => match op.stat(de.path(), OpStat::new()).await {
+                    Ok(rp) => rp.into_metadata().content_length(),
+                    // Files deleted after listed are skipped.
+                    Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err),
+                },
+            };
+        }
+    }
+
+    Ok((size, dirs))
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// MockService lists `a` and `b` without content length, `b` has been
+    /// deleted after listed.
+    #[derive(Debug)]
+    struct MockService;
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = MockPager;
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.capability_mut().stat = true;
+            am.capability_mut().list = true;
+            am
+        }
+
+        async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+            match path {
+                "a" => Ok(RpStat::new(
+                    Metadata::new(EntryMode::FILE).with_content_length(2),
+                )),
+                _ => Err(Error::new(ErrorKind::NotFound, "file not found")),
+            }
+        }
+
+        async fn list(&self, _: &str, _: OpList) -> Result<(RpList, Self::Pager)> {
+            Ok((RpList::default(), MockPager { done: false }))
+        }
+    }
+
+    struct MockPager {
+        done: bool,
+    }
+
+    #[async_trait]
+    impl Page for MockPager {
+        async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+            if self.done {
+                return Ok(None);
+            }
+            self.done = true;
+
+            Ok(Some(
+                ["a", "b"]
+                    .iter()
+                    .map(|path| oio::Entry::new(path, Metadata::new(EntryMode::FILE)))
+                    .collect(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_prefix_size_skip_deleted() -> Result<()> {
+        let op = OperatorBuilder::new(MockService).finish();
+
+        assert_eq!(scan_prefix_size(op.into(), "/").await?, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scan_prefix_size() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+
+        let mut expected = 0;
+        for i in 0..100 {
+            let path = format!("data/{}/{}/file-{i}", i % 3, i % 7);
+            op.write(&path, vec![0; i]).await?;
+            expected += i as u64;
+        }
+        op.write("other", vec![0; 1024]).await?;
+
+        assert_eq!(
+            scan_prefix_size(op.clone().into(), "data/").await?,
+            expected
+        );
+        assert_eq!(scan_prefix_size(op.into(), "/").await?, expected + 1024);
+        Ok(())
+    }
+}
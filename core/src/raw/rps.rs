@@ -15,8 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use http::Request;
 
+use crate::raw::MultipartPart;
 use crate::*;
 
 /// Reply for `create_dir` operation
@@ -31,6 +34,10 @@ pub struct RpDelete {}
 #[derive(Debug, Clone, Default)]
 pub struct RpList {}
 
+/// Reply for `list_versions` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpListVersions {}
+
 /// Reply for `presign` operation.
 #[derive(Debug, Clone)]
 pub struct RpPresign {
@@ -49,6 +56,51 @@ impl RpPresign {
     }
 }
 
+/// Reply for `presign_post` operation.
+#[derive(Debug, Clone)]
+pub struct RpPresignPost {
+    post: PresignedPost,
+}
+
+impl RpPresignPost {
+    /// Create a new reply for `presign_post`.
+    pub fn new(post: PresignedPost) -> Self {
+        RpPresignPost { post }
+    }
+
+    /// Consume reply to get the presigned post.
+    pub fn into_presigned_post(self) -> PresignedPost {
+        self.post
+    }
+}
+
+/// PresignedPost is a presigned form upload returned by `presign_post`.
+///
+/// Users should render a html form which posts to `url` with all `fields`
+/// as hidden inputs, the file to upload must be the last field named `file`.
+#[derive(Debug, Clone)]
+pub struct PresignedPost {
+    url: http::Uri,
+    fields: HashMap<String, String>,
+}
+
+impl PresignedPost {
+    /// Create a new PresignedPost
+    pub fn new(url: http::Uri, fields: HashMap<String, String>) -> Self {
+        Self { url, fields }
+    }
+
+    /// Return the url that form should be posted to.
+    pub fn url(&self) -> &http::Uri {
+        &self.url
+    }
+
+    /// Return the fields that form must carry.
+    pub fn fields(&self) -> &HashMap<String, String> {
+        &self.fields
+    }
+}
+
 /// PresignedRequest is a presigned request return by `presign`.
 #[derive(Debug, Clone)]
 pub struct PresignedRequest {
@@ -228,6 +280,323 @@ impl RpRename {
     }
 }
 
+/// Reply for `touch` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpTouch {}
+
+impl RpTouch {
+    /// Create a new reply for `touch`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `head` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpHead {
+    content_length: u64,
+    etag: Option<String>,
+}
+
+impl RpHead {
+    /// Create a new reply for `head`.
+    pub fn new(content_length: u64, etag: Option<&str>) -> Self {
+        Self {
+            content_length,
+            etag: etag.map(|v| v.to_string()),
+        }
+    }
+
+    /// Get the content length from reply.
+    pub fn content_length(&self) -> u64 {
+        self.content_length
+    }
+
+    /// Get the etag from reply.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Consume reply to get content length and etag.
+    pub fn into_parts(self) -> (u64, Option<String>) {
+        (self.content_length, self.etag)
+    }
+}
+
+/// Reply for `get_lifecycle_rules` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetLifecycleRules {
+    rules: Vec<LifecycleRule>,
+}
+
+impl RpGetLifecycleRules {
+    /// Create a new reply for `get_lifecycle_rules`.
+    pub fn new(rules: Vec<LifecycleRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Get the rules from reply.
+    pub fn rules(&self) -> &[LifecycleRule] {
+        &self.rules
+    }
+
+    /// Consume reply to get the rules.
+    pub fn into_rules(self) -> Vec<LifecycleRule> {
+        self.rules
+    }
+}
+
+/// Reply for `set_lifecycle_rules` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetLifecycleRules {}
+
+impl RpSetLifecycleRules {
+    /// Create a new reply for `set_lifecycle_rules`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `get_cors_config` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetCorsConfig {
+    rules: Vec<CorsRule>,
+}
+
+impl RpGetCorsConfig {
+    /// Create a new reply for `get_cors_config`.
+    pub fn new(rules: Vec<CorsRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Get the rules from reply.
+    pub fn rules(&self) -> &[CorsRule] {
+        &self.rules
+    }
+
+    /// Consume reply to get the rules.
+    pub fn into_rules(self) -> Vec<CorsRule> {
+        self.rules
+    }
+}
+
+/// Reply for `set_cors_config` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetCorsConfig {}
+
+impl RpSetCorsConfig {
+    /// Create a new reply for `set_cors_config`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `get_bucket_acl` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetBucketAcl {
+    acl: BucketAcl,
+}
+
+impl RpGetBucketAcl {
+    /// Create a new reply for `get_bucket_acl`.
+    pub fn new(acl: BucketAcl) -> Self {
+        Self { acl }
+    }
+
+    /// Get the acl from reply.
+    pub fn acl(&self) -> &BucketAcl {
+        &self.acl
+    }
+
+    /// Consume reply to get the acl.
+    pub fn into_acl(self) -> BucketAcl {
+        self.acl
+    }
+}
+
+/// Reply for `set_bucket_acl` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetBucketAcl {}
+
+impl RpSetBucketAcl {
+    /// Create a new reply for `set_bucket_acl`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `get_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetTags {
+    tags: HashMap<String, String>,
+}
+
+impl RpGetTags {
+    /// Create a new reply for `get_tags`.
+    pub fn new(tags: HashMap<String, String>) -> Self {
+        Self { tags }
+    }
+
+    /// Get the tags from reply.
+    pub fn tags(&self) -> &HashMap<String, String> {
+        &self.tags
+    }
+
+    /// Consume reply to get the tags.
+    pub fn into_tags(self) -> HashMap<String, String> {
+        self.tags
+    }
+}
+
+/// Reply for `get_restore_status` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetRestoreStatus {
+    status: RestoreStatus,
+}
+
+impl RpGetRestoreStatus {
+    /// Create a new reply for `get_restore_status`.
+    pub fn new(status: RestoreStatus) -> Self {
+        Self { status }
+    }
+
+    /// Get the restore status from reply.
+    pub fn status(&self) -> &RestoreStatus {
+        &self.status
+    }
+
+    /// Consume reply to get the restore status.
+    pub fn into_status(self) -> RestoreStatus {
+        self.status
+    }
+}
+
+/// Reply for `set_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetTags {}
+
+impl RpSetTags {
+    /// Create a new reply for `set_tags`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `update_metadata` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpUpdateMetadata {}
+
+impl RpUpdateMetadata {
+    /// Create a new reply for `update_metadata`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `get_object_lock` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetObjectLock {
+    config: Option<ObjectLockConfig>,
+}
+
+impl RpGetObjectLock {
+    /// Create a new reply for `get_object_lock`.
+    pub fn new(config: Option<ObjectLockConfig>) -> Self {
+        Self { config }
+    }
+
+    /// Get the lock config from reply.
+    pub fn config(&self) -> Option<&ObjectLockConfig> {
+        self.config.as_ref()
+    }
+
+    /// Consume reply to get the lock config.
+    pub fn into_config(self) -> Option<ObjectLockConfig> {
+        self.config
+    }
+}
+
+/// Reply for `lock_object` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpLockObject {}
+
+impl RpLockObject {
+    /// Create a new reply for `lock_object`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `create_multipart` operation.
+#[derive(Debug, Clone)]
+pub struct RpCreateMultipart {
+    upload_id: String,
+}
+
+impl RpCreateMultipart {
+    /// Create a new reply for `create_multipart`.
+    pub fn new(upload_id: &str) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+        }
+    }
+
+    /// Get the upload_id of this multipart upload.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Consume reply to get the upload_id.
+    pub fn into_upload_id(self) -> String {
+        self.upload_id
+    }
+}
+
+/// Reply for `write_multipart` operation.
+#[derive(Debug, Clone)]
+pub struct RpWriteMultipart {
+    part: MultipartPart,
+}
+
+impl RpWriteMultipart {
+    /// Create a new reply for `write_multipart`.
+    pub fn new(part: MultipartPart) -> Self {
+        Self { part }
+    }
+
+    /// Get the uploaded part.
+    pub fn part(&self) -> &MultipartPart {
+        &self.part
+    }
+
+    /// Consume reply to get the uploaded part.
+    pub fn into_part(self) -> MultipartPart {
+        self.part
+    }
+}
+
+/// Reply for `complete_multipart` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpCompleteMultipart {}
+
+impl RpCompleteMultipart {
+    /// Create a new reply for `complete_multipart`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `abort_multipart` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpAbortMultipart {}
+
+impl RpAbortMultipart {
+    /// Create a new reply for `abort_multipart`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
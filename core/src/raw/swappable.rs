@@ -0,0 +1,328 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use parking_lot::RwLock;
+
+use crate::raw::*;
+use crate::*;
+
+/// SwappableAccessor is an accessor whose underlying backend can be
+/// swapped at runtime.
+///
+/// # Notes
+///
+/// Every operation will take a read lock to clone the current backend,
+/// release the lock and dispatch to it. So in-flight operations will
+/// complete against the old backend while new operations go to the new
+/// one. No in-flight operation will be interrupted by [`SwappableAccessor::swap`].
+///
+/// # Examples
+///
+/// ```
+/// use opendal::raw::SwappableAccessor;
+/// use opendal::services::Memory;
+/// use opendal::Operator;
+/// use opendal::OperatorBuilder;
+///
+/// # fn main() -> opendal::Result<()> {
+/// let primary = Operator::new(Memory::default())?.finish();
+/// let acc = SwappableAccessor::new(primary.into());
+///
+/// // Keep a handle of `acc` to swap the backend later.
+/// let op = OperatorBuilder::new(acc.clone()).finish();
+///
+/// let secondary = Operator::new(Memory::default())?.finish();
+/// acc.swap(secondary.into());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SwappableAccessor {
+    inner: Arc<RwLock<FusedAccessor>>,
+}
+
+impl SwappableAccessor {
+    /// Create a new swappable accessor with given backend.
+    pub fn new(inner: FusedAccessor) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(inner)),
+        }
+    }
+
+    /// Replace the backend with the new one.
+    ///
+    /// All calls after `swap` returned will go to the new backend.
+    pub fn swap(&self, new_backend: FusedAccessor) {
+        *self.inner.write() = new_backend;
+    }
+
+    /// Clone the current backend out so that the lock will not be held
+    /// during the operation.
+    fn current(&self) -> FusedAccessor {
+        self.inner.read().clone()
+    }
+}
+
+#[async_trait]
+impl Accessor for SwappableAccessor {
+    type Reader = oio::Reader;
+    type BlockingReader = oio::BlockingReader;
+    type Writer = oio::Writer;
+    type BlockingWriter = oio::BlockingWriter;
+    type Appender = oio::Appender;
+    type Pager = oio::Pager;
+    type BlockingPager = oio::BlockingPager;
+
+    fn info(&self) -> AccessorInfo {
+        self.current().info()
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.current().create_dir(path, args).await
+    }
+
+    async fn ensure_dir(&self, path: &str) -> Result<()> {
+        self.current().ensure_dir(path).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.current().read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.current().write(path, args).await
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.current().append(path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.current().copy(from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.current().rename(from, to, args).await
+    }
+
+    async fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        self.current().create_symlink(src, dst).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.current().stat(path, args).await
+    }
+
+    async fn object_exists(&self, path: &str) -> Result<bool> {
+        self.current().object_exists(path).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.current().delete(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.current().list(path, args).await
+    }
+
+    async fn list_versions(
+        &self,
+        path: &str,
+        args: OpListVersions,
+    ) -> Result<(RpListVersions, oio::Pager)> {
+        self.current().list_versions(path, args).await
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.current().presign(path, args).await
+    }
+
+    async fn create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        self.current().create_multipart(path, args).await
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        bs: Bytes,
+    ) -> Result<RpWriteMultipart> {
+        self.current().write_multipart(path, args, bs).await
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.current().complete_multipart(path, args).await
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        self.current().abort_multipart(path, args).await
+    }
+
+    async fn touch(&self, path: &str, args: OpTouch) -> Result<RpTouch> {
+        self.current().touch(path, args).await
+    }
+
+    async fn get_lifecycle_rules(&self, args: OpGetLifecycleRules) -> Result<RpGetLifecycleRules> {
+        self.current().get_lifecycle_rules(args).await
+    }
+
+    async fn set_lifecycle_rules(&self, args: OpSetLifecycleRules) -> Result<RpSetLifecycleRules> {
+        self.current().set_lifecycle_rules(args).await
+    }
+
+    async fn get_cors_config(&self, args: OpGetCorsConfig) -> Result<RpGetCorsConfig> {
+        self.current().get_cors_config(args).await
+    }
+
+    async fn set_cors_config(&self, args: OpSetCorsConfig) -> Result<RpSetCorsConfig> {
+        self.current().set_cors_config(args).await
+    }
+
+    async fn get_bucket_acl(&self, args: OpGetBucketAcl) -> Result<RpGetBucketAcl> {
+        self.current().get_bucket_acl(args).await
+    }
+
+    async fn set_bucket_acl(&self, args: OpSetBucketAcl) -> Result<RpSetBucketAcl> {
+        self.current().set_bucket_acl(args).await
+    }
+
+    async fn head(&self, path: &str, args: OpHead) -> Result<RpHead> {
+        self.current().head(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.current().get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        self.current().set_tags(path, args).await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        self.current().update_metadata(path, args).await
+    }
+
+    async fn get_object_lock(&self, path: &str, args: OpGetObjectLock) -> Result<RpGetObjectLock> {
+        self.current().get_object_lock(path, args).await
+    }
+
+    async fn lock_object(&self, path: &str, args: OpLockObject) -> Result<RpLockObject> {
+        self.current().lock_object(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.current().presign_post(path, args).await
+    }
+
+    async fn get_restore_status(
+        &self,
+        path: &str,
+        args: OpGetRestoreStatus,
+    ) -> Result<RpGetRestoreStatus> {
+        self.current().get_restore_status(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.current().batch(args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.current().blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.current().blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.current().blocking_write(path, args)
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.current().blocking_copy(from, to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.current().blocking_rename(from, to, args)
+    }
+
+    fn blocking_create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        self.current().blocking_create_symlink(src, dst)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.current().blocking_stat(path, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.current().blocking_delete(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.current().blocking_list(path, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_swap() {
+        let primary = Operator::new(Memory::default()).unwrap().finish();
+        primary.write("data", "primary").await.unwrap();
+        let secondary = Operator::new(Memory::default()).unwrap().finish();
+        secondary.write("data", "secondary").await.unwrap();
+
+        let acc = SwappableAccessor::new(primary.into());
+        let op = OperatorBuilder::new(acc.clone()).finish();
+        assert_eq!(op.read("data").await.unwrap(), b"primary");
+
+        // Reader created before swap should still read from primary.
+        let mut r = op.reader("data").await.unwrap();
+
+        acc.swap(secondary.into());
+        assert_eq!(op.read("data").await.unwrap(), b"secondary");
+
+        let mut bs = vec![];
+        futures::AsyncReadExt::read_to_end(&mut r, &mut bs)
+            .await
+            .unwrap();
+        assert_eq!(bs, b"primary");
+    }
+}
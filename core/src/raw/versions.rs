@@ -0,0 +1,198 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::raw::oio::Page;
+use crate::raw::*;
+use crate::*;
+
+/// VersionedEntry is a version of file returned by [`list_all_versions_sorted`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionedEntry {
+    /// Path of the file.
+    pub path: String,
+    /// Version id of this version.
+    pub version_id: String,
+    /// Last modified time of this version.
+    pub last_modified: Option<DateTime<Utc>>,
+    /// Size of this version, delete markers always have size `0`.
+    pub size: u64,
+    /// Whether this version is a delete marker.
+    pub is_delete_marker: bool,
+}
+
+/// List all versions (including delete markers) under given prefix, sorted
+/// by path and then by last modified time with the newest first.
+///
+/// Require [`Capability::versioning`].
+///
+/// # Notes
+///
+/// All pages will be collected and sorted in memory, which takes about
+/// `number of versions * ~200 bytes`. For example, 10 million versions will
+/// take about 2 GiB memory. For very large buckets, please consider
+/// [`Accessor::list_versions`] or [`Operator::list_versions`] which stream
+/// entries page by page instead.
+pub async fn list_all_versions_sorted(
+    op: FusedAccessor,
+    prefix: &str,
+) -> Result<Vec<VersionedEntry>> {
+    let (_, mut pager) = op
+        .list_versions(prefix, OpListVersions::new().with_delete_markers(true))
+        .await?;
+
+    let mut versions = Vec::new();
+    while let Some(entries) = pager.next().await? {
+        for de in entries {
+            let meta = de.metadata();
+            let version_id = meta.version().ok_or_else(|| {
+                Error::new(ErrorKind::Unexpected, "listed entry doesn't have version")
+                    .with_operation("list_all_versions_sorted")
+                    .with_context("path", de.path())
+            })?;
+
+            versions.push(VersionedEntry {
+                path: de.path().to_string(),
+                version_id: version_id.to_string(),
+                last_modified: meta.last_modified(),
+                size: meta.content_length(),
+                is_delete_marker: meta.is_delete_marker(),
+            });
+        }
+    }
+
+    // Sort is stable, so versions with the same last modified time will
+    // keep the order returned by services.
+    versions.sort_by(|a, b| {
+        a.path
+            .cmp(&b.path)
+            .then_with(|| match (a.last_modified, b.last_modified) {
+                (Some(a), Some(b)) => b.cmp(&a),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            })
+    });
+
+    Ok(versions)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockService;
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = oio::Reader;
+        type BlockingReader = oio::BlockingReader;
+        type Writer = oio::Writer;
+        type BlockingWriter = oio::BlockingWriter;
+        type Appender = oio::Appender;
+        type Pager = oio::Pager;
+        type BlockingPager = oio::BlockingPager;
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.capability_mut().versioning = true;
+            am
+        }
+
+        async fn list_versions(
+            &self,
+            _: &str,
+            args: OpListVersions,
+        ) -> Result<(RpListVersions, oio::Pager)> {
+            assert!(args.delete_markers());
+
+            Ok((
+                RpListVersions::default(),
+                Box::new(MockPager {
+                    pages: vec![
+                        vec![
+                            new_entry("b", "b1", 1, false),
+                            new_entry("a", "a1", 1, false),
+                        ],
+                        vec![
+                            new_entry("b", "b3", 3, true),
+                            new_entry("a", "a2", 2, false),
+                            new_entry("b", "b2", 2, false),
+                        ],
+                    ],
+                }),
+            ))
+        }
+    }
+
+    struct MockPager {
+        pages: Vec<Vec<oio::Entry>>,
+    }
+
+    #[async_trait]
+    impl Page for MockPager {
+        async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+            if self.pages.is_empty() {
+                return Ok(None);
+            }
+            Ok(Some(self.pages.remove(0)))
+        }
+    }
+
+    fn new_entry(path: &str, version: &str, ts: i64, is_delete_marker: bool) -> oio::Entry {
+        let meta = Metadata::new(EntryMode::FILE)
+            .with_content_length(if is_delete_marker { 0 } else { 4 })
+            .with_version(version.to_string())
+            .with_last_modified(
+                parse_datetime_from_rfc3339(&format!("2023-06-0{ts}T00:00:00Z")).unwrap(),
+            )
+            .with_delete_marker(is_delete_marker);
+        oio::Entry::new(path, meta)
+    }
+
+    #[tokio::test]
+    async fn test_list_all_versions_sorted() -> Result<()> {
+        let versions = list_all_versions_sorted(Arc::new(MockService), "").await?;
+
+        let got: Vec<_> = versions
+            .iter()
+            .map(|v| (v.path.as_str(), v.version_id.as_str(), v.is_delete_marker))
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                ("a", "a2", false),
+                ("a", "a1", false),
+                ("b", "b3", true),
+                ("b", "b2", false),
+                ("b", "b1", false),
+            ]
+        );
+        assert_eq!(versions[2].size, 0);
+        assert_eq!(versions[3].size, 4);
+        Ok(())
+    }
+}
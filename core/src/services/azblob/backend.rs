@@ -39,6 +39,7 @@ use super::pager::AzblobPager;
 use super::writer::AzblobWriter;
 use crate::raw::*;
 use crate::services::azblob::core::AzblobCore;
+use crate::services::azblob::core::CUSTOM_METADATA_HEADERS;
 use crate::types::Metadata;
 use crate::*;
 
@@ -541,6 +542,7 @@ impl Accessor for AzblobBackend {
 
                 delete: true,
                 create_dir: true,
+                directory_create: true,
                 copy: true,
 
                 list: true,
@@ -655,7 +657,11 @@ impl Accessor for AzblobBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut meta = parse_into_metadata(path, resp.headers())?;
+                parse_into_custom_metadata(resp.headers(), CUSTOM_METADATA_HEADERS, &mut meta)?;
+                Ok(RpStat::new(meta))
+            }
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
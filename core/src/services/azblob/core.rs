@@ -36,6 +36,14 @@ use reqsign::AzureStorageSigner;
 use crate::raw::*;
 use crate::*;
 
+/// Backend-specific headers returned by stat which will be kept in custom metadata.
+pub const CUSTOM_METADATA_HEADERS: &[&str] = &[
+    "x-ms-access-tier",
+    "x-ms-blob-type",
+    "x-ms-lease-status",
+    "x-ms-server-encrypted",
+];
+
 mod constants {
     pub const X_MS_VERSION: &str = "x-ms-version";
 
@@ -277,6 +277,7 @@ impl Accessor for CosBackend {
 
                 delete: true,
                 create_dir: true,
+                directory_create: true,
                 copy: true,
 
                 list: true,
@@ -213,6 +213,22 @@ impl FsBackend {
         Ok(p)
     }
 
+    /// Check whether the write should only create a new file.
+    ///
+    /// Only `*` is supported for If-None-Match on fs.
+    fn is_create_new(args: &OpWrite) -> Result<bool> {
+        match args.if_none_match() {
+            None => Ok(false),
+            Some("*") => Ok(true),
+            Some(v) => Err(Error::new(
+                ErrorKind::Unsupported,
+                "fs only supports write with if none match as `*`",
+            )
+            .with_operation(Operation::Write)
+            .with_context("if_none_match", v)),
+        }
+    }
+
     // Build write path and ensure the parent dirs created
     async fn ensure_write_abs_path(parent: &Path, path: &str) -> Result<PathBuf> {
         let p = parent.join(path);
@@ -266,6 +282,9 @@ impl Accessor for FsBackend {
                 write: true,
                 write_can_sink: true,
                 write_without_content_length: true,
+                // Write with if none match relies on `O_EXCL` which can't
+                // be kept while writing into atomic write dir.
+                write_with_if_none_match: self.atomic_write_dir.is_none(),
                 create_dir: true,
                 delete: true,
 
@@ -276,6 +295,7 @@ impl Accessor for FsBackend {
 
                 copy: true,
                 rename: true,
+                symlink: cfg!(unix),
                 blocking: true,
 
                 ..Default::default()
@@ -294,6 +314,19 @@ impl Accessor for FsBackend {
         Ok(RpCreateDir::default())
     }
 
+    async fn ensure_dir(&self, path: &str) -> Result<()> {
+        if path.ends_with('/') {
+            let p = self.root.join(path.trim_end_matches('/'));
+            tokio::fs::create_dir_all(&p)
+                .await
+                .map_err(parse_io_error)?;
+        } else {
+            Self::ensure_write_abs_path(&self.root, path).await?;
+        }
+
+        Ok(())
+    }
+
     /// # Notes
     ///
     /// There are three ways to get the total file length:
@@ -366,7 +399,9 @@ impl Accessor for FsBackend {
         Ok((RpRead::new(end - start), r))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let create_new = Self::is_create_new(&args)?;
+
         let (target_path, tmp_path) = if let Some(atomic_write_dir) = &self.atomic_write_dir {
             let target_path = Self::ensure_write_abs_path(&self.root, path).await?;
             let tmp_path =
@@ -378,9 +413,13 @@ impl Accessor for FsBackend {
             (p, None)
         };
 
-        let f = tokio::fs::OpenOptions::new()
-            .create(true)
-            .truncate(true)
+        let mut opts = tokio::fs::OpenOptions::new();
+        if create_new {
+            opts.create_new(true);
+        } else {
+            opts.create(true).truncate(true);
+        }
+        let f = opts
             .write(true)
             .open(tmp_path.as_ref().unwrap_or(&target_path))
             .await
@@ -429,10 +468,28 @@ impl Accessor for FsBackend {
         Ok(RpRename::default())
     }
 
+    #[cfg(unix)]
+    async fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        let src = self.root.join(src.trim_end_matches('/'));
+        let dst = Self::ensure_write_abs_path(&self.root, dst.trim_end_matches('/')).await?;
+
+        tokio::fs::symlink(src, dst).await.map_err(parse_io_error)
+    }
+
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
         let p = self.root.join(path.trim_end_matches('/'));
 
-        let meta = tokio::fs::metadata(&p).await.map_err(parse_io_error)?;
+        let meta = tokio::fs::symlink_metadata(&p)
+            .await
+            .map_err(parse_io_error)?;
+        // Report the target's metadata except mode for symlinks, metadata
+        // of broken symlinks will be kept.
+        let is_symlink = meta.file_type().is_symlink();
+        let meta = if is_symlink {
+            tokio::fs::metadata(&p).await.unwrap_or(meta)
+        } else {
+            meta
+        };
 
         if self.enable_path_check && meta.is_dir() != path.ends_with('/') {
             return Err(Error::new(
@@ -441,7 +498,9 @@ impl Accessor for FsBackend {
             ));
         }
 
-        let mode = if meta.is_dir() {
+        let mode = if is_symlink {
+            EntryMode::SYMLINK
+        } else if meta.is_dir() {
             EntryMode::DIR
         } else if meta.is_file() {
             EntryMode::FILE
@@ -462,7 +521,8 @@ impl Accessor for FsBackend {
     async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
         let p = self.root.join(path.trim_end_matches('/'));
 
-        let meta = tokio::fs::metadata(&p).await;
+        // Symlinks are removed as files instead of following them.
+        let meta = tokio::fs::symlink_metadata(&p).await;
 
         match meta {
             Ok(meta) => {
@@ -566,7 +626,9 @@ impl Accessor for FsBackend {
         Ok((RpRead::new(end - start), r))
     }
 
-    fn blocking_write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let create_new = Self::is_create_new(&args)?;
+
         let (target_path, tmp_path) = if let Some(atomic_write_dir) = &self.atomic_write_dir {
             let target_path = Self::blocking_ensure_write_abs_path(&self.root, path)?;
             let tmp_path =
@@ -578,9 +640,13 @@ impl Accessor for FsBackend {
             (p, None)
         };
 
-        let f = std::fs::OpenOptions::new()
-            .create(true)
-            .truncate(true)
+        let mut opts = std::fs::OpenOptions::new();
+        if create_new {
+            opts.create_new(true);
+        } else {
+            opts.create(true).truncate(true);
+        }
+        let f = opts
             .write(true)
             .open(tmp_path.as_ref().unwrap_or(&target_path))
             .map_err(parse_io_error)?;
@@ -614,10 +680,24 @@ impl Accessor for FsBackend {
         Ok(RpRename::default())
     }
 
+    #[cfg(unix)]
+    fn blocking_create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        let src = self.root.join(src.trim_end_matches('/'));
+        let dst = Self::blocking_ensure_write_abs_path(&self.root, dst.trim_end_matches('/'))?;
+
+        std::os::unix::fs::symlink(src, dst).map_err(parse_io_error)
+    }
+
     fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
         let p = self.root.join(path.trim_end_matches('/'));
 
-        let meta = std::fs::metadata(p).map_err(parse_io_error)?;
+        let meta = std::fs::symlink_metadata(&p).map_err(parse_io_error)?;
+        let is_symlink = meta.file_type().is_symlink();
+        let meta = if is_symlink {
+            std::fs::metadata(&p).unwrap_or(meta)
+        } else {
+            meta
+        };
 
         if self.enable_path_check && meta.is_dir() != path.ends_with('/') {
             return Err(Error::new(
@@ -626,7 +706,9 @@ impl Accessor for FsBackend {
             ));
         }
 
-        let mode = if meta.is_dir() {
+        let mode = if is_symlink {
+            EntryMode::SYMLINK
+        } else if meta.is_dir() {
             EntryMode::DIR
         } else if meta.is_file() {
             EntryMode::FILE
@@ -647,7 +729,7 @@ impl Accessor for FsBackend {
     fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
         let p = self.root.join(path.trim_end_matches('/'));
 
-        let meta = std::fs::metadata(&p);
+        let meta = std::fs::symlink_metadata(&p);
 
         match meta {
             Ok(meta) => {
@@ -686,8 +768,103 @@ impl Accessor for FsBackend {
 
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
+
     use super::*;
 
+    #[tokio::test]
+    async fn test_ensure_dir() -> Result<()> {
+        let root = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        let mut builder = FsBuilder::default();
+        builder.root(&root.to_string_lossy());
+        let backend = builder.build()?;
+
+        backend.ensure_dir("a/b/c/d/file").await?;
+        assert!(root.join("a/b/c/d").is_dir());
+        assert!(!root.join("a/b/c/d/file").exists());
+
+        backend.ensure_dir("x/y/z/").await?;
+        assert!(root.join("x/y/z").is_dir());
+        // Dirs exist already.
+        backend.ensure_dir("x/y/z/").await?;
+
+        let mut builder = FsBuilder::default();
+        builder.root(&root.to_string_lossy());
+        let op = Operator::new(builder)?.finish();
+        op.write("deep/nested/path/to/file", "Hello").await?;
+        assert!(root.join("deep/nested/path/to").is_dir());
+        assert_eq!(op.read("deep/nested/path/to/file").await?, b"Hello");
+
+        std::fs::remove_dir_all(&root).map_err(parse_io_error)?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_create_symlink() -> Result<()> {
+        let root = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        let mut builder = FsBuilder::default();
+        builder.root(&root.to_string_lossy());
+        let op = Operator::new(builder)?.finish();
+        assert!(op.info().can_symlink());
+
+        op.write("dir/file", "Hello").await?;
+        op.create_symlink("dir/file", "links/link").await?;
+        assert!(std::fs::symlink_metadata(root.join("links/link"))
+            .map_err(parse_io_error)?
+            .file_type()
+            .is_symlink());
+
+        let meta = op.stat("links/link").await?;
+        assert_eq!(meta.mode(), EntryMode::SYMLINK);
+        assert_eq!(meta.content_length(), 5);
+        assert_eq!(op.read("links/link").await?, b"Hello");
+
+        let entries = op.list("links/").await?.try_collect::<Vec<_>>().await?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].metadata().as_ref().map(|v| v.mode()),
+            Some(EntryMode::SYMLINK)
+        );
+
+        let err = op
+            .create_symlink("dir/file", "links/link")
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        // Delete removes the link instead of its target.
+        op.delete("links/link").await?;
+        assert!(!op.is_exist("links/link").await?);
+        assert!(op.is_exist("dir/file").await?);
+
+        std::fs::remove_dir_all(&root).map_err(parse_io_error)?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_blocking_create_symlink() -> Result<()> {
+        let root = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        let mut builder = FsBuilder::default();
+        builder.root(&root.to_string_lossy());
+        let op = Operator::new(builder)?.finish().blocking();
+
+        op.write("dir/file", "Hello")?;
+        op.create_symlink("dir/file", "links/link")?;
+
+        let meta = op.stat("links/link")?;
+        assert_eq!(meta.mode(), EntryMode::SYMLINK);
+        assert_eq!(meta.content_length(), 5);
+        assert_eq!(op.read("links/link")?, b"Hello");
+
+        let err = op.create_symlink("dir/file", "links/link").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        std::fs::remove_dir_all(&root).map_err(parse_io_error)?;
+        Ok(())
+    }
+
     #[test]
     fn test_tmp_file_of() {
         let cases = vec![
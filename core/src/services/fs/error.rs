@@ -27,6 +27,7 @@ pub fn parse_io_error(err: io::Error) -> Error {
     let (kind, retryable) = match err.kind() {
         NotFound => (ErrorKind::NotFound, false),
         PermissionDenied => (ErrorKind::PermissionDenied, false),
+        AlreadyExists => (ErrorKind::AlreadyExists, false),
         Interrupted | UnexpectedEof | TimedOut | WouldBlock => (ErrorKind::Unexpected, true),
         _ => (ErrorKind::Unexpected, true),
     };
@@ -74,6 +74,8 @@ impl oio::Page for FsPager<tokio::fs::ReadDir> {
             } else if file_type.is_dir() {
                 // Make sure we are returning the correct path.
                 oio::Entry::new(&format!("{rel_path}/"), Metadata::new(EntryMode::DIR))
+            } else if file_type.is_symlink() {
+                oio::Entry::new(&rel_path, Metadata::new(EntryMode::SYMLINK))
             } else {
                 oio::Entry::new(&rel_path, Metadata::new(EntryMode::Unknown))
             };
@@ -115,6 +117,8 @@ impl oio::BlockingPage for FsPager<std::fs::ReadDir> {
             } else if file_type.is_dir() {
                 // Make sure we are returning the correct path.
                 oio::Entry::new(&format!("{rel_path}/"), Metadata::new(EntryMode::DIR))
+            } else if file_type.is_symlink() {
+                oio::Entry::new(&rel_path, Metadata::new(EntryMode::SYMLINK))
             } else {
                 oio::Entry::new(&rel_path, Metadata::new(EntryMode::Unknown))
             };
@@ -130,7 +130,9 @@ impl Builder for FtpBuilder {
         };
 
         let host = endpoint_uri.host().unwrap_or("127.0.0.1");
-        let port = endpoint_uri.port_u16().unwrap_or(21);
+        let port = endpoint_uri
+            .port_u16()
+            .unwrap_or_else(|| Scheme::Ftp.default_port());
 
         let endpoint = format!("{host}:{port}");
 
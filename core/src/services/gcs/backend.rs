@@ -406,6 +406,7 @@ impl Accessor for GcsBackend {
             .set_name(&self.core.bucket)
             .set_capability(Capability {
                 create_dir: true,
+                directory_create: true,
 
                 stat: true,
                 stat_with_if_match: true,
@@ -79,7 +79,7 @@ impl oio::Page for IpmfsPager {
                         EntryMode::DIR => {
                             format!("{}{}/", &self.path, object.name)
                         }
-                        EntryMode::Unknown => unreachable!(),
+                        EntryMode::SYMLINK | EntryMode::Unknown => unreachable!(),
                     };
 
                     let path = build_rel_path(&self.root, &path);
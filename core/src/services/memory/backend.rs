@@ -102,6 +102,10 @@ impl typed_kv::Adapter for Adapter {
         }
     }
 
+    async fn contains(&self, path: &str) -> Result<bool> {
+        Ok(self.inner.lock().contains_key(path))
+    }
+
     async fn set(&self, path: &str, value: typed_kv::Value) -> Result<()> {
         self.blocking_set(path, value)
     }
@@ -155,4 +159,16 @@ mod tests {
         let b2 = MemoryBuilder::default().build().unwrap();
         assert_ne!(b1.info().name(), b2.info().name())
     }
+
+    #[tokio::test]
+    async fn test_is_exist() -> Result<()> {
+        let op = Operator::new(MemoryBuilder::default())?.finish();
+        assert!(op.info().can_exists());
+
+        op.write("file", "Hello").await?;
+        assert!(op.is_exist("file").await?);
+        assert!(!op.is_exist("not_exist").await?);
+        assert!(op.is_exist("dir/").await?);
+        Ok(())
+    }
 }
@@ -103,6 +103,24 @@ pub use self::moka::Moka;
 mod obs;
 #[cfg(feature = "services-obs")]
 pub use obs::Obs;
+#[cfg(feature = "services-obs")]
+pub use obs::ObsBackend;
+#[cfg(feature = "services-obs")]
+pub use obs::ObsBucketInfo;
+#[cfg(feature = "services-obs")]
+pub use obs::ObsClientInfo;
+#[cfg(feature = "services-obs")]
+pub use obs::ObsDebugInfo;
+#[cfg(feature = "services-obs")]
+pub use obs::ObsError;
+#[cfg(feature = "services-obs")]
+pub use obs::ObsIncompleteUpload;
+#[cfg(feature = "services-obs")]
+pub use obs::ObsRoutingRule;
+#[cfg(feature = "services-obs")]
+pub use obs::ObsStaticWebsiteConfig;
+#[cfg(feature = "services-obs")]
+pub use obs::ObsUploadedPart;
 
 #[cfg(feature = "services-oss")]
 mod oss;
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -77,17 +78,18 @@ impl oio::Append for ObsAppender {
             }
         }
 
+        let size = bs.len();
         let mut req = self.core.obs_append_object_request(
             &self.path,
             self.position.expect("position is not set"),
-            bs.len(),
+            size,
             &self.op,
             AsyncBody::Bytes(bs),
         )?;
 
         self.core.sign(&mut req).await?;
 
-        let resp = self.core.send(req).await?;
+        let resp = self.core.send(Operation::Append, &self.path, req).await?;
 
         let status = resp.status();
 
@@ -105,6 +107,9 @@ impl oio::Append for ObsAppender {
                         )
                     })?;
                 self.position = Some(position);
+                self.core
+                    .bytes_written
+                    .fetch_add(size as u64, Ordering::Relaxed);
                 Ok(())
             }
 
@@ -15,33 +15,217 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
 use std::time::Duration;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
+use http::header::HeaderName;
+use http::header::AUTHORIZATION;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
+use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
+use http::header::DATE;
 use http::header::IF_MATCH;
+use http::header::IF_MODIFIED_SINCE;
 use http::header::IF_NONE_MATCH;
+use http::header::TRANSFER_ENCODING;
+use http::HeaderMap;
+use http::HeaderValue;
 use http::Request;
 use http::Response;
+use http::Uri;
+use md5::Digest;
+use md5::Md5;
 use reqsign::HuaweicloudObsCredential;
 use reqsign::HuaweicloudObsCredentialLoader;
 use reqsign::HuaweicloudObsSigner;
+use serde::Deserialize;
+use serde::Serialize;
+use sha1::Sha1;
 
+use super::backend::RoutingRule;
+use super::sts::StsLoader;
 use crate::raw::*;
 use crate::*;
 
+/// Max size of object that can be written by a single PUT, which is 5 GiB.
+pub const MAX_SINGLE_PUT_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+/// Max parts that a multipart upload can have.
+pub const MAX_MULTIPART_PARTS: u64 = 10000;
+/// Max size of a single object, which is about 48.8 TiB.
+pub const MAX_OBJECT_SIZE: u64 = MAX_MULTIPART_PARTS * MAX_SINGLE_PUT_SIZE;
+/// Min part size used by multipart upload.
+pub const MIN_PART_SIZE: u64 = 8 * 1024 * 1024;
+/// Max sources that can be concatenated into one object.
+pub const MAX_CONCAT_SOURCES: usize = 100;
+/// Min size of sources to concat except the last one, which is the min
+/// part size allowed by OBS.
+pub const MIN_CONCAT_SOURCE_SIZE: u64 = 100 * 1024;
+
+/// Backend-specific headers returned by stat which will be kept in custom metadata.
+pub const CUSTOM_METADATA_HEADERS: &[&str] = &[
+    "x-obs-storage-class",
+    "x-obs-storage-redundancy",
+    "x-obs-server-side-encryption",
+    "x-obs-object-type",
+    "x-obs-expiration",
+];
+
+mod constants {
+    pub const X_OBS_REQUEST_PAYER: &str = "x-obs-request-payer";
+    pub const X_OBS_SECURITY_TOKEN: &str = "x-obs-security-token";
+    pub const X_OBS_RESTORE: &str = "x-obs-restore";
+    pub const X_OBS_FETCH_SOURCE_URL: &str = "x-obs-fetch-source-url";
+    pub const X_OBS_OBJECT_LOCK_MODE: &str = "x-obs-object-lock-mode";
+    pub const X_OBS_OBJECT_LOCK_RETAIN_UNTIL_DATE: &str = "x-obs-object-lock-retain-until-date";
+
+    pub const TRACEPARENT: &str = "traceparent";
+    pub const TRACESTATE: &str = "tracestate";
+
+    pub const X_OBS_METADATA_DIRECTIVE: &str = "x-obs-metadata-directive";
+    pub const X_OBS_META_PREFIX: &str = "x-obs-meta-";
+
+    pub const X_OBS_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
+        "x-obs-server-side-encryption-customer-algorithm";
+    pub const X_OBS_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY: &str =
+        "x-obs-server-side-encryption-customer-key";
+    pub const X_OBS_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5: &str =
+        "x-obs-server-side-encryption-customer-key-md5";
+
+    pub const X_OBS_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
+        "x-obs-copy-source-server-side-encryption-customer-algorithm";
+    pub const X_OBS_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY: &str =
+        "x-obs-copy-source-server-side-encryption-customer-key";
+    pub const X_OBS_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5: &str =
+        "x-obs-copy-source-server-side-encryption-customer-key-md5";
+}
+
+/// Object headers that will be cleared by the `REPLACE` metadata directive.
+const PRESERVED_HEADERS: &[&str] = &[
+    "content-type",
+    "content-encoding",
+    "content-disposition",
+    "content-language",
+    "cache-control",
+    "expires",
+    "x-obs-storage-class",
+    "x-obs-website-redirect-location",
+];
+
+/// Check user metadata can be sent as `x-obs-meta-*` headers.
+pub fn validate_user_metadata(metadata: &HashMap<String, String>) -> Result<()> {
+    for (key, value) in metadata {
+        let name = format!("{}{key}", constants::X_OBS_META_PREFIX);
+        if key.is_empty()
+            || HeaderName::try_from(name).is_err()
+            || HeaderValue::try_from(value).is_err()
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "user metadata is not a valid http header",
+            )
+            .with_context("service", Scheme::Obs)
+            .with_context("key", key));
+        }
+    }
+
+    Ok(())
+}
+
+/// Carry the preserved headers returned by HEAD into the copy request.
+fn insert_preserved_headers(
+    mut req: http::request::Builder,
+    headers: &HeaderMap,
+) -> http::request::Builder {
+    for key in PRESERVED_HEADERS {
+        if let Some(value) = headers.get(*key) {
+            req = req.header(*key, value);
+        }
+    }
+    req
+}
+
+/// SseCConfig is the config of server side encryption with customer-provided keys.
+///
+/// OBS encrypts the object with given AES-256 key and discards the key after
+/// that, so the same key must be provided while reading the object.
+#[derive(Clone)]
+pub struct SseCConfig {
+    key: [u8; 32],
+}
+
+impl Debug for SseCConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SseCConfig")
+            .field("key", &"<redacted>")
+            .finish()
+    }
+}
+
+impl SseCConfig {
+    /// Create a new SseCConfig with given AES-256 key.
+    pub fn new(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+
+    /// Build the `(algorithm, key, key_md5)` header values.
+    fn header_values(&self) -> (HeaderValue, HeaderValue, HeaderValue) {
+        let algorithm = HeaderValue::from_static("AES256");
+
+        let mut key = HeaderValue::from_str(&BASE64_STANDARD.encode(self.key))
+            .expect("base64 encoded key must be valid header value");
+        key.set_sensitive(true);
+
+        let mut key_md5 =
+            HeaderValue::from_str(&BASE64_STANDARD.encode(Md5::digest(self.key).as_slice()))
+                .expect("base64 encoded md5 must be valid header value");
+        key_md5.set_sensitive(true);
+
+        (algorithm, key, key_md5)
+    }
+}
+
+#[derive(Clone)]
 pub struct ObsCore {
     pub bucket: String,
     pub root: String,
     pub endpoint: String,
 
-    pub signer: HuaweicloudObsSigner,
-    pub loader: HuaweicloudObsCredentialLoader,
+    pub server_side_encryption_customer_key: Option<SseCConfig>,
+    pub requester_pays: bool,
+    /// List with the `marker` based API instead of list objects v2.
+    pub disable_list_objects_v2: bool,
+
+    /// Signer and loaders are shared by backends cloned via
+    /// `ObsBackend::clone_with_root`.
+    pub signer: Arc<HuaweicloudObsSigner>,
+    pub loader: Arc<HuaweicloudObsCredentialLoader>,
+    /// Loader of the assumed role's credentials if `iam_role` is set.
+    pub sts: Option<Arc<StsLoader>>,
     pub client: HttpClient,
+    /// Whether the http client is set via `ObsBuilder::http_client`.
+    pub custom_http_client: bool,
+    /// Last 4 characters of the access key id, used by `debug_info`.
+    pub access_key_id_suffix: Option<String>,
+    /// Middleware that processes get, put, head, delete and list requests
+    /// before signing.
+    pub request_middleware: Option<Arc<dyn RequestMiddleware>>,
+
+    /// Total bytes read from and written into this backend.
+    pub bytes_read: Arc<AtomicU64>,
+    pub bytes_written: Arc<AtomicU64>,
 }
 
 impl Debug for ObsCore {
@@ -62,24 +246,43 @@ impl ObsCore {
             .await
             .map_err(new_request_credential_error)?;
 
-        if let Some(cred) = cred {
-            Ok(Some(cred))
-        } else {
-            Ok(None)
+        match (cred, &self.sts) {
+            (Some(cred), Some(sts)) => Ok(Some(sts.load(&cred).await?)),
+            (Some(cred), None) => Ok(Some(cred)),
+            (None, Some(_)) => Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "iam_role requires access_key_id and secret_access_key to assume role",
+            )
+            .with_context("service", Scheme::Obs)),
+            (None, None) => Ok(None),
         }
     }
 
     pub async fn sign<T>(&self, req: &mut Request<T>) -> Result<()> {
+        self.sign_with(&self.signer, req).await
+    }
+
+    /// Sign request with given signer, which is used for requests sent to
+    /// other buckets.
+    async fn sign_with<T>(
+        &self,
+        signer: &HuaweicloudObsSigner,
+        req: &mut Request<T>,
+    ) -> Result<()> {
+        self.insert_request_payer_header(req);
+
         let cred = if let Some(cred) = self.load_credential().await? {
             cred
         } else {
             return Ok(());
         };
 
-        self.signer.sign(req, &cred).map_err(new_request_sign_error)
+        signer.sign(req, &cred).map_err(new_request_sign_error)
     }
 
     pub async fn sign_query<T>(&self, req: &mut Request<T>, duration: Duration) -> Result<()> {
+        self.insert_request_payer_header(req);
+
         let cred = if let Some(cred) = self.load_credential().await? {
             cred
         } else {
@@ -91,9 +294,118 @@ impl ObsCore {
             .map_err(new_request_sign_error)
     }
 
-    #[inline]
-    pub async fn send(&self, req: Request<AsyncBody>) -> Result<Response<IncomingAsyncBody>> {
-        self.client.send(req).await
+    /// Process request with the middleware if it has been configured.
+    ///
+    /// The middleware must be applied before signing so that headers
+    /// added by it will be included in the signature.
+    fn apply_request_middleware(&self, req: &mut Request<AsyncBody>) {
+        if let Some(middleware) = &self.request_middleware {
+            middleware.process(req);
+        }
+    }
+
+    /// Insert request payer header if requester pays has been enabled.
+    ///
+    /// The header must be inserted before signing so that it will be
+    /// included in the canonical headers.
+    fn insert_request_payer_header<T>(&self, req: &mut Request<T>) {
+        if self.requester_pays {
+            req.headers_mut().insert(
+                constants::X_OBS_REQUEST_PAYER,
+                HeaderValue::from_static("requester"),
+            );
+        }
+    }
+
+    /// Insert SSE-C headers into request if customer key has been configured.
+    ///
+    /// OBS requires these headers on write, read and stat of the object.
+    pub fn insert_sse_c_headers(&self, mut req: http::request::Builder) -> http::request::Builder {
+        if let Some(sse_c) = &self.server_side_encryption_customer_key {
+            let (algorithm, key, key_md5) = sse_c.header_values();
+
+            req = req
+                .header(
+                    HeaderName::from_static(
+                        constants::X_OBS_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+                    ),
+                    algorithm,
+                )
+                .header(
+                    HeaderName::from_static(constants::X_OBS_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY),
+                    key,
+                )
+                .header(
+                    HeaderName::from_static(
+                        constants::X_OBS_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
+                    ),
+                    key_md5,
+                );
+        }
+
+        req
+    }
+
+    /// Insert trace context headers into request if the caller provided one.
+    pub fn insert_request_context_headers(
+        &self,
+        mut req: http::request::Builder,
+        ctx: Option<&RequestContext>,
+    ) -> http::request::Builder {
+        if let Some(trace) = ctx.and_then(|v| v.trace_context()) {
+            req = req.header(constants::TRACEPARENT, trace.traceparent());
+            if let Some(tracestate) = trace.tracestate() {
+                req = req.header(constants::TRACESTATE, tracestate);
+            }
+        }
+
+        req
+    }
+
+    /// Insert SSE-C headers of the copy source into request if customer key
+    /// has been configured.
+    pub fn insert_copy_source_sse_c_headers(
+        &self,
+        mut req: http::request::Builder,
+    ) -> http::request::Builder {
+        if let Some(sse_c) = &self.server_side_encryption_customer_key {
+            let (algorithm, key, key_md5) = sse_c.header_values();
+
+            req = req
+                .header(
+                    HeaderName::from_static(
+                        constants::X_OBS_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+                    ),
+                    algorithm,
+                )
+                .header(
+                    HeaderName::from_static(
+                        constants::X_OBS_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY,
+                    ),
+                    key,
+                )
+                .header(
+                    HeaderName::from_static(
+                        constants::X_OBS_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
+                    ),
+                    key_md5,
+                );
+        }
+
+        req
+    }
+
+    pub async fn send(
+        &self,
+        op: Operation,
+        path: &str,
+        req: Request<AsyncBody>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        log_request_debug(op, path, &req);
+        let resp = self.client.send(req).await?;
+        log_response_debug(op, path, &resp);
+
+        Ok(resp)
     }
 }
 
@@ -101,55 +413,68 @@ impl ObsCore {
     pub async fn obs_get_object(
         &self,
         path: &str,
-        range: BytesRange,
-        if_match: Option<&str>,
-        if_none_match: Option<&str>,
+        args: &OpRead,
     ) -> Result<Response<IncomingAsyncBody>> {
-        let mut req = self.obs_get_object_request(path, range, if_match, if_none_match)?;
+        let mut req = self.obs_get_object_request(path, args)?;
 
         self.sign(&mut req).await?;
 
-        self.send(req).await
+        self.send(Operation::Read, path, req).await
     }
 
-    pub fn obs_get_object_request(
-        &self,
-        path: &str,
-        range: BytesRange,
-        if_match: Option<&str>,
-        if_none_match: Option<&str>,
-    ) -> Result<Request<AsyncBody>> {
+    pub fn obs_get_object_request(&self, path: &str, args: &OpRead) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
 
         let mut req = Request::get(&url);
 
-        if let Some(if_match) = if_match {
+        req = self.insert_sse_c_headers(req);
+        req = self.insert_request_context_headers(req, args.request_context());
+
+        if let Some(if_match) = args.if_match() {
             req = req.header(IF_MATCH, if_match);
         }
 
+        let range = args.range();
         if !range.is_full() {
             req = req.header(http::header::RANGE, range.to_header())
         }
 
-        if let Some(if_none_match) = if_none_match {
+        if let Some(if_none_match) = args.if_none_match() {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
 
-        let req = req
+        if let Some(if_modified_since) = args.if_modified_since() {
+            req = req.header(
+                IF_MODIFIED_SINCE,
+                format_datetime_into_http_date(if_modified_since),
+            );
+        }
+
+        let mut req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
+        self.apply_request_middleware(&mut req);
 
         Ok(req)
     }
 
+    /// Build the request of put object.
+    ///
+    /// If `size` is `None`, the length will be decided by `body`:
+    ///
+    /// - `Bytes` will be sent with the length of bytes.
+    /// - `Stream` will be sent with `Transfer-Encoding: chunked`.
+    /// - `Empty` will be sent without length, which is used by presign.
+    ///
+    /// OBS signature doesn't cover the payload, so chunked bodies can be
+    /// signed without knowing the payload hash in advance.
     pub fn obs_put_object_request(
         &self,
         path: &str,
         size: Option<u64>,
-        content_type: Option<&str>,
-        cache_control: Option<&str>,
+        args: &OpWrite,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
@@ -158,18 +483,45 @@ impl ObsCore {
 
         let mut req = Request::put(&url);
 
-        if let Some(size) = size {
-            req = req.header(CONTENT_LENGTH, size)
+        req = self.insert_sse_c_headers(req);
+        req = self.insert_request_context_headers(req, args.request_context());
+
+        match (size, &body) {
+            (Some(size), _) => req = req.header(CONTENT_LENGTH, size),
+            // Some endpoints reject requests without content length.
+            (None, AsyncBody::Bytes(bs)) => req = req.header(CONTENT_LENGTH, bs.len()),
+            (None, AsyncBody::Stream(_)) => req = req.header(TRANSFER_ENCODING, "chunked"),
+            (None, AsyncBody::Empty) => {}
         }
-        if let Some(cache_control) = cache_control {
+        if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
-        if let Some(mime) = content_type {
+        if let Some(mime) = args.content_type() {
             req = req.header(CONTENT_TYPE, mime)
         }
+        if let Some(encoding) = args.content_encoding() {
+            req = req.header(CONTENT_ENCODING, encoding)
+        }
 
-        let req = req.body(body).map_err(new_request_build_error)?;
+        match args.expected_checksum() {
+            Some(v @ Checksum::Md5(_)) => req = req.header("CONTENT-MD5", v.to_base64()),
+            Some(Checksum::Sha256(_)) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "obs only supports md5 as expected checksum",
+                )
+                .with_operation(Operation::Write));
+            }
+            None => {}
+        }
+
+        if let Some(if_none_match) = args.if_none_match() {
+            req = req.header(IF_NONE_MATCH, if_none_match);
+        }
+
+        let mut req = req.body(body).map_err(new_request_build_error)?;
+        self.apply_request_middleware(&mut req);
 
         Ok(req)
     }
@@ -184,7 +536,7 @@ impl ObsCore {
 
         self.sign(&mut req).await?;
 
-        self.send(req).await
+        self.send(Operation::Stat, path, req).await
     }
 
     pub fn obs_head_object_request(
@@ -202,6 +554,8 @@ impl ObsCore {
 
         let mut req = Request::head(&url);
 
+        req = self.insert_sse_c_headers(req);
+
         if let Some(if_match) = if_match {
             req = req.header(IF_MATCH, if_match);
         }
@@ -210,27 +564,36 @@ impl ObsCore {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
 
-        let req = req
+        let mut req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
+        self.apply_request_middleware(&mut req);
 
         Ok(req)
     }
 
-    pub async fn obs_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    pub async fn obs_delete_object(
+        &self,
+        path: &str,
+        version: Option<&str>,
+    ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        let mut url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        if let Some(version) = version {
+            url.push_str(&format!("?versionId={}", percent_encode_path(version)));
+        }
 
         let req = Request::delete(&url);
 
         let mut req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
+        self.apply_request_middleware(&mut req);
 
         self.sign(&mut req).await?;
 
-        self.send(req).await
+        self.send(Operation::Delete, path, req).await
     }
 
     pub fn obs_append_object_request(
@@ -251,6 +614,8 @@ impl ObsCore {
 
         let mut req = Request::post(&url);
 
+        req = self.insert_sse_c_headers(req);
+
         req = req.header(CONTENT_LENGTH, size);
 
         if let Some(mime) = args.content_type() {
@@ -274,20 +639,131 @@ impl ObsCore {
         from: &str,
         to: &str,
     ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self
+            .obs_copy_object_request(from, to)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Copy, to, req).await
+    }
+
+    /// Copy object to itself with metadata replaced to make OBS update
+    /// its last modified time.
+    ///
+    /// OBS will clear all metadata while replacing, so we need to carry
+    /// the headers returned by HEAD, including the user metadata.
+    pub async fn obs_touch_object(
+        &self,
+        path: &str,
+        headers: &HeaderMap,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let req = self
+            .obs_copy_object_request(path, path)
+            .header(constants::X_OBS_METADATA_DIRECTIVE, "REPLACE");
+
+        let mut req = insert_preserved_headers(req, headers);
+        for (key, value) in headers {
+            if key.as_str().starts_with(constants::X_OBS_META_PREFIX) {
+                req = req.header(key, value);
+            }
+        }
+
+        let mut req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Touch, path, req).await
+    }
+
+    /// Copy object to itself with user metadata replaced.
+    ///
+    /// Like `obs_touch_object`, the preserved headers returned by HEAD
+    /// need to be carried.
+    pub async fn obs_update_object_metadata(
+        &self,
+        path: &str,
+        metadata: &HashMap<String, String>,
+        headers: &HeaderMap,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let req = self
+            .obs_copy_object_request(path, path)
+            .header(constants::X_OBS_METADATA_DIRECTIVE, "REPLACE");
+
+        let mut req = insert_preserved_headers(req, headers);
+        for (key, value) in metadata {
+            req = req.header(format!("{}{key}", constants::X_OBS_META_PREFIX), value);
+        }
+
+        let mut req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::UpdateMetadata, path, req).await
+    }
+
+    fn obs_copy_object_request(&self, from: &str, to: &str) -> http::request::Builder {
         let source = build_abs_path(&self.root, from);
         let target = build_abs_path(&self.root, to);
 
         let source = format!("/{}/{}", self.bucket, percent_encode_path(&source));
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&target));
 
-        let mut req = Request::put(&url)
-            .header("x-obs-copy-source", percent_encode_path(&source))
+        let req = Request::put(&url).header("x-obs-copy-source", percent_encode_path(&source));
+
+        // Both source and target are encrypted with the same customer key.
+        let req = self.insert_copy_source_sse_c_headers(req);
+        self.insert_sse_c_headers(req)
+    }
+
+    /// Copy object into another bucket, `to` is relative to the same root.
+    pub async fn obs_copy_object_to_bucket(
+        &self,
+        from: &str,
+        bucket: &str,
+        to: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self
+            .obs_copy_object_to_bucket_request(from, bucket, to)?
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
-        self.sign(&mut req).await?;
+        self.sign_with(&HuaweicloudObsSigner::new(bucket), &mut req)
+            .await?;
+
+        self.send(Operation::Copy, to, req).await
+    }
+
+    pub fn obs_copy_object_to_bucket_request(
+        &self,
+        from: &str,
+        bucket: &str,
+        to: &str,
+    ) -> Result<http::request::Builder> {
+        let endpoint = self.bucket_endpoint(bucket).ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unsupported,
+                "copy across buckets requires the default obs endpoint",
+            )
+            .with_context("service", Scheme::Obs)
+            .with_context("endpoint", &self.endpoint)
+        })?;
+
+        let source = build_abs_path(&self.root, from);
+        let target = build_abs_path(&self.root, to);
+
+        let source = format!("/{}/{}", self.bucket, percent_encode_path(&source));
+        let url = format!("{}/{}", endpoint, percent_encode_path(&target));
+
+        let req = Request::put(&url).header("x-obs-copy-source", source);
 
-        self.send(req).await
+        let req = self.insert_copy_source_sse_c_headers(req);
+        Ok(self.insert_sse_c_headers(req))
     }
 
     pub async fn obs_list_objects(
@@ -322,9 +798,1928 @@ impl ObsCore {
         let mut req = Request::get(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
+        self.apply_request_middleware(&mut req);
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::List, path, req).await
+    }
+
+    /// List objects with `list-type=2` which paginates by
+    /// `continuation-token` instead of `marker`.
+    pub async fn obs_list_objects_v2(
+        &self,
+        path: &str,
+        continuation_token: &str,
+        delimiter: &str,
+        limit: Option<usize>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        // Owner is only returned with `fetch-owner` in v2.
+        let mut queries = vec!["list-type=2".to_string(), "fetch-owner=true".to_string()];
+        if !path.is_empty() {
+            queries.push(format!("prefix={}", percent_encode_path(&p)));
+        }
+        if !delimiter.is_empty() {
+            queries.push(format!("delimiter={delimiter}"));
+        }
+        if let Some(limit) = limit {
+            queries.push(format!("max-keys={limit}"));
+        }
+        if !continuation_token.is_empty() {
+            queries.push(format!(
+                "continuation-token={}",
+                percent_encode_path(continuation_token)
+            ));
+        }
+
+        let url = format!("{}?{}", self.endpoint, queries.join("&"));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+        self.apply_request_middleware(&mut req);
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::List, path, req).await
+    }
+
+    pub async fn obs_list_object_versions(
+        &self,
+        path: &str,
+        key_marker: &str,
+        version_id_marker: &str,
+        limit: Option<usize>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut queries = vec!["versions".to_string()];
+        if !p.is_empty() {
+            queries.push(format!("prefix={}", percent_encode_path(&p)));
+        }
+        if let Some(limit) = limit {
+            queries.push(format!("max-keys={limit}"));
+        }
+        if !key_marker.is_empty() {
+            queries.push(format!("key-marker={}", percent_encode_path(key_marker)));
+        }
+        if !version_id_marker.is_empty() {
+            queries.push(format!(
+                "version-id-marker={}",
+                percent_encode_path(version_id_marker)
+            ));
+        }
+
+        let url = format!("{}?{}", self.endpoint, queries.join("&"));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::ListVersions, path, req).await
+    }
+
+    pub async fn obs_create_bucket(&self, location: &str) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}/", self.endpoint);
+
+        let content = quick_xml::se::to_string(&CreateBucketConfiguration {
+            location: location.to_string(),
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::CreateDir, "/", req).await
+    }
+
+    pub async fn obs_get_service(&self) -> Result<Response<IncomingAsyncBody>> {
+        let cred = self.load_credential().await?;
+
+        send_get_service_request(&self.client, &self.service_endpoint(), cred.as_ref()).await
+    }
+
+    /// Get the endpoint without bucket, which is used by get service.
+    pub fn service_endpoint(&self) -> String {
+        match self.endpoint.split_once("://") {
+            Some((scheme, host)) => match host.strip_prefix(&format!("{}.", self.bucket)) {
+                Some(host) => format!("{scheme}://{host}"),
+                None => self.endpoint.clone(),
+            },
+            None => self.endpoint.clone(),
+        }
+    }
+
+    /// Get the endpoint of given bucket in the same region.
+    ///
+    /// Returns `None` if the endpoint is not in `<bucket>.<host>` style,
+    /// for example, a user domain bound to the bucket.
+    pub fn bucket_endpoint(&self, bucket: &str) -> Option<String> {
+        let service_endpoint = self.service_endpoint();
+        if service_endpoint == self.endpoint {
+            return None;
+        }
+
+        let (scheme, host) = service_endpoint.split_once("://")?;
+        Some(format!("{scheme}://{bucket}.{host}"))
+    }
+
+    pub async fn obs_delete_bucket(&self) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}/", self.endpoint);
+
+        let mut req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Delete, "/", req).await
+    }
+
+    pub async fn obs_get_bucket_lifecycle(&self) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}?lifecycle", self.endpoint);
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::GetLifecycleRules, "/", req).await
+    }
+
+    pub async fn obs_delete_bucket_lifecycle(&self) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}?lifecycle", self.endpoint);
+
+        let mut req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
 
         self.sign(&mut req).await?;
 
-        self.send(req).await
+        self.send(Operation::SetLifecycleRules, "/", req).await
+    }
+
+    /// Put the lifecycle configuration of bucket, prefixes of `rules` are
+    /// absolute in bucket.
+    pub async fn obs_put_bucket_lifecycle(
+        &self,
+        rules: Vec<LifecycleConfigurationRule>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}?lifecycle", self.endpoint);
+
+        let content = quick_xml::se::to_string(&LifecycleConfiguration { rule: rules })
+            .map_err(new_xml_deserialize_error)?;
+
+        // OBS requires Content-MD5 for putting lifecycle configuration.
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()))
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::SetLifecycleRules, "/", req).await
+    }
+
+    /// Presign a form upload via OBS POST object.
+    ///
+    /// Please refer to this doc for more details:
+    /// https://support.huaweicloud.com/intl/en-us/api-obs/obs_04_0012.html
+    pub async fn obs_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+    ) -> Result<PresignedPost> {
+        let url = self.endpoint.parse::<Uri>().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "endpoint is invalid").set_source(err)
+        })?;
+
+        let mut fields = args.fields().clone();
+        fields.insert("key".to_string(), build_abs_path(&self.root, path));
+
+        let cred = match self.load_credential().await? {
+            Some(cred) => cred,
+            // Anonymous users don't need to sign the policy.
+            None => return Ok(PresignedPost::new(url, fields)),
+        };
+        if let Some(token) = &cred.security_token {
+            fields.insert(constants::X_OBS_SECURITY_TOKEN.to_string(), token.clone());
+        }
+
+        let expire = chrono::Duration::from_std(args.expire()).map_err(|err| {
+            Error::new(ErrorKind::InvalidInput, "expire is too large").set_source(err)
+        })?;
+        let policy = build_post_policy(
+            &self.bucket,
+            &fields,
+            args.conditions(),
+            Utc::now() + expire,
+        )?;
+        let policy = BASE64_STANDARD.encode(policy);
+        let signature = base64_hmac_sha1(&cred.secret_access_key, &policy);
+
+        fields.insert("policy".to_string(), policy);
+        fields.insert("AccessKeyId".to_string(), cred.access_key_id);
+        fields.insert("signature".to_string(), signature);
+
+        Ok(PresignedPost::new(url, fields))
+    }
+
+    pub async fn obs_get_object_tagging(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::GetTags, path, req).await
+    }
+
+    /// Make OBS fetch the object from `source_url` and store it into path.
+    pub async fn obs_fetch_object(
+        &self,
+        path: &str,
+        source_url: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+
+        let req = Request::put(&url)
+            .header(constants::X_OBS_FETCH_SOURCE_URL, source_url)
+            .header(CONTENT_LENGTH, 0);
+        let req = self.insert_sse_c_headers(req);
+
+        let mut req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Write, path, req).await
+    }
+
+    pub async fn obs_get_object_acl(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?acl", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Stat, path, req).await
+    }
+
+    pub async fn obs_delete_object_tagging(
+        &self,
+        path: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::SetTags, path, req).await
+    }
+
+    pub async fn obs_put_object_tagging(
+        &self,
+        path: &str,
+        tags: &HashMap<String, String>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let content =
+            quick_xml::se::to_string(&Tagging::from(tags)).map_err(new_xml_deserialize_error)?;
+
+        // OBS requires Content-MD5 for putting tagging.
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()))
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::SetTags, path, req).await
+    }
+
+    pub async fn obs_put_object_retention(
+        &self,
+        path: &str,
+        config: &ObjectLockConfig,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?retention", self.endpoint, percent_encode_path(&p));
+
+        let content = quick_xml::se::to_string(&Retention::from(config))
+            .map_err(new_xml_deserialize_error)?;
+
+        // OBS requires Content-MD5 for putting retention.
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()))
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::LockObject, path, req).await
+    }
+
+    pub async fn obs_get_bucket_cors(&self) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}?cors", self.endpoint);
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::GetCorsConfig, "/", req).await
+    }
+
+    pub async fn obs_delete_bucket_cors(&self) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}?cors", self.endpoint);
+
+        let mut req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::SetCorsConfig, "/", req).await
+    }
+
+    pub async fn obs_put_bucket_cors(
+        &self,
+        rules: &[CorsRule],
+    ) -> Result<Response<IncomingAsyncBody>> {
+        validate_cors_rules(rules)?;
+
+        let url = format!("{}?cors", self.endpoint);
+
+        let content = quick_xml::se::to_string(&CorsConfiguration {
+            cors_rule: rules.iter().map(CorsConfigurationRule::from).collect(),
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        // OBS requires Content-MD5 for putting cors configuration.
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()))
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::SetCorsConfig, "/", req).await
+    }
+
+    pub async fn obs_get_bucket_acl(&self) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}?acl", self.endpoint);
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::GetBucketAcl, "/", req).await
+    }
+
+    pub async fn obs_put_bucket_acl(&self, acl: &BucketAcl) -> Result<Response<IncomingAsyncBody>> {
+        if acl.owner.is_empty() {
+            return Err(
+                Error::new(ErrorKind::InvalidInput, "bucket acl must have an owner")
+                    .with_operation(Operation::SetBucketAcl),
+            );
+        }
+
+        let url = format!("{}?acl", self.endpoint);
+
+        let content = quick_xml::se::to_string(&AccessControlPolicy::from(acl))
+            .map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::SetBucketAcl, "/", req).await
+    }
+
+    pub async fn obs_get_bucket_website(&self) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}?website", self.endpoint);
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Info, "/", req).await
+    }
+
+    pub async fn obs_delete_bucket_website(&self) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}?website", self.endpoint);
+
+        let mut req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Delete, "/", req).await
+    }
+
+    pub async fn obs_put_bucket_website(
+        &self,
+        config: &WebsiteConfiguration,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}?website", self.endpoint);
+
+        let content = quick_xml::se::to_string(config).map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Write, "/", req).await
+    }
+
+    pub async fn obs_initiate_multipart_upload(
+        &self,
+        path: &str,
+        content_type: Option<&str>,
+        content_encoding: Option<&str>,
+        cache_control: Option<&str>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?uploads", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::post(&url);
+
+        req = self.insert_sse_c_headers(req);
+
+        if let Some(mime) = content_type {
+            req = req.header(CONTENT_TYPE, mime)
+        }
+        if let Some(encoding) = content_encoding {
+            req = req.header(CONTENT_ENCODING, encoding)
+        }
+        if let Some(cache_control) = cache_control {
+            req = req.header(CACHE_CONTROL, cache_control)
+        }
+
+        let mut req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Write, path, req).await
+    }
+
+    pub fn obs_upload_part_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: Option<u64>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+
+        let mut req = Request::put(&url);
+
+        req = self.insert_sse_c_headers(req);
+
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    /// Upload a part by copying the whole object at `from`.
+    pub async fn obs_upload_part_copy(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        from: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let source = build_abs_path(&self.root, from);
+
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+        let source = format!("/{}/{}", self.bucket, percent_encode_path(&source));
+
+        let req = Request::put(&url).header("x-obs-copy-source", source);
+
+        let req = self.insert_copy_source_sse_c_headers(req);
+        let mut req = self
+            .insert_sse_c_headers(req)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::WriteMultipart, path, req).await
+    }
+
+    pub async fn obs_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[CompleteMultipartUploadRequestPart],
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}?uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            percent_encode_path(upload_id)
+        );
+
+        let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest {
+            part: parts.to_vec(),
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        // Set content-type to `application/xml` to avoid mixed with form post.
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Write, path, req).await
+    }
+
+    pub async fn obs_abort_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}?uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            percent_encode_path(upload_id)
+        );
+
+        let mut req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::Write, path, req).await
+    }
+
+    /// List multipart uploads that are initiated but not completed or
+    /// aborted yet under given prefix.
+    pub async fn obs_list_multipart_uploads(
+        &self,
+        prefix: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, prefix);
+
+        let mut url = format!("{}?uploads", self.endpoint);
+        if !p.is_empty() {
+            url.push_str(&format!("&prefix={}", percent_encode_path(&p)));
+        }
+        if !key_marker.is_empty() {
+            url.push_str(&format!("&key-marker={}", percent_encode_path(key_marker)));
+        }
+        if !upload_id_marker.is_empty() {
+            url.push_str(&format!(
+                "&upload-id-marker={}",
+                percent_encode_path(upload_id_marker)
+            ));
+        }
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::List, prefix, req).await
+    }
+
+    /// List parts that have been uploaded by given multipart upload.
+    pub async fn obs_list_parts(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number_marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/{}?uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            percent_encode_path(upload_id)
+        );
+        if !part_number_marker.is_empty() {
+            url.push_str(&format!("&part-number-marker={part_number_marker}"));
+        }
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+        self.apply_request_middleware(&mut req);
+
+        self.sign(&mut req).await?;
+
+        self.send(Operation::List, path, req).await
+    }
+}
+
+/// Validate cors rules against OBS's schema so that we can return
+/// meaningful errors before sending requests.
+///
+/// Please refer to this doc for more details:
+/// https://support.huaweicloud.com/intl/en-us/api-obs/obs_04_0021.html
+fn validate_cors_rules(rules: &[CorsRule]) -> Result<()> {
+    const MAX_RULES: usize = 100;
+    const ALLOWED_METHODS: [&str; 5] = ["GET", "PUT", "HEAD", "POST", "DELETE"];
+
+    let new_error = |msg: &str| {
+        Error::new(ErrorKind::InvalidInput, msg).with_operation(Operation::SetCorsConfig)
+    };
+
+    if rules.len() > MAX_RULES {
+        return Err(new_error("obs allows at most 100 cors rules")
+            .with_context("rules", rules.len().to_string()));
+    }
+
+    for rule in rules {
+        if rule.allowed_origins.is_empty() {
+            return Err(new_error("cors rule must have at least one allowed origin"));
+        }
+        if let Some(v) = rule
+            .allowed_origins
+            .iter()
+            .find(|v| v.matches('*').count() > 1)
+        {
+            return Err(new_error("allowed origin can contain at most one wildcard")
+                .with_context("allowed_origin", v));
+        }
+
+        if rule.allowed_methods.is_empty() {
+            return Err(new_error("cors rule must have at least one allowed method"));
+        }
+        if let Some(v) = rule
+            .allowed_methods
+            .iter()
+            .find(|v| !ALLOWED_METHODS.contains(&v.as_str()))
+        {
+            return Err(
+                new_error("allowed method must be one of GET, PUT, HEAD, POST and DELETE")
+                    .with_context("allowed_method", v),
+            );
+        }
+
+        if let Some(v) = rule
+            .allowed_headers
+            .iter()
+            .find(|v| v.matches('*').count() > 1)
+        {
+            return Err(new_error("allowed header can contain at most one wildcard")
+                .with_context("allowed_header", v));
+        }
+        if let Some(v) = rule.expose_headers.iter().find(|v| v.contains('*')) {
+            return Err(
+                new_error("expose header can't contain wildcard").with_context("expose_header", v)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Cors configuration of bucket, used by both get and put.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "CORSConfiguration")]
+pub struct CorsConfiguration {
+    #[serde(rename = "CORSRule")]
+    pub cors_rule: Vec<CorsConfigurationRule>,
+}
+
+/// The order of fields must be kept as OBS validates the xml strictly.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CorsConfigurationRule {
+    pub allowed_method: Vec<String>,
+    pub allowed_origin: Vec<String>,
+    pub allowed_header: Vec<String>,
+    pub max_age_seconds: u32,
+    pub expose_header: Vec<String>,
+}
+
+impl From<&CorsRule> for CorsConfigurationRule {
+    fn from(rule: &CorsRule) -> Self {
+        Self {
+            allowed_method: rule.allowed_methods.clone(),
+            allowed_origin: rule.allowed_origins.clone(),
+            allowed_header: rule.allowed_headers.clone(),
+            max_age_seconds: rule.max_age_seconds,
+            expose_header: rule.expose_headers.clone(),
+        }
+    }
+}
+
+impl From<CorsConfigurationRule> for CorsRule {
+    fn from(rule: CorsConfigurationRule) -> Self {
+        Self {
+            allowed_origins: rule.allowed_origin,
+            allowed_methods: rule.allowed_method,
+            allowed_headers: rule.allowed_header,
+            expose_headers: rule.expose_header,
+            max_age_seconds: rule.max_age_seconds,
+        }
+    }
+}
+
+/// Static website configuration of bucket, used by both get and put.
+///
+/// The order of fields must be kept as OBS validates the xml strictly.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "WebsiteConfiguration", rename_all = "PascalCase")]
+pub struct WebsiteConfiguration {
+    /// Only returned by get, the bucket redirects all requests to another
+    /// host instead of hosting the website itself.
+    #[serde(skip_serializing)]
+    pub redirect_all_requests_to: Option<WebsiteRedirectAllRequestsTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_document: Option<WebsiteIndexDocument>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_document: Option<WebsiteErrorDocument>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_rules: Option<WebsiteRoutingRules>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct WebsiteRedirectAllRequestsTo {
+    pub host_name: String,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct WebsiteIndexDocument {
+    pub suffix: String,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct WebsiteErrorDocument {
+    pub key: String,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebsiteRoutingRules {
+    #[serde(rename = "RoutingRule")]
+    pub routing_rule: Vec<WebsiteRoutingRule>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct WebsiteRoutingRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<WebsiteRoutingRuleCondition>,
+    pub redirect: WebsiteRoutingRuleRedirect,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct WebsiteRoutingRuleCondition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_prefix_equals: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_error_code_returned_equals: Option<u16>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct WebsiteRoutingRuleRedirect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace_key_prefix_with: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace_key_with: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_redirect_code: Option<u16>,
+}
+
+impl From<&RoutingRule> for WebsiteRoutingRule {
+    fn from(rule: &RoutingRule) -> Self {
+        let condition =
+            if rule.key_prefix_equals.is_none() && rule.http_error_code_returned_equals.is_none() {
+                None
+            } else {
+                Some(WebsiteRoutingRuleCondition {
+                    key_prefix_equals: rule.key_prefix_equals.clone(),
+                    http_error_code_returned_equals: rule.http_error_code_returned_equals,
+                })
+            };
+
+        Self {
+            condition,
+            redirect: WebsiteRoutingRuleRedirect {
+                protocol: rule.protocol.clone(),
+                host_name: rule.host_name.clone(),
+                replace_key_prefix_with: rule.replace_key_prefix_with.clone(),
+                replace_key_with: rule.replace_key_with.clone(),
+                http_redirect_code: rule.http_redirect_code,
+            },
+        }
+    }
+}
+
+impl From<WebsiteRoutingRule> for RoutingRule {
+    fn from(rule: WebsiteRoutingRule) -> Self {
+        let condition = rule.condition.unwrap_or_default();
+
+        Self {
+            key_prefix_equals: condition.key_prefix_equals,
+            http_error_code_returned_equals: condition.http_error_code_returned_equals,
+            protocol: rule.redirect.protocol,
+            host_name: rule.redirect.host_name,
+            replace_key_prefix_with: rule.redirect.replace_key_prefix_with,
+            replace_key_with: rule.redirect.replace_key_with,
+            http_redirect_code: rule.redirect.http_redirect_code,
+        }
+    }
+}
+
+/// Request of creating bucket.
+#[derive(Default, Debug, Serialize)]
+#[serde(
+    default,
+    rename = "CreateBucketConfiguration",
+    rename_all = "PascalCase"
+)]
+pub struct CreateBucketConfiguration {
+    pub location: String,
+}
+
+/// Build the policy document of POST object.
+///
+/// All fields will be added as exact match conditions.
+fn build_post_policy(
+    bucket: &str,
+    fields: &HashMap<String, String>,
+    conditions: &[PolicyCondition],
+    expiration: DateTime<Utc>,
+) -> Result<String> {
+    let mut conds = vec![serde_json::json!({ "bucket": bucket })];
+
+    let mut fields: Vec<_> = fields.iter().collect();
+    // Keep the output stable for the same fields.
+    fields.sort();
+    conds.extend(fields.into_iter().map(|(k, v)| serde_json::json!({ k: v })));
+
+    conds.extend(conditions.iter().map(|cond| match cond {
+        PolicyCondition::Eq(k, v) => serde_json::json!(["eq", format!("${k}"), v]),
+        PolicyCondition::StartsWith(k, v) => serde_json::json!(["starts-with", format!("${k}"), v]),
+        PolicyCondition::ContentLengthRange(min, max) => {
+            serde_json::json!(["content-length-range", min, max])
+        }
+    }));
+
+    let policy = serde_json::json!({
+        "expiration": expiration.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+        "conditions": conds,
+    });
+    serde_json::to_string(&policy).map_err(new_json_serialize_error)
+}
+
+/// Sign the content with HMAC-SHA1 and encode it in base64.
+///
+/// Used to sign post policies and requests not bound to any bucket.
+fn base64_hmac_sha1(secret_access_key: &str, content: &str) -> String {
+    let mut h = Hmac::<Sha1>::new_from_slice(secret_access_key.as_bytes())
+        .expect("hmac can take key of any size");
+    h.update(content.as_bytes());
+    BASE64_STANDARD.encode(h.finalize().into_bytes())
+}
+
+/// Send the get service request to list all buckets owned by the
+/// credential.
+///
+/// Get service is not bound to any bucket, but the signer of reqsign always
+/// puts the bucket into the canonicalized resource, so we sign it here.
+///
+/// Please refer to this doc for more details:
+/// https://support.huaweicloud.com/intl/en-us/api-obs/obs_04_0029.html
+pub async fn send_get_service_request(
+    client: &HttpClient,
+    endpoint: &str,
+    cred: Option<&HuaweicloudObsCredential>,
+) -> Result<Response<IncomingAsyncBody>> {
+    let url = format!("{}/", endpoint.trim_end_matches('/'));
+
+    let mut req = Request::get(&url)
+        .body(AsyncBody::Empty)
+        .map_err(new_request_build_error)?;
+
+    if let Some(cred) = cred {
+        sign_service_request(&mut req, cred)?;
+    }
+
+    log_request_debug(Operation::List, "/", &req);
+    let resp = client.send(req).await?;
+    log_response_debug(Operation::List, "/", &resp);
+
+    Ok(resp)
+}
+
+/// Sign request with the canonicalized resource `/`.
+fn sign_service_request<T>(req: &mut Request<T>, cred: &HuaweicloudObsCredential) -> Result<()> {
+    let to_header_value = |v: &str| {
+        HeaderValue::from_str(v).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "header value is invalid")
+                .with_operation("sign_service_request")
+                .set_source(err)
+        })
+    };
+
+    let date = format_datetime_into_http_date(Utc::now());
+    let mut string_to_sign = format!("{}\n\n\n{date}\n", req.method());
+    if let Some(token) = &cred.security_token {
+        string_to_sign.push_str(&format!("{}:{token}\n", constants::X_OBS_SECURITY_TOKEN));
+        req.headers_mut()
+            .insert(constants::X_OBS_SECURITY_TOKEN, to_header_value(token)?);
+    }
+    string_to_sign.push('/');
+
+    let signature = base64_hmac_sha1(&cred.secret_access_key, &string_to_sign);
+    req.headers_mut().insert(DATE, to_header_value(&date)?);
+    req.headers_mut().insert(
+        AUTHORIZATION,
+        to_header_value(&format!("OBS {}:{signature}", cred.access_key_id))?,
+    );
+    Ok(())
+}
+
+/// Parse restore status from `x-obs-restore` header, which looks like:
+///
+/// - `ongoing-request="true"`
+/// - `ongoing-request="false", expiry-date="Wed, 7 Nov 2012 00:00:00 GMT"`
+///
+/// Files without this header are neither being restored nor restored.
+pub fn parse_restore_status(headers: &HeaderMap) -> Result<RestoreStatus> {
+    let v = match headers.get(constants::X_OBS_RESTORE) {
+        Some(v) => v.to_str().map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "header value is not valid utf-8 string",
+            )
+            .with_operation("parse_restore_status")
+            .set_source(e)
+        })?,
+        None => return Ok(RestoreStatus::default()),
+    };
+
+    // Values are quoted and expiry date contains `,`, so we can't split by `,`.
+    let quoted = |key: &str| {
+        let start = v.find(&format!("{key}=\""))? + key.len() + 2;
+        let end = v[start..].find('"')? + start;
+        Some(&v[start..end])
+    };
+
+    Ok(RestoreStatus {
+        in_progress: quoted("ongoing-request") == Some("true"),
+        expiry: quoted("expiry-date")
+            .map(parse_datetime_from_rfc2822)
+            .transpose()?,
+    })
+}
+
+/// Parse object lock from headers of head object.
+///
+/// The retain until date could be either a timestamp in milliseconds or
+/// a RFC 3339 datetime. Files without lock headers are not locked.
+pub fn parse_object_lock(headers: &HeaderMap) -> Result<Option<ObjectLockConfig>> {
+    let header = |key: &str| {
+        headers
+            .get(key)
+            .map(|v| {
+                v.to_str().map_err(|e| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "header value is not valid utf-8 string",
+                    )
+                    .with_operation("parse_object_lock")
+                    .set_source(e)
+                })
+            })
+            .transpose()
+    };
+
+    let (mode, retain_until) = match (
+        header(constants::X_OBS_OBJECT_LOCK_MODE)?,
+        header(constants::X_OBS_OBJECT_LOCK_RETAIN_UNTIL_DATE)?,
+    ) {
+        (Some(mode), Some(retain_until)) => (mode, retain_until),
+        _ => return Ok(None),
+    };
+
+    let mode = match mode.to_ascii_uppercase().as_str() {
+        "COMPLIANCE" => LockMode::Compliance,
+        "GOVERNANCE" => LockMode::Governance,
+        _ => {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "object lock mode is unknown")
+                    .with_operation("parse_object_lock")
+                    .with_context("mode", mode),
+            )
+        }
+    };
+    let retain_until = match retain_until.parse::<i64>() {
+        Ok(ms) => parse_datetime_from_from_timestamp_millis(ms)?,
+        Err(_) => parse_datetime_from_rfc3339(retain_until)?,
+    };
+
+    Ok(Some(ObjectLockConfig::new(mode, retain_until)))
+}
+
+/// Retention of object, used by put object retention.
+#[derive(Default, Debug, Serialize)]
+#[serde(rename = "Retention", rename_all = "PascalCase")]
+pub struct Retention {
+    pub mode: String,
+    /// Timestamp in milliseconds.
+    pub retain_until_date: i64,
+}
+
+impl From<&ObjectLockConfig> for Retention {
+    fn from(config: &ObjectLockConfig) -> Self {
+        let mode = match config.mode {
+            LockMode::Compliance => "COMPLIANCE",
+            LockMode::Governance => "GOVERNANCE",
+        };
+
+        Self {
+            mode: mode.to_string(),
+            retain_until_date: config.retain_until.timestamp_millis(),
+        }
+    }
+}
+
+/// Result of InitiateMultipartUpload
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct InitiateMultipartUploadResult {
+    pub upload_id: String,
+}
+
+/// Request of CompleteMultipartUpload
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
+pub struct CompleteMultipartUploadRequest {
+    pub part: Vec<CompleteMultipartUploadRequestPart>,
+}
+
+#[derive(Clone, Default, Debug, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CompleteMultipartUploadRequestPart {
+    pub part_number: usize,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+/// Result of UploadPartCopy
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+/// Result of CompleteMultipartUpload
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CompleteMultipartUploadResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+/// Result of ListMultipartUploads
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsResult {
+    pub is_truncated: bool,
+    pub next_key_marker: String,
+    pub next_upload_id_marker: String,
+    pub upload: Vec<ListMultipartUploadsResultUpload>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsResultUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: String,
+}
+
+/// Result of ListParts
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListPartsResult {
+    pub is_truncated: bool,
+    pub next_part_number_marker: String,
+    pub part: Vec<ListPartsResultPart>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListPartsResultPart {
+    pub part_number: usize,
+    pub last_modified: String,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    pub size: u64,
+}
+
+/// Tagging of object, used by both get and put.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tagging", rename_all = "PascalCase")]
+pub struct Tagging {
+    pub tag_set: TagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct TagSet {
+    pub tag: Vec<Tag>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Tag {
+    pub key: String,
+    pub value: String,
+}
+
+impl From<&HashMap<String, String>> for Tagging {
+    fn from(tags: &HashMap<String, String>) -> Self {
+        let mut tag: Vec<Tag> = tags
+            .iter()
+            .map(|(k, v)| Tag {
+                key: k.clone(),
+                value: v.clone(),
+            })
+            .collect();
+        // Keep the output stable for the same tags.
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Self {
+            tag_set: TagSet { tag },
+        }
+    }
+}
+
+impl From<Tagging> for HashMap<String, String> {
+    fn from(tagging: Tagging) -> Self {
+        tagging
+            .tag_set
+            .tag
+            .into_iter()
+            .map(|v| (v.key, v.value))
+            .collect()
+    }
+}
+
+/// Result of get service.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListAllMyBucketsResult {
+    pub buckets: ListAllMyBucketsResultBuckets,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListAllMyBucketsResultBuckets {
+    pub bucket: Vec<ListAllMyBucketsResultBucket>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListAllMyBucketsResultBucket {
+    pub name: String,
+    pub creation_date: String,
+    pub location: String,
+}
+
+/// ACL of object or bucket, used by get object acl and get/put bucket acl.
+///
+/// Field names are kept the same as OBS so that the JSON form follows
+/// the schema documented by OBS.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "AccessControlPolicy", rename_all = "PascalCase")]
+pub struct AccessControlPolicy {
+    pub owner: AccessControlPolicyOwner,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivered: Option<bool>,
+    pub access_control_list: AccessControlList,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct AccessControlPolicyOwner {
+    #[serde(rename = "ID")]
+    pub id: String,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct AccessControlList {
+    pub grant: Vec<AccessControlGrant>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct AccessControlGrant {
+    pub grantee: AccessControlGrantee,
+    /// `READ`, `READ_ACP`, `WRITE_ACP` or `FULL_CONTROL`
+    pub permission: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivered: Option<bool>,
+}
+
+/// Grantee is either a user with `ID` or a group with `Canned`.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct AccessControlGrantee {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// `Everyone` is the only canned group supported by OBS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canned: Option<String>,
+}
+
+impl From<&BucketAcl> for AccessControlPolicy {
+    fn from(acl: &BucketAcl) -> Self {
+        let grant = acl
+            .grants
+            .iter()
+            .map(|v| AccessControlGrant {
+                grantee: match &v.grantee {
+                    AclGrantee::User(id) => AccessControlGrantee {
+                        id: Some(id.clone()),
+                        canned: None,
+                    },
+                    AclGrantee::Everyone => AccessControlGrantee {
+                        id: None,
+                        canned: Some("Everyone".to_string()),
+                    },
+                },
+                permission: v.permission.clone(),
+                delivered: None,
+            })
+            .collect();
+
+        Self {
+            owner: AccessControlPolicyOwner {
+                id: acl.owner.clone(),
+            },
+            delivered: None,
+            access_control_list: AccessControlList { grant },
+        }
+    }
+}
+
+impl From<AccessControlPolicy> for BucketAcl {
+    fn from(policy: AccessControlPolicy) -> Self {
+        let grants = policy
+            .access_control_list
+            .grant
+            .into_iter()
+            .map(|v| AclGrant {
+                grantee: match v.grantee.canned {
+                    Some(_) => AclGrantee::Everyone,
+                    None => AclGrantee::User(v.grantee.id.unwrap_or_default()),
+                },
+                permission: v.permission,
+            })
+            .collect();
+
+        Self {
+            owner: policy.owner.id,
+            grants,
+        }
+    }
+}
+
+/// Lifecycle configuration of bucket, used by both get and put.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "LifecycleConfiguration", rename_all = "PascalCase")]
+pub struct LifecycleConfiguration {
+    pub rule: Vec<LifecycleConfigurationRule>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct LifecycleConfigurationRule {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub prefix: String,
+    /// `Enabled` or `Disabled`
+    pub status: String,
+    pub transition: Vec<LifecycleConfigurationTransition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<LifecycleConfigurationExpiration>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct LifecycleConfigurationTransition {
+    pub days: u32,
+    pub storage_class: String,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct LifecycleConfigurationExpiration {
+    pub days: u32,
+}
+
+impl From<&LifecycleRule> for LifecycleConfigurationRule {
+    fn from(rule: &LifecycleRule) -> Self {
+        Self {
+            id: rule.id.clone(),
+            prefix: rule.prefix.clone(),
+            status: if rule.enabled { "Enabled" } else { "Disabled" }.to_string(),
+            transition: rule
+                .transitions
+                .iter()
+                .map(|v| LifecycleConfigurationTransition {
+                    days: v.days,
+                    storage_class: v.storage_class.clone(),
+                })
+                .collect(),
+            expiration: rule
+                .expiration
+                .as_ref()
+                .map(|v| LifecycleConfigurationExpiration { days: v.days }),
+        }
+    }
+}
+
+impl From<LifecycleConfigurationRule> for LifecycleRule {
+    fn from(rule: LifecycleConfigurationRule) -> Self {
+        Self {
+            id: rule.id,
+            prefix: rule.prefix,
+            enabled: rule.status == "Enabled",
+            transitions: rule
+                .transition
+                .into_iter()
+                .map(|v| Transition {
+                    days: v.days,
+                    storage_class: v.storage_class,
+                })
+                .collect(),
+            expiration: rule.expiration.map(|v| Expiration { days: v.days }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_restore_status() -> Result<()> {
+        let cases = vec![
+            (None, RestoreStatus::default()),
+            (
+                Some(r#"ongoing-request="true""#),
+                RestoreStatus {
+                    in_progress: true,
+                    expiry: None,
+                },
+            ),
+            (
+                Some(r#"ongoing-request="false", expiry-date="Wed, 7 Nov 2012 00:00:00 GMT""#),
+                RestoreStatus {
+                    in_progress: false,
+                    expiry: Some(parse_datetime_from_rfc2822("Wed, 7 Nov 2012 00:00:00 GMT")?),
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let mut headers = HeaderMap::new();
+            if let Some(v) = input {
+                headers.insert(constants::X_OBS_RESTORE, HeaderValue::from_static(v));
+            }
+            assert_eq!(parse_restore_status(&headers)?, expected, "{input:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sse_c_header_values() {
+        let sse_c = SseCConfig::new([0x61; 32]);
+        let (algorithm, key, key_md5) = sse_c.header_values();
+
+        assert_eq!(algorithm, "AES256");
+        assert_eq!(key, "YWFhYWFhYWFhYWFhYWFhYWFhYWFhYWFhYWFhYWFhYWE=");
+        assert_eq!(
+            key_md5,
+            BASE64_STANDARD.encode(Md5::digest([0x61; 32]).as_slice())
+        );
+        assert!(key.is_sensitive());
+        assert!(key_md5.is_sensitive());
+        assert!(!format!("{sse_c:?}").contains("YWFh"));
+    }
+
+    #[test]
+    fn test_build_post_policy() {
+        let fields = HashMap::from([
+            ("key".to_string(), "path/to/file".to_string()),
+            ("content-type".to_string(), "text/plain".to_string()),
+        ]);
+        let expiration = DateTime::parse_from_rfc3339("2023-07-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let policy = build_post_policy(
+            "test",
+            &fields,
+            &[
+                PolicyCondition::StartsWith("x-obs-meta-tag".to_string(), "opendal".to_string()),
+                PolicyCondition::ContentLengthRange(1, 1024),
+            ],
+            expiration,
+        )
+        .expect("must success");
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&policy).unwrap(),
+            serde_json::json!({
+                "expiration": "2023-07-01T12:00:00.000Z",
+                "conditions": [
+                    {"bucket": "test"},
+                    {"content-type": "text/plain"},
+                    {"key": "path/to/file"},
+                    ["starts-with", "$x-obs-meta-tag", "opendal"],
+                    ["content-length-range", 1, 1024],
+                ],
+            })
+        );
+    }
+
+    /// Test vector from https://en.wikipedia.org/wiki/HMAC#Examples
+    #[test]
+    fn test_base64_hmac_sha1() {
+        assert_eq!(
+            base64_hmac_sha1("key", "The quick brown fox jumps over the lazy dog"),
+            BASE64_STANDARD.encode([
+                0xde, 0x7c, 0x9b, 0x85, 0xb8, 0xb7, 0x8a, 0xa6, 0xbc, 0x8a, 0x7a, 0x36, 0xf7, 0x0a,
+                0x90, 0x70, 0x1c, 0x9d, 0xb4, 0xd9
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sign_service_request() {
+        let cred = HuaweicloudObsCredential {
+            access_key_id: "ak".to_string(),
+            secret_access_key: "sk".to_string(),
+            security_token: Some("token".to_string()),
+        };
+        let mut req = Request::get("https://obs.cn-north-4.myhuaweicloud.com/")
+            .body(())
+            .unwrap();
+        sign_service_request(&mut req, &cred).unwrap();
+
+        let date = req.headers()[DATE].to_str().unwrap();
+        let signature = base64_hmac_sha1(
+            "sk",
+            &format!("GET\n\n\n{date}\nx-obs-security-token:token\n/"),
+        );
+        assert_eq!(req.headers()["x-obs-security-token"], "token");
+        assert_eq!(
+            req.headers()[AUTHORIZATION].to_str().unwrap(),
+            format!("OBS ak:{signature}")
+        );
+    }
+
+    #[test]
+    fn test_tagging() {
+        let tags = HashMap::from([
+            ("project".to_string(), "opendal".to_string()),
+            ("env".to_string(), "dev".to_string()),
+        ]);
+
+        let content = quick_xml::se::to_string(&Tagging::from(&tags)).expect("must success");
+        assert_eq!(
+            content,
+            "<Tagging><TagSet><Tag><Key>env</Key><Value>dev</Value></Tag><Tag><Key>project</Key><Value>opendal</Value></Tag></TagSet></Tagging>"
+        );
+
+        let out: Tagging = quick_xml::de::from_reader(bytes::Buf::reader(Bytes::from(content)))
+            .expect("must success");
+        assert_eq!(HashMap::from(out), tags);
+
+        let out: Tagging = quick_xml::de::from_reader(bytes::Buf::reader(Bytes::from(
+            "<Tagging><TagSet></TagSet></Tagging>",
+        )))
+        .expect("must success");
+        assert!(HashMap::from(out).is_empty());
+    }
+
+    #[test]
+    fn test_object_lock() {
+        let retain_until = parse_datetime_from_rfc3339("2030-01-01T00:00:00Z").unwrap();
+        let config = ObjectLockConfig::new(LockMode::Compliance, retain_until);
+
+        let content = quick_xml::se::to_string(&Retention::from(&config)).expect("must success");
+        assert_eq!(
+            content,
+            "<Retention><Mode>COMPLIANCE</Mode><RetainUntilDate>1893456000000</RetainUntilDate></Retention>"
+        );
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_object_lock(&headers).unwrap(), None);
+
+        headers.insert(
+            constants::X_OBS_OBJECT_LOCK_MODE,
+            HeaderValue::from_static("COMPLIANCE"),
+        );
+        headers.insert(
+            constants::X_OBS_OBJECT_LOCK_RETAIN_UNTIL_DATE,
+            HeaderValue::from_static("1893456000000"),
+        );
+        assert_eq!(parse_object_lock(&headers).unwrap(), Some(config.clone()));
+
+        headers.insert(
+            constants::X_OBS_OBJECT_LOCK_RETAIN_UNTIL_DATE,
+            HeaderValue::from_static("2030-01-01T00:00:00.000Z"),
+        );
+        assert_eq!(parse_object_lock(&headers).unwrap(), Some(config));
+    }
+
+    /// This example is from https://support.huaweicloud.com/intl/en-us/api-obs/obs_04_0046.html
+    #[test]
+    fn test_deserialize_lifecycle_configuration() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<LifecycleConfiguration xmlns="http://obs.cn-north-4.myhuaweicloud.com/doc/2015-06-30/">
+    <Rule>
+        <ID>delete-2-days</ID>
+        <Prefix>test/</Prefix>
+        <Status>Enabled</Status>
+        <Transition>
+            <Days>30</Days>
+            <StorageClass>WARM</StorageClass>
+        </Transition>
+        <Transition>
+            <Days>60</Days>
+            <StorageClass>COLD</StorageClass>
+        </Transition>
+        <Expiration>
+            <Days>70</Days>
+        </Expiration>
+    </Rule>
+    <Rule>
+        <ID>disabled</ID>
+        <Prefix>tmp/</Prefix>
+        <Status>Disabled</Status>
+    </Rule>
+</LifecycleConfiguration>"#,
+        );
+
+        let out: LifecycleConfiguration =
+            quick_xml::de::from_reader(bytes::Buf::reader(bs)).expect("must success");
+        let rules: Vec<LifecycleRule> = out.rule.into_iter().map(LifecycleRule::from).collect();
+
+        assert_eq!(
+            rules,
+            vec![
+                LifecycleRule {
+                    id: "delete-2-days".to_string(),
+                    prefix: "test/".to_string(),
+                    enabled: true,
+                    transitions: vec![
+                        Transition {
+                            days: 30,
+                            storage_class: "WARM".to_string(),
+                        },
+                        Transition {
+                            days: 60,
+                            storage_class: "COLD".to_string(),
+                        },
+                    ],
+                    expiration: Some(Expiration { days: 70 }),
+                },
+                LifecycleRule {
+                    id: "disabled".to_string(),
+                    prefix: "tmp/".to_string(),
+                    enabled: false,
+                    transitions: vec![],
+                    expiration: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_serialize_lifecycle_configuration() {
+        let rule = LifecycleRule {
+            id: "rule".to_string(),
+            prefix: "logs/".to_string(),
+            enabled: true,
+            transitions: vec![Transition {
+                days: 30,
+                storage_class: "WARM".to_string(),
+            }],
+            expiration: Some(Expiration { days: 60 }),
+        };
+        let req = LifecycleConfiguration {
+            rule: vec![LifecycleConfigurationRule::from(&rule)],
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            "<LifecycleConfiguration><Rule><ID>rule</ID><Prefix>logs/</Prefix><Status>Enabled</Status>\
+<Transition><Days>30</Days><StorageClass>WARM</StorageClass></Transition>\
+<Expiration><Days>60</Days></Expiration></Rule></LifecycleConfiguration>"
+        )
+    }
+
+    /// This example is from https://support.huaweicloud.com/intl/en-us/api-obs/obs_04_0021.html
+    #[test]
+    fn test_deserialize_cors_configuration() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<CORSConfiguration xmlns="http://obs.cn-north-4.myhuaweicloud.com/doc/2015-06-30/">
+    <CORSRule>
+        <AllowedMethod>POST</AllowedMethod>
+        <AllowedMethod>GET</AllowedMethod>
+        <AllowedOrigin>www.example.com</AllowedOrigin>
+        <AllowedHeader>AllowedHeader_1</AllowedHeader>
+        <AllowedHeader>AllowedHeader_2</AllowedHeader>
+        <MaxAgeSeconds>100</MaxAgeSeconds>
+        <ExposeHeader>ExposeHeader_1</ExposeHeader>
+    </CORSRule>
+</CORSConfiguration>"#,
+        );
+
+        let out: CorsConfiguration =
+            quick_xml::de::from_reader(bytes::Buf::reader(bs)).expect("must success");
+        let rules: Vec<CorsRule> = out.cors_rule.into_iter().map(CorsRule::from).collect();
+
+        assert_eq!(
+            rules,
+            vec![CorsRule {
+                allowed_origins: vec!["www.example.com".to_string()],
+                allowed_methods: vec!["POST".to_string(), "GET".to_string()],
+                allowed_headers: vec!["AllowedHeader_1".to_string(), "AllowedHeader_2".to_string()],
+                expose_headers: vec!["ExposeHeader_1".to_string()],
+                max_age_seconds: 100,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_serialize_cors_configuration() {
+        let rule = CorsRule {
+            allowed_origins: vec!["*".to_string()],
+            allowed_methods: vec!["GET".to_string(), "HEAD".to_string()],
+            allowed_headers: vec![],
+            expose_headers: vec!["ETag".to_string()],
+            max_age_seconds: 3600,
+        };
+        let req = CorsConfiguration {
+            cors_rule: vec![CorsConfigurationRule::from(&rule)],
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            "<CORSConfiguration><CORSRule><AllowedMethod>GET</AllowedMethod><AllowedMethod>HEAD</AllowedMethod>\
+<AllowedOrigin>*</AllowedOrigin><MaxAgeSeconds>3600</MaxAgeSeconds>\
+<ExposeHeader>ETag</ExposeHeader></CORSRule></CORSConfiguration>"
+        )
+    }
+
+    #[test]
+    fn test_deserialize_website_configuration() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<WebsiteConfiguration xmlns="http://obs.cn-north-4.myhuaweicloud.com/doc/2015-06-30/">
+    <IndexDocument>
+        <Suffix>index.html</Suffix>
+    </IndexDocument>
+    <ErrorDocument>
+        <Key>error.html</Key>
+    </ErrorDocument>
+    <RoutingRules>
+        <RoutingRule>
+            <Condition>
+                <KeyPrefixEquals>docs/</KeyPrefixEquals>
+            </Condition>
+            <Redirect>
+                <ReplaceKeyPrefixWith>documents/</ReplaceKeyPrefixWith>
+            </Redirect>
+        </RoutingRule>
+        <RoutingRule>
+            <Condition>
+                <HttpErrorCodeReturnedEquals>404</HttpErrorCodeReturnedEquals>
+            </Condition>
+            <Redirect>
+                <Protocol>https</Protocol>
+                <HostName>example.com</HostName>
+                <ReplaceKeyWith>not-found.html</ReplaceKeyWith>
+                <HttpRedirectCode>302</HttpRedirectCode>
+            </Redirect>
+        </RoutingRule>
+    </RoutingRules>
+</WebsiteConfiguration>"#,
+        );
+
+        let out: WebsiteConfiguration =
+            quick_xml::de::from_reader(bytes::Buf::reader(bs)).expect("must success");
+
+        assert!(out.redirect_all_requests_to.is_none());
+        assert_eq!(out.index_document.expect("must exist").suffix, "index.html");
+        assert_eq!(out.error_document.expect("must exist").key, "error.html");
+
+        let rules: Vec<RoutingRule> = out
+            .routing_rules
+            .expect("must exist")
+            .routing_rule
+            .into_iter()
+            .map(RoutingRule::from)
+            .collect();
+        assert_eq!(
+            rules,
+            vec![
+                RoutingRule {
+                    key_prefix_equals: Some("docs/".to_string()),
+                    replace_key_prefix_with: Some("documents/".to_string()),
+                    ..Default::default()
+                },
+                RoutingRule {
+                    http_error_code_returned_equals: Some(404),
+                    protocol: Some("https".to_string()),
+                    host_name: Some("example.com".to_string()),
+                    replace_key_with: Some("not-found.html".to_string()),
+                    http_redirect_code: Some(302),
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_serialize_website_configuration() {
+        let rules = [
+            RoutingRule {
+                key_prefix_equals: Some("docs/".to_string()),
+                replace_key_prefix_with: Some("documents/".to_string()),
+                ..Default::default()
+            },
+            RoutingRule {
+                host_name: Some("example.com".to_string()),
+                ..Default::default()
+            },
+        ];
+        let req = WebsiteConfiguration {
+            index_document: Some(WebsiteIndexDocument {
+                suffix: "index.html".to_string(),
+            }),
+            routing_rules: Some(WebsiteRoutingRules {
+                routing_rule: rules.iter().map(WebsiteRoutingRule::from).collect(),
+            }),
+            ..Default::default()
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            "<WebsiteConfiguration><IndexDocument><Suffix>index.html</Suffix></IndexDocument>\
+<RoutingRules><RoutingRule><Condition><KeyPrefixEquals>docs/</KeyPrefixEquals></Condition>\
+<Redirect><ReplaceKeyPrefixWith>documents/</ReplaceKeyPrefixWith></Redirect></RoutingRule>\
+<RoutingRule><Redirect><HostName>example.com</HostName></Redirect></RoutingRule></RoutingRules>\
+</WebsiteConfiguration>"
+        )
+    }
+
+    #[test]
+    fn test_validate_cors_rules() {
+        let valid = CorsRule {
+            allowed_origins: vec!["https://*.example.com".to_string()],
+            allowed_methods: vec!["GET".to_string()],
+            allowed_headers: vec!["*".to_string()],
+            expose_headers: vec![],
+            max_age_seconds: 0,
+        };
+        assert!(validate_cors_rules(&[valid.clone()]).is_ok());
+
+        let cases = vec![
+            (
+                "wildcard method",
+                CorsRule {
+                    allowed_methods: vec!["*".to_string()],
+                    ..valid.clone()
+                },
+            ),
+            (
+                "empty origins",
+                CorsRule {
+                    allowed_origins: vec![],
+                    ..valid.clone()
+                },
+            ),
+            (
+                "multiple wildcards in origin",
+                CorsRule {
+                    allowed_origins: vec!["*.*".to_string()],
+                    ..valid.clone()
+                },
+            ),
+            (
+                "wildcard in expose header",
+                CorsRule {
+                    expose_headers: vec!["*".to_string()],
+                    ..valid.clone()
+                },
+            ),
+        ];
+
+        for (name, rule) in cases {
+            let err = validate_cors_rules(&[rule]).expect_err(name);
+            assert_eq!(err.kind(), ErrorKind::InvalidInput, "{name}");
+        }
+
+        let rules = vec![valid; 101];
+        assert!(validate_cors_rules(&rules).is_err());
     }
 }
@@ -15,6 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
 use bytes::Buf;
 use http::Response;
 use http::StatusCode;
@@ -26,10 +30,40 @@ use crate::Error;
 use crate::ErrorKind;
 use crate::Result;
 
+/// Error code returned while accessing requester pays buckets without
+/// request payer header.
+const REQUESTER_PAYS_REQUIRED: &str = "RequestorPaysBucketRequiresRequestPayerHeader";
+
+/// Error codes returned while the content doesn't match `Content-MD5`.
+const DIGEST_MISMATCH: [&str; 2] = ["InvalidDigest", "BadDigest"];
+
+/// Header of the request id that obs returns with every response.
+const X_OBS_REQUEST_ID: &str = "x-obs-request-id";
+
 /// ObsError is the error returned by obs service.
-#[derive(Default, Debug, Deserialize)]
+///
+/// [`Error`]s built from obs error responses carry the ObsError as their
+/// source, use [`ObsError::downcast`] to recover it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use anyhow::Result;
+/// use opendal::services::ObsError;
+/// use opendal::Operator;
+///
+/// # async fn test(op: Operator) -> Result<()> {
+/// if let Err(err) = op.stat("path/to/file").await {
+///     if let Some(obs_err) = ObsError::downcast(&err) {
+///         println!("{} {}", obs_err.code(), obs_err.request_id());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, Deserialize)]
 #[serde(default, rename_all = "PascalCase")]
-struct ObsError {
+pub struct ObsError {
     code: String,
     message: String,
     resource: String,
@@ -37,17 +71,84 @@ struct ObsError {
     host_id: String,
 }
 
+impl ObsError {
+    /// Error code like `NoSuchKey`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Error message returned by obs.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The bucket or object related to this error.
+    pub fn resource(&self) -> &str {
+        &self.resource
+    }
+
+    /// ID of the request, which is useful while asking obs for support.
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    /// ID of the server which handled the request.
+    pub fn host_id(&self) -> &str {
+        &self.host_id
+    }
+
+    /// Find the ObsError in the source chain of given error.
+    ///
+    /// Returns `None` if the error is not built from an obs error response,
+    /// or the response body is not a valid obs error.
+    ///
+    /// For [`std::io::Error`] converted from [`Error`], use
+    /// `io_err.get_ref()` to get the inner [`Error`] first.
+    pub fn downcast(err: &Error) -> Option<&ObsError> {
+        let mut source = std::error::Error::source(err);
+        while let Some(err) = source {
+            if let Some(obs_err) = err.downcast_ref::<ObsError>() {
+                return Some(obs_err);
+            }
+            source = err.source();
+        }
+        None
+    }
+}
+
+impl Display for ObsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}, request_id: {}",
+            self.code, self.message, self.request_id
+        )
+    }
+}
+
+impl std::error::Error for ObsError {}
+
 /// Parse error response into Error.
 pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     let (parts, body) = resp.into_parts();
     let bs = body.bytes().await?;
 
+    let obs_error = de::from_reader::<_, ObsError>(bs.clone().reader()).ok();
+
     let (kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
         StatusCode::PRECONDITION_FAILED | StatusCode::NOT_MODIFIED => {
             (ErrorKind::ConditionNotMatch, false)
         }
+        StatusCode::BAD_REQUEST
+            if obs_error
+                .as_ref()
+                .map(|v| DIGEST_MISMATCH.contains(&v.code.as_str()))
+                .unwrap_or_default() =>
+        {
+            (ErrorKind::InvalidData, false)
+        }
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
         | StatusCode::SERVICE_UNAVAILABLE
@@ -58,12 +159,26 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         _ => (ErrorKind::Unexpected, false),
     };
 
-    let message = match de::from_reader::<_, ObsError>(bs.clone().reader()) {
-        Ok(obs_error) => format!("{obs_error:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let message = match &obs_error {
+        // Give users a hint about how to access requester pays buckets.
+        Some(obs_error) if obs_error.code == REQUESTER_PAYS_REQUIRED => format!(
+            "bucket is requester pays, please enable requester_pays in builder: {obs_error:?}"
+        ),
+        Some(obs_error) => format!("{obs_error:?}"),
+        None => String::from_utf8_lossy(&bs).into_owned(),
     };
 
     let mut err = Error::new(kind, &message);
+    if let Some(obs_error) = obs_error {
+        err = err.set_source(obs_error);
+    }
+    if let Some(request_id) = parts
+        .headers
+        .get(X_OBS_REQUEST_ID)
+        .and_then(|v| v.to_str().ok())
+    {
+        err = err.with_context("request_id", request_id);
+    }
 
     err = with_error_response_context(err, parts);
 
@@ -74,6 +189,15 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     Ok(err)
 }
 
+/// Add the trace context of the request into error, so that the failed
+/// request can be found in the caller's trace.
+pub fn with_request_context(err: Error, ctx: Option<&RequestContext>) -> Error {
+    match ctx.and_then(|v| v.trace_context()) {
+        Some(trace) => err.with_context("traceparent", trace.traceparent()),
+        None => err,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,4 +229,93 @@ mod tests {
             "RkRCRDJENDc5MzdGQkQ4OUY3MTI4NTQ3NDk2Mjg0M0FBQUFBQUFBYmJiYmJiYmJD"
         );
     }
+
+    #[tokio::test]
+    async fn test_parse_error_requester_pays() -> Result<()> {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+<Code>RequestorPaysBucketRequiresRequestPayerHeader</Code>
+<Message>Bucket requires request payer header</Message>
+</Error>"#;
+        let body = IncomingAsyncBody::new(
+            Box::new(oio::into_stream::from_futures_stream(
+                futures::stream::iter(vec![Ok(bytes::Bytes::from(body))]),
+            )),
+            None,
+        );
+        let resp = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(body)
+            .expect("must success");
+
+        let err = parse_error(resp).await?;
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(err.to_string().contains("enable requester_pays"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parse_error_downcast() -> Result<()> {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+<Code>NoSuchKey</Code>
+<Message>The resource you requested does not exist</Message>
+<RequestId>001B21A61C6C0000013402C4616D5285</RequestId>
+<HostId>RkRCRDJENDc5MzdGQkQ4OUY3MTI4NTQ3NDk2Mjg0M0FBQUFBQUFBYmJiYmJiYmJD</HostId>
+</Error>"#;
+        let body = IncomingAsyncBody::new(
+            Box::new(oio::into_stream::from_futures_stream(
+                futures::stream::iter(vec![Ok(bytes::Bytes::from(body))]),
+            )),
+            None,
+        );
+        let resp = Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(body)
+            .expect("must success");
+
+        let err = parse_error(resp).await?;
+        let obs_err = ObsError::downcast(&err).expect("must be obs error");
+        assert_eq!(obs_err.code(), "NoSuchKey");
+        assert_eq!(obs_err.request_id(), "001B21A61C6C0000013402C4616D5285");
+
+        // ObsError is still reachable after wrapped by other errors.
+        let err = Error::new(ErrorKind::Unexpected, "wrapped").set_source(err);
+        assert!(ObsError::downcast(&err).is_some());
+
+        let io_err = std::io::Error::from(err);
+        let inner = io_err
+            .get_ref()
+            .and_then(|v| v.downcast_ref::<Error>())
+            .expect("must be opendal error");
+        assert_eq!(
+            ObsError::downcast(inner).map(|v| v.code()),
+            Some("NoSuchKey")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parse_error_invalid_digest() -> Result<()> {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+<Code>InvalidDigest</Code>
+<Message>The Content-MD5 you specified did not match what we received.</Message>
+</Error>"#;
+        let body = IncomingAsyncBody::new(
+            Box::new(oio::into_stream::from_futures_stream(
+                futures::stream::iter(vec![Ok(bytes::Bytes::from(body))]),
+            )),
+            None,
+        );
+        let resp = Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(body)
+            .expect("must success");
+
+        let err = parse_error(resp).await?;
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(!err.is_temporary());
+        Ok(())
+    }
 }
@@ -0,0 +1,530 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! An in-memory OBS server for unit tests.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
+use md5::Digest;
+use md5::Md5;
+use percent_encoding::percent_decode_str;
+use quick_xml::escape::escape;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::Request;
+use wiremock::Respond;
+use wiremock::ResponseTemplate;
+
+use super::backend::ObsBackend;
+use super::backend::ObsBuilder;
+use crate::raw::*;
+use crate::*;
+
+/// Max keys of a list page if `max-keys` is not specified, the same as OBS.
+const DEFAULT_MAX_KEYS: usize = 1000;
+
+/// ObsMockServer simulates the OBS API on top of an in-memory bucket.
+///
+/// Supported APIs:
+///
+/// - `PUT` to write objects, `If-None-Match: *` is respected.
+/// - `PUT` with `x-obs-copy-source` to copy objects.
+/// - `GET` to read objects, with or without `Range`.
+/// - `HEAD` to stat objects.
+/// - `DELETE` to delete objects.
+/// - Multipart uploads, parts can be uploaded with body or copied by
+///   `x-obs-copy-source`.
+/// - `GET /?prefix=&delimiter=&marker=&max-keys=` to list objects page
+///   by page, `list-type=2` with `continuation-token` is also supported.
+///
+/// Failures can be injected by [`ObsMockServer::fail`].
+pub struct ObsMockServer {
+    server: MockServer,
+    responder: ObsResponder,
+    expected: Mutex<Vec<(String, String)>>,
+}
+
+impl ObsMockServer {
+    /// Start a new mock server and a backend connected to it.
+    pub async fn start() -> (Self, ObsBackend) {
+        let server = MockServer::start().await;
+        let responder = ObsResponder::default();
+        Mock::given(wiremock::matchers::any())
+            .respond_with(responder.clone())
+            .mount(&server)
+            .await;
+
+        let mut builder = ObsBuilder::default();
+        builder
+            .endpoint(&server.uri())
+            .bucket("test")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key");
+        let backend = Builder::build(&mut builder).expect("build must succeed");
+
+        (
+            Self {
+                server,
+                responder,
+                expected: Mutex::default(),
+            },
+            backend,
+        )
+    }
+
+    /// Expect a request with given method and path, the path is the object
+    /// key like `path/to/file`.
+    ///
+    /// Expectations are checked by [`ObsMockServer::assert_all_called`].
+    pub fn expect(&self, method: &str, path: &str) -> &Self {
+        self.expected
+            .lock()
+            .unwrap()
+            .push((method.to_uppercase(), path.to_string()));
+        self
+    }
+
+    /// Make all following requests with given method and path fail with
+    /// `status`.
+    pub fn fail(&self, method: &str, path: &str, status: u16) -> &Self {
+        self.responder.failures.lock().unwrap().push((
+            method.to_uppercase(),
+            path.to_string(),
+            status,
+        ));
+        self
+    }
+
+    /// Assert all expected requests have been received.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any expected request is not received.
+    pub async fn assert_all_called(&self) {
+        let received: Vec<(String, String)> = self
+            .server
+            .received_requests()
+            .await
+            .expect("request recording must be enabled")
+            .iter()
+            .map(|req| (req.method.to_string(), object_key(req)))
+            .collect();
+
+        for (method, path) in self.expected.lock().unwrap().iter() {
+            assert!(
+                received.iter().any(|(m, p)| m == method && p == path),
+                "expected request {method} {path} is not received, received: {received:?}"
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Object {
+    content: Bytes,
+    content_type: Option<String>,
+    etag: String,
+    last_modified: DateTime<Utc>,
+}
+
+#[derive(Default, Clone)]
+struct ObsResponder {
+    objects: Arc<Mutex<BTreeMap<String, Object>>>,
+    /// Failures injected as `(method, path, status)`.
+    failures: Arc<Mutex<Vec<(String, String, u16)>>>,
+    /// Parts of ongoing multipart uploads by upload id.
+    uploads: Arc<Mutex<BTreeMap<String, BTreeMap<usize, Bytes>>>>,
+}
+
+impl ObsResponder {
+    fn respond_multipart(
+        &self,
+        method: &str,
+        req: &Request,
+        objects: &mut BTreeMap<String, Object>,
+    ) -> Option<ResponseTemplate> {
+        let key = object_key(req);
+        let mut uploads = self.uploads.lock().unwrap();
+
+        if method == "POST" && query(req, "uploads").is_some() {
+            let upload_id = format!("upload-{}", uploads.len());
+            uploads.insert(upload_id.clone(), BTreeMap::new());
+            return Some(ResponseTemplate::new(200).set_body_string(format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><InitiateMultipartUploadResult><UploadId>{upload_id}</UploadId></InitiateMultipartUploadResult>"
+            )));
+        }
+
+        let upload_id = query(req, "uploadId")?;
+        let parts = match uploads.get_mut(&upload_id) {
+            Some(parts) => parts,
+            None => return Some(error_response(404, "NoSuchUpload")),
+        };
+
+        let resp = match method {
+            "PUT" => {
+                let part_number = query(req, "partNumber")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or_default();
+                let copied = header(req, "x-obs-copy-source").map(|source| {
+                    let source = percent_decode_str(source).decode_utf8_lossy();
+                    objects
+                        .get(source.trim_start_matches("/test/"))
+                        .map(|v| v.content.clone())
+                });
+                let is_copy = copied.is_some();
+                let content = match copied {
+                    None => Bytes::from(req.body.clone()),
+                    Some(Some(content)) => content,
+                    Some(None) => return Some(error_response(404, "NoSuchKey")),
+                };
+
+                let etag = format!("\"{:x}\"", Md5::digest(&content));
+                parts.insert(part_number, content);
+                if is_copy {
+                    ResponseTemplate::new(200).set_body_string(format!(
+                        "<?xml version=\"1.0\" encoding=\"UTF-8\"?><CopyPartResult><ETag>{}</ETag></CopyPartResult>",
+                        escape(&etag)
+                    ))
+                } else {
+                    ResponseTemplate::new(200).insert_header("etag", etag.as_str())
+                }
+            }
+            "POST" => {
+                let content: Vec<u8> = parts.values().flat_map(|v| v.to_vec()).collect();
+                let object = Object {
+                    etag: format!("\"{:x}-{}\"", Md5::digest(&content), parts.len()),
+                    content: Bytes::from(content),
+                    content_type: None,
+                    last_modified: Utc::now(),
+                };
+                let resp = ResponseTemplate::new(200).set_body_string(format!(
+                    "<?xml version=\"1.0\" encoding=\"UTF-8\"?><CompleteMultipartUploadResult><Key>{}</Key><ETag>{}</ETag></CompleteMultipartUploadResult>",
+                    escape(&key),
+                    escape(&object.etag)
+                ));
+                objects.insert(key, object);
+                uploads.remove(&upload_id);
+                resp
+            }
+            "DELETE" => {
+                uploads.remove(&upload_id);
+                ResponseTemplate::new(204)
+            }
+            _ => error_response(405, "MethodNotAllowed"),
+        };
+        Some(resp)
+    }
+}
+
+impl Respond for ObsResponder {
+    fn respond(&self, req: &Request) -> ResponseTemplate {
+        let key = object_key(req);
+        let method = req.method.to_string();
+        if let Some((_, _, status)) = self
+            .failures
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(m, p, _)| m == &method && p == &key)
+        {
+            return error_response(*status, "InjectedFailure");
+        }
+
+        let mut objects = self.objects.lock().unwrap();
+        if let Some(resp) = self.respond_multipart(&method, req, &mut objects) {
+            return resp;
+        }
+
+        match (method.as_str(), key.is_empty()) {
+            ("GET", true) => list_objects(&objects, req),
+            ("PUT", false) if header(req, "x-obs-copy-source").is_some() => {
+                let source = header(req, "x-obs-copy-source").unwrap_or_default();
+                let source = percent_decode_str(source).decode_utf8_lossy();
+                let source = source.trim_start_matches("/test/");
+                match objects.get(source).cloned() {
+                    Some(mut object) => {
+                        object.last_modified = Utc::now();
+                        objects.insert(key, object);
+                        ResponseTemplate::new(200).set_body_string(
+                            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><CopyObjectResult></CopyObjectResult>",
+                        )
+                    }
+                    None => error_response(404, "NoSuchKey"),
+                }
+            }
+            ("PUT", false) => {
+                if header(req, "if-none-match") == Some("*") && objects.contains_key(&key) {
+                    return error_response(412, "PreconditionFailed");
+                }
+
+                let content = Bytes::from(req.body.clone());
+                let object = Object {
+                    etag: format!("\"{:x}\"", Md5::digest(&content)),
+                    content,
+                    content_type: header(req, "content-type").map(|v| v.to_string()),
+                    last_modified: Utc::now(),
+                };
+                let resp = ResponseTemplate::new(200).insert_header("etag", object.etag.as_str());
+                objects.insert(key, object);
+                resp
+            }
+            ("GET", false) => match objects.get(&key) {
+                Some(object) => read_object(object, header(req, "range")),
+                None => error_response(404, "NoSuchKey"),
+            },
+            ("HEAD", false) => match objects.get(&key) {
+                Some(object) => object_response(200, object)
+                    .insert_header("content-length", object.content.len().to_string().as_str()),
+                // HEAD responses don't have body.
+                None => ResponseTemplate::new(404),
+            },
+            ("DELETE", false) => {
+                objects.remove(&key);
+                ResponseTemplate::new(204)
+            }
+            _ => error_response(405, "MethodNotAllowed"),
+        }
+    }
+}
+
+/// Get the decoded object key of request.
+fn object_key(req: &Request) -> String {
+    let path = req.url.path().trim_start_matches('/');
+    percent_decode_str(path).decode_utf8_lossy().to_string()
+}
+
+fn header<'a>(req: &'a Request, name: &str) -> Option<&'a str> {
+    req.headers
+        .iter()
+        .find(|(k, _)| k.as_str().eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.last().as_str())
+}
+
+fn query(req: &Request, name: &str) -> Option<String> {
+    req.url
+        .query_pairs()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.to_string())
+}
+
+fn error_response(status: u16, code: &str) -> ResponseTemplate {
+    ResponseTemplate::new(status).set_body_string(format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>{code}</Code><Message>{code}</Message><RequestId>mock</RequestId><HostId>mock</HostId></Error>"
+    ))
+}
+
+fn object_response(status: u16, object: &Object) -> ResponseTemplate {
+    let mut resp = ResponseTemplate::new(status)
+        .insert_header("etag", object.etag.as_str())
+        .insert_header(
+            "last-modified",
+            format_datetime_into_http_date(object.last_modified).as_str(),
+        );
+    if let Some(v) = &object.content_type {
+        resp = resp.insert_header("content-type", v.as_str());
+    }
+    resp
+}
+
+fn read_object(object: &Object, range: Option<&str>) -> ResponseTemplate {
+    let size = object.content.len() as u64;
+    let range = match range {
+        None => return object_response(200, object).set_body_bytes(object.content.to_vec()),
+        Some(v) => match v.parse::<BytesRange>() {
+            Ok(range) => range,
+            Err(_) => return error_response(400, "InvalidArgument"),
+        },
+    };
+
+    let (start, end) = match (range.offset(), range.size()) {
+        (Some(offset), Some(len)) => (offset, (offset + len).min(size)),
+        (Some(offset), None) => (offset, size),
+        (None, Some(len)) => (size.saturating_sub(len), size),
+        (None, None) => (0, size),
+    };
+    if start >= end {
+        return error_response(416, "InvalidRange");
+    }
+
+    object_response(206, object)
+        .insert_header(
+            "content-range",
+            format!("bytes {}-{}/{size}", start, end - 1).as_str(),
+        )
+        .set_body_bytes(object.content[start as usize..end as usize].to_vec())
+}
+
+fn list_objects(objects: &BTreeMap<String, Object>, req: &Request) -> ResponseTemplate {
+    let prefix = query(req, "prefix").unwrap_or_default();
+    let delimiter = query(req, "delimiter").unwrap_or_default();
+    let v2 = query(req, "list-type").as_deref() == Some("2");
+    // The last returned key is used as the continuation token.
+    let marker = if v2 {
+        query(req, "continuation-token")
+    } else {
+        query(req, "marker")
+    }
+    .unwrap_or_default();
+    let max_keys = query(req, "max-keys")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MAX_KEYS);
+
+    // Entries are `(key, object)`, object is `None` for common prefixes.
+    let mut entries: Vec<(String, Option<&Object>)> = vec![];
+    for (key, object) in objects.range(marker.clone()..) {
+        if !key.starts_with(&prefix) || key <= &marker {
+            continue;
+        }
+        // Keys under a returned common prefix have been listed already.
+        if !delimiter.is_empty() && marker.ends_with(&delimiter) && key.starts_with(&marker) {
+            continue;
+        }
+
+        let rest = &key[prefix.len()..];
+        match rest.find(&delimiter).filter(|_| !delimiter.is_empty()) {
+            Some(idx) => {
+                let common_prefix = &key[..prefix.len() + idx + delimiter.len()];
+                if entries.last().map(|(k, _)| k.as_str()) != Some(common_prefix) {
+                    entries.push((common_prefix.to_string(), None));
+                }
+            }
+            None => entries.push((key.clone(), Some(object))),
+        }
+    }
+
+    let truncated = entries.len() > max_keys;
+    entries.truncate(max_keys);
+
+    let mut body = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?><ListBucketResult><Name>test</Name><Prefix>{}</Prefix><Marker>{}</Marker><MaxKeys>{max_keys}</MaxKeys><IsTruncated>{truncated}</IsTruncated>",
+        escape(&prefix),
+        escape(&marker),
+    );
+    if truncated {
+        let next_marker = &entries.last().expect("truncated page must have entries").0;
+        let tag = if v2 {
+            "NextContinuationToken"
+        } else {
+            "NextMarker"
+        };
+        body.push_str(&format!("<{tag}>{}</{tag}>", escape(next_marker)));
+    }
+    for (key, object) in &entries {
+        match object {
+            Some(object) => body.push_str(&format!(
+                "<Contents><Key>{}</Key><LastModified>{}</LastModified><ETag>{}</ETag><Size>{}</Size><StorageClass>STANDARD</StorageClass></Contents>",
+                escape(key),
+                object.last_modified.to_rfc3339(),
+                escape(&object.etag),
+                object.content.len(),
+            )),
+            None => body.push_str(&format!(
+                "<CommonPrefixes><Prefix>{}</Prefix></CommonPrefixes>",
+                escape(key)
+            )),
+        }
+    }
+    body.push_str("</ListBucketResult>");
+
+    ResponseTemplate::new(200).set_body_string(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_write() -> Result<()> {
+        let (server, backend) = ObsMockServer::start().await;
+        let op = OperatorBuilder::new(backend).finish();
+
+        op.write_with("file", "Hello, World!")
+            .content_type("text/plain")
+            .await?;
+        assert_eq!(op.read("file").await?, b"Hello, World!");
+        assert_eq!(op.range_read("file", 7..12).await?, b"World");
+        assert_eq!(op.range_read("file", 7..).await?, b"World!");
+
+        let meta = op.stat("file").await?;
+        assert_eq!(meta.content_length(), 13);
+        assert_eq!(meta.content_type(), Some("text/plain"));
+        assert!(meta.etag().is_some());
+
+        op.delete("file").await?;
+        let err = op.stat("file").await.expect_err("file must be deleted");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        let err = op.read("file").await.expect_err("file must be deleted");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        server
+            .expect("PUT", "file")
+            .expect("GET", "file")
+            .expect("HEAD", "file")
+            .expect("DELETE", "file");
+        server.assert_all_called().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list() -> Result<()> {
+        let (server, backend) = ObsMockServer::start().await;
+        let op = OperatorBuilder::new(backend).finish();
+
+        for path in ["dir/a", "dir/b", "dir/c", "dir/sub/d", "dir/sub/e", "other"] {
+            op.write(path, "Hello").await?;
+        }
+
+        // Every page contains at most 2 entries.
+        let lister = op.list_with("dir/").limit(2).await?;
+        let mut paths: Vec<String> = lister
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await?;
+        // Dirs are returned before files in the same page.
+        paths.sort();
+        assert_eq!(paths, vec!["dir/a", "dir/b", "dir/c", "dir/sub/"]);
+
+        let lister = op.scan("dir/").await?;
+        let paths: Vec<String> = lister
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await?;
+        assert_eq!(
+            paths,
+            vec!["dir/a", "dir/b", "dir/c", "dir/sub/d", "dir/sub/e"]
+        );
+
+        server.expect("GET", "");
+        server.assert_all_called().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "is not received")]
+    async fn test_assert_all_called() {
+        let (server, _) = ObsMockServer::start().await;
+        server.expect("GET", "file");
+        server.assert_all_called().await;
+    }
+}
@@ -16,10 +16,23 @@
 // under the License.
 
 mod backend;
+pub use backend::BucketInfo as ObsBucketInfo;
+pub use backend::ClientInfo as ObsClientInfo;
+pub use backend::DebugInfo as ObsDebugInfo;
+pub use backend::IncompleteUpload as ObsIncompleteUpload;
+pub use backend::ObsBackend;
 pub use backend::ObsBuilder as Obs;
+pub use backend::RoutingRule as ObsRoutingRule;
+pub use backend::StaticWebsiteConfig as ObsStaticWebsiteConfig;
+pub use backend::UploadedPart as ObsUploadedPart;
 
 mod appender;
 mod core;
 mod error;
+pub use error::ObsError;
+#[cfg(test)]
+mod mock;
 mod pager;
+mod reader;
+mod sts;
 mod writer;
@@ -19,6 +19,8 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use bytes::Buf;
+use bytes::Bytes;
+use log::warn;
 use quick_xml::de;
 use serde::Deserialize;
 
@@ -28,7 +30,9 @@ use crate::raw::*;
 use crate::EntryMode;
 use crate::Error;
 use crate::ErrorKind;
+use crate::ErrorPolicy;
 use crate::Metadata;
+use crate::Owner;
 use crate::Result;
 
 pub struct ObsPager {
@@ -36,54 +40,120 @@ pub struct ObsPager {
     path: String,
     delimiter: String,
     limit: Option<usize>,
+    error_policy: ErrorPolicy,
 
+    /// The `marker`, or the `continuation-token` of list objects v2, of
+    /// the next page.
     next_marker: String,
+    /// The marker of last failed request, used by `ErrorPolicy::SkipPage`.
+    failed_marker: Option<String>,
     done: bool,
 }
 
 impl ObsPager {
-    pub fn new(core: Arc<ObsCore>, path: &str, delimiter: &str, limit: Option<usize>) -> Self {
+    pub fn new(core: Arc<ObsCore>, path: &str, args: &OpList) -> Self {
         Self {
             core,
             path: path.to_string(),
-            delimiter: delimiter.to_string(),
-            limit,
+            delimiter: args.delimiter().to_string(),
+            limit: args.limit(),
+            error_policy: args.error_policy().clone(),
 
             next_marker: "".to_string(),
+            failed_marker: None,
             done: false,
         }
     }
-}
 
-#[async_trait]
-impl oio::Page for ObsPager {
-    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
-        if self.done {
-            return Ok(None);
+    /// Narrow the prefix that will be listed by this pager.
+    ///
+    /// `prefix` is relative to root like the listing path and must be
+    /// under it, otherwise an `InvalidInput` error will be returned. The
+    /// filter is applied by service, entries outside of `prefix` will not
+    /// be returned at all.
+    ///
+    /// This function must be called before the first page is fetched.
+    pub fn with_prefix_filter(mut self, prefix: &str) -> Result<Self> {
+        if !prefix.starts_with(&self.path) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "prefix filter must be under the listing path",
+            )
+            .with_context("service", "obs")
+            .with_context("path", &self.path)
+            .with_context("prefix", prefix));
         }
 
-        let resp = self
-            .core
-            .obs_list_objects(&self.path, &self.next_marker, &self.delimiter, self.limit)
-            .await?;
+        self.path = prefix.to_string();
+        Ok(self)
+    }
+
+    /// Fetch the next page, pager's state will only be updated if
+    /// succeeded, so it's safe to call again after error.
+    async fn next_page(&mut self) -> Result<Vec<oio::Entry>> {
+        let bs = self.fetch_page().await?;
+        self.parse_page(bs)
+    }
+
+    async fn fetch_page(&self) -> Result<Bytes> {
+        let resp = if self.core.disable_list_objects_v2 {
+            self.core
+                .obs_list_objects(&self.path, &self.next_marker, &self.delimiter, self.limit)
+                .await?
+        } else {
+            self.core
+                .obs_list_objects_v2(&self.path, &self.next_marker, &self.delimiter, self.limit)
+                .await?
+        };
 
         if resp.status() != http::StatusCode::OK {
             return Err(parse_error(resp).await?);
         }
 
-        let bs = resp.into_body().bytes().await?;
-
-        let output: Output = de::from_reader(bs.reader())
-            .map_err(|e| Error::new(ErrorKind::Unexpected, "deserialize xml").set_source(e))?;
+        resp.into_body().bytes().await
+    }
 
+    /// Update the marker of next page, the list is done if there is no
+    /// next marker.
+    fn advance(&mut self, next_marker: Option<String>, next_continuation_token: Option<String>) {
         // Try our best to check whether this list is done.
         //
-        // - Check `next_marker`
-        self.done = match output.next_marker.as_ref() {
+        // - Check `next_marker`, or `next_continuation_token` for v2
+        let next_marker = if self.core.disable_list_objects_v2 {
+            next_marker
+        } else {
+            next_continuation_token
+        };
+        self.done = match next_marker.as_ref() {
             None => true,
             Some(next_marker) => next_marker.is_empty(),
         };
-        self.next_marker = output.next_marker.clone().unwrap_or_default();
+        self.next_marker = next_marker.unwrap_or_default();
+    }
+
+    /// Skip the page that can't be parsed by reading its next marker only.
+    ///
+    /// The parse error will be returned if the next marker can't be read
+    /// either.
+    fn skip_page(&mut self, bs: Bytes, err: Error) -> Result<Vec<oio::Entry>> {
+        let output: OutputMarker = match de::from_reader(bs.reader()) {
+            Ok(output) => output,
+            Err(_) => return Err(err),
+        };
+
+        warn!(
+            "service=obs operation=list path={} marker={}: skip page: {err}",
+            self.path, self.next_marker
+        );
+        self.advance(output.next_marker, output.next_continuation_token);
+        Ok(vec![])
+    }
+
+    fn parse_page(&mut self, bs: Bytes) -> Result<Vec<oio::Entry>> {
+        let output: Output = de::from_reader(bs.reader())
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "deserialize xml").set_source(e))?;
+
+        self.advance(output.next_marker, output.next_continuation_token);
 
         let common_prefixes = output.common_prefixes;
         let mut entries = Vec::with_capacity(common_prefixes.len() + output.contents.len());
@@ -102,13 +172,185 @@ impl oio::Page for ObsPager {
                 continue;
             }
 
-            let meta = Metadata::new(EntryMode::FILE).with_content_length(object.size);
+            let mut meta = Metadata::new(EntryMode::FILE).with_content_length(object.size);
+            if let Some(etag) = object.etag.as_deref().filter(|v| !v.is_empty()) {
+                meta.set_etag(etag);
+                // Etags of files uploaded by multipart are not md5 of the
+                // content, they look like `"<md5>-<parts>"`. Etags of
+                // encrypted files are not md5 of the content either.
+                let etag = etag.trim_matches('"');
+                if !etag.contains('-') && self.core.server_side_encryption_customer_key.is_none() {
+                    meta.set_content_md5(etag);
+                }
+            }
+            if let Some(owner) = &object.owner {
+                meta.set_owner(Owner::new(&owner.id, &owner.display_name));
+            }
 
             let de = oio::Entry::new(&build_rel_path(&self.core.root, &object.key), meta);
 
             entries.push(de);
         }
 
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl oio::Page for ObsPager {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        match self.error_policy.clone() {
+            ErrorPolicy::FailFast => self.next_page().await.map(Some),
+            ErrorPolicy::SkipPage => {
+                let bs = match self.fetch_page().await {
+                    Ok(bs) => bs,
+                    Err(err) if self.failed_marker.as_ref() == Some(&self.next_marker) => {
+                        return Err(err)
+                    }
+                    Err(err) => {
+                        warn!(
+                            "service=obs operation=list path={} marker={}: retry page once: {err}",
+                            self.path, self.next_marker
+                        );
+                        self.failed_marker = Some(self.next_marker.clone());
+                        return Ok(Some(vec![]));
+                    }
+                };
+                self.failed_marker = None;
+
+                match self.parse_page(bs.clone()) {
+                    Ok(entries) => Ok(Some(entries)),
+                    Err(err) => self.skip_page(bs, err).map(Some),
+                }
+            }
+            ErrorPolicy::Retry(cfg) => {
+                let mut backoff = cfg.backoff();
+                loop {
+                    match self.next_page().await {
+                        Ok(entries) => return Ok(Some(entries)),
+                        Err(err) if err.is_temporary() => match backoff.next() {
+                            Some(dur) => {
+                                warn!(
+                                    "service=obs operation=list path={} marker={}: retry after {}s: {err}",
+                                    self.path,
+                                    self.next_marker,
+                                    dur.as_secs_f64()
+                                );
+                                tokio::time::sleep(dur).await;
+                            }
+                            None => return Err(err),
+                        },
+                        Err(err) => return Err(err),
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub struct ObsVersionsPager {
+    core: Arc<ObsCore>,
+    path: String,
+    limit: Option<usize>,
+    delete_markers: bool,
+
+    key_marker: String,
+    version_id_marker: String,
+    done: bool,
+}
+
+impl ObsVersionsPager {
+    pub fn new(core: Arc<ObsCore>, path: &str, args: &OpListVersions) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+            limit: args.limit(),
+            delete_markers: args.delete_markers(),
+
+            key_marker: "".to_string(),
+            version_id_marker: "".to_string(),
+            done: false,
+        }
+    }
+}
+
+#[async_trait]
+impl oio::Page for ObsVersionsPager {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = self
+            .core
+            .obs_list_object_versions(
+                &self.path,
+                &self.key_marker,
+                &self.version_id_marker,
+                self.limit,
+            )
+            .await?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body().bytes().await?;
+
+        let output: VersionsOutput = de::from_reader(bs.reader())
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "deserialize xml").set_source(e))?;
+
+        self.done = !output.is_truncated;
+        self.key_marker = output.next_key_marker.unwrap_or_default();
+        self.version_id_marker = output.next_version_id_marker.unwrap_or_default();
+
+        let mut entries = Vec::with_capacity(output.versions.len());
+
+        // Delete markers don't have content, so they are only returned if asked.
+        for version in output.versions {
+            if version.key.ends_with('/') {
+                continue;
+            }
+
+            let mut meta = Metadata::new(EntryMode::FILE)
+                .with_content_length(version.size)
+                .with_version(version.version_id);
+            if !version.etag.is_empty() {
+                meta.set_etag(&version.etag);
+            }
+            if !version.last_modified.is_empty() {
+                meta.set_last_modified(parse_datetime_from_rfc3339(&version.last_modified)?);
+            }
+
+            let de = oio::Entry::new(&build_rel_path(&self.core.root, &version.key), meta);
+
+            entries.push(de);
+        }
+
+        if self.delete_markers {
+            for marker in output.delete_markers {
+                if marker.key.ends_with('/') {
+                    continue;
+                }
+
+                let mut meta = Metadata::new(EntryMode::FILE)
+                    .with_content_length(0)
+                    .with_version(marker.version_id)
+                    .with_delete_marker(true);
+                if !marker.last_modified.is_empty() {
+                    meta.set_last_modified(parse_datetime_from_rfc3339(&marker.last_modified)?);
+                }
+
+                let de = oio::Entry::new(&build_rel_path(&self.core.root, &marker.key), meta);
+
+                entries.push(de);
+            }
+        }
+
         Ok(Some(entries))
     }
 }
@@ -122,6 +364,16 @@ struct Output {
     common_prefixes: Vec<CommonPrefix>,
     marker: String,
     next_marker: Option<String>,
+    next_continuation_token: Option<String>,
+}
+
+/// OutputMarker only reads the next marker of a page, used to skip pages
+/// that can't be parsed.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct OutputMarker {
+    next_marker: Option<String>,
+    next_continuation_token: Option<String>,
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -135,13 +387,478 @@ struct CommonPrefix {
 struct Content {
     key: String,
     size: u64,
+    #[serde(rename = "ETag", default)]
+    etag: Option<String>,
+    owner: Option<OwnerOutput>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct OwnerOutput {
+    #[serde(rename = "ID")]
+    id: String,
+    display_name: String,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct VersionsOutput {
+    name: String,
+    prefix: String,
+    is_truncated: bool,
+    next_key_marker: Option<String>,
+    next_version_id_marker: Option<String>,
+    #[serde(rename = "Version")]
+    versions: Vec<Version>,
+    #[serde(rename = "DeleteMarker")]
+    delete_markers: Vec<DeleteMarker>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct Version {
+    key: String,
+    version_id: String,
+    is_latest: bool,
+    last_modified: String,
+    #[serde(rename = "ETag")]
+    etag: String,
+    size: u64,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct DeleteMarker {
+    key: String,
+    version_id: String,
+    is_latest: bool,
+    last_modified: String,
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+
     use bytes::Buf;
+    use futures::TryStreamExt;
+    use proptest::prelude::*;
+    use wiremock::matchers::method;
+    use wiremock::matchers::query_param;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::Respond;
+    use wiremock::ResponseTemplate;
 
     use super::*;
+    use crate::services::Obs;
+    use crate::Metakey;
+    use crate::Operator;
+
+    /// MockBucket serves list objects requests from a fixed list of keys
+    /// and records the markers it received.
+    struct MockBucket {
+        keys: Vec<String>,
+        page_size: usize,
+        markers: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Respond for MockBucket {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let mut marker = String::new();
+            let mut prefix = String::new();
+            let mut max_keys = 1000;
+            let mut v2 = false;
+            for (k, v) in req.url.query_pairs() {
+                match k.as_ref() {
+                    // The mock uses the last key as continuation token.
+                    "marker" | "continuation-token" => marker = v.to_string(),
+                    "list-type" => v2 = v == "2",
+                    "prefix" => prefix = v.to_string(),
+                    "max-keys" => max_keys = v.parse().unwrap(),
+                    _ => {}
+                }
+            }
+            self.markers.lock().unwrap().push(marker.clone());
+
+            let page_size = self.page_size.min(max_keys);
+            let remaining: Vec<&String> = self
+                .keys
+                .iter()
+                .filter(|k| **k > marker && k.starts_with(&prefix))
+                .collect();
+            let page = &remaining[..page_size.min(remaining.len())];
+            let is_truncated = page.len() < remaining.len();
+
+            let mut body =
+                String::from(r#"<?xml version="1.0" encoding="UTF-8"?><ListBucketResult>"#);
+            if is_truncated {
+                let tag = if v2 {
+                    "NextContinuationToken"
+                } else {
+                    "NextMarker"
+                };
+                body.push_str(&format!("<{tag}>{}</{tag}>", page[page.len() - 1]));
+            }
+            body.push_str(&format!("<IsTruncated>{is_truncated}</IsTruncated>"));
+            for key in page {
+                body.push_str(&format!(
+                    "<Contents><Key>{key}</Key><Size>1</Size></Contents>"
+                ));
+            }
+            body.push_str("</ListBucketResult>");
+
+            ResponseTemplate::new(200).set_body_string(body)
+        }
+    }
+
+    async fn list_with_mock(
+        count: usize,
+        page_size: usize,
+        limit: Option<usize>,
+    ) -> Result<(Vec<String>, Vec<String>)> {
+        let keys: Vec<String> = (0..count).map(|i| format!("file-{i:04}")).collect();
+        let markers = Arc::new(Mutex::new(vec![]));
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(MockBucket {
+                keys,
+                page_size,
+                markers: markers.clone(),
+            })
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = Obs::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .bucket("test")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key");
+        let op = Operator::new(builder)?.finish();
+
+        let mut fut = op.list_with("");
+        if let Some(limit) = limit {
+            fut = fut.limit(limit);
+        }
+        let entries: Vec<String> = fut
+            .await?
+            .map_ok(|entry| entry.path().to_string())
+            .try_collect()
+            .await?;
+
+        let markers = markers.lock().unwrap().clone();
+        Ok((entries, markers))
+    }
+
+    #[tokio::test]
+    async fn test_list_pagination() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let (entries, markers) = list_with_mock(5, 1, None).await?;
+
+        assert_eq!(
+            entries,
+            [
+                "file-0000",
+                "file-0001",
+                "file-0002",
+                "file-0003",
+                "file-0004"
+            ]
+        );
+        assert_eq!(
+            markers,
+            ["", "file-0000", "file-0001", "file-0002", "file-0003"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_pagination_with_limit() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let (entries, markers) = list_with_mock(5, 1000, Some(2)).await?;
+
+        assert_eq!(entries.len(), 5);
+        assert_eq!(markers, ["", "file-0001", "file-0003"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_skip_page() -> Result<()> {
+        let keys: Vec<String> = (0..8).map(|i| format!("file-{i:04}")).collect();
+        let markers = Arc::new(Mutex::new(vec![]));
+
+        let mock_server = MockServer::start().await;
+        // The second page can't be parsed, and will be skipped.
+        Mock::given(method("GET"))
+            .and(query_param("continuation-token", "file-0001"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<?xml version="1.0" encoding="UTF-8"?><ListBucketResult><NextContinuationToken>file-0003</NextContinuationToken><IsTruncated>true</IsTruncated><Contents><Key>file-0002</Key><Size>invalid</Size></Contents></ListBucketResult>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+        // The third page fails once.
+        Mock::given(method("GET"))
+            .and(query_param("continuation-token", "file-0003"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        // The fourth page always fails.
+        Mock::given(method("GET"))
+            .and(query_param("continuation-token", "file-0005"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(MockBucket {
+                keys,
+                page_size: 2,
+                markers: markers.clone(),
+            })
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = Obs::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .bucket("test")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key");
+        let op = Operator::new(builder)?.finish();
+
+        let mut lister = op.list_with("").error_policy(ErrorPolicy::SkipPage).await?;
+        let mut entries = vec![];
+        let err = loop {
+            match lister.try_next().await {
+                Ok(Some(entry)) => entries.push(entry.path().to_string()),
+                Ok(None) => panic!("list must fail"),
+                Err(err) => break err,
+            }
+        };
+
+        // Entries of the second page are skipped, the failed request of
+        // the third page is sent again, and the error of the fourth page
+        // is returned after it failed twice.
+        assert_eq!(
+            entries,
+            ["file-0000", "file-0001", "file-0004", "file-0005"]
+        );
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert_eq!(markers.lock().unwrap().clone(), ["", "file-0003"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_with_prefix_filter() -> Result<()> {
+        let keys: Vec<String> = ["dir/a-1", "dir/a-2", "dir/b-1", "other"]
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(MockBucket {
+                keys,
+                page_size: 1000,
+                markers: Arc::new(Mutex::new(vec![])),
+            })
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = Obs::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .bucket("test")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key");
+        let backend = crate::Builder::build(&mut builder)?;
+
+        let list = |pager: ObsPager| async move {
+            let mut pager = pager;
+            let mut paths = vec![];
+            while let Some(entries) = oio::Page::next(&mut pager).await? {
+                paths.extend(entries.into_iter().map(|v| v.path().to_string()));
+            }
+            Ok::<_, Error>(paths)
+        };
+
+        let args = OpList::new().with_delimiter("");
+        let paths = list(ObsPager::new(backend.core.clone(), "dir/", &args)).await?;
+        assert_eq!(paths, ["dir/a-1", "dir/a-2", "dir/b-1"]);
+
+        let pager =
+            ObsPager::new(backend.core.clone(), "dir/", &args).with_prefix_filter("dir/a")?;
+        let paths = list(pager).await?;
+        assert_eq!(paths, ["dir/a-1", "dir/a-2"]);
+
+        let err = ObsPager::new(backend.core.clone(), "dir/", &args)
+            .with_prefix_filter("other")
+            .err()
+            .expect("prefix outside of path must fail");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        // Prefix filter is exposed via operator's list builder.
+        let op = Operator::new(builder)?.finish();
+        let paths: Vec<String> = op
+            .list_with("dir/")
+            .delimiter("")
+            .prefix_filter("dir/b")
+            .await?
+            .map_ok(|v| v.path().to_string())
+            .try_collect()
+            .await?;
+        assert_eq!(paths, ["dir/b-1"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_content_md5() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult>
+    <IsTruncated>false</IsTruncated>
+    <Contents>
+        <Key>a</Key>
+        <ETag>"a72e382246ac83e86bd203389849e71d"</ETag>
+        <Size>9</Size>
+    </Contents>
+    <Contents>
+        <Key>b</Key>
+        <ETag>"a72e382246ac83e86bd203389849e71d-2"</ETag>
+        <Size>9</Size>
+    </Contents>
+    <Contents>
+        <Key>c</Key>
+        <Size>9</Size>
+    </Contents>
+</ListBucketResult>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let list = |builder: &mut Obs| {
+            builder
+                .endpoint(&mock_server.uri())
+                .bucket("test")
+                .access_key_id("access_key_id")
+                .secret_access_key("secret_access_key");
+            let backend = crate::Builder::build(builder);
+            async move {
+                let mut pager = ObsPager::new(backend?.core, "", &OpList::new());
+                let entries = oio::Page::next(&mut pager).await?.unwrap_or_default();
+                Ok::<_, Error>(
+                    entries
+                        .iter()
+                        .map(|v| {
+                            let meta = v.metadata();
+                            (
+                                v.path().to_string(),
+                                meta.bit()
+                                    .contains(Metakey::Etag)
+                                    .then(|| meta.etag().unwrap_or_default().to_string()),
+                                meta.bit()
+                                    .contains(Metakey::ContentMd5)
+                                    .then(|| meta.content_md5().unwrap_or_default().to_string()),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            }
+        };
+
+        let etag = r#""a72e382246ac83e86bd203389849e71d""#.to_string();
+        let md5 = "a72e382246ac83e86bd203389849e71d".to_string();
+        let multipart_etag = r#""a72e382246ac83e86bd203389849e71d-2""#.to_string();
+        let entries = list(&mut Obs::default()).await?;
+        assert_eq!(
+            entries,
+            [
+                ("a".to_string(), Some(etag.clone()), Some(md5)),
+                ("b".to_string(), Some(multipart_etag.clone()), None),
+                ("c".to_string(), None, None),
+            ]
+        );
+
+        // Etags of files encrypted with SSE-C are not md5 of the content.
+        let entries =
+            list(Obs::default().server_side_encryption_with_customer_key(&[0; 32])).await?;
+        assert_eq!(
+            entries,
+            [
+                ("a".to_string(), Some(etag), None),
+                ("b".to_string(), Some(multipart_etag), None),
+                ("c".to_string(), None, None),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_v1_fallback() -> Result<()> {
+        let keys: Vec<String> = (0..5).map(|i| format!("file-{i:04}")).collect();
+        let markers = Arc::new(Mutex::new(vec![]));
+
+        // Simulate an old server which doesn't support list objects v2.
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(query_param("list-type", "2"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(MockBucket {
+                keys,
+                page_size: 2,
+                markers: markers.clone(),
+            })
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = Obs::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .bucket("test")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key");
+        let op = Operator::new(builder.clone())?.finish();
+        let res: Result<Vec<_>> = op.list("").await?.try_collect().await;
+        assert!(res.is_err(), "list objects v2 must be used by default");
+
+        builder.disable_list_objects_v2();
+        let op = Operator::new(builder)?.finish();
+        let entries: Vec<String> = op
+            .list("")
+            .await?
+            .map_ok(|entry| entry.path().to_string())
+            .try_collect()
+            .await?;
+        assert_eq!(entries.len(), 5);
+        assert_eq!(
+            markers.lock().unwrap().clone(),
+            ["", "file-0001", "file-0003"]
+        );
+        Ok(())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn test_list_pagination_collects_all(count in 1usize..=500, page_size in 1usize..=100) {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            let (entries, markers) = rt.block_on(list_with_mock(count, page_size, None)).unwrap();
+
+            prop_assert_eq!(entries.len(), count);
+            prop_assert_eq!(markers.len(), (count + page_size - 1) / page_size);
+            prop_assert!(markers.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
 
     #[test]
     fn test_parse_xml() {
@@ -171,6 +888,7 @@ mod tests {
         <Size>10</Size>
         <Owner>
             <ID>b4bf1b36d9ca43d984fbcb9491b6fce9</ID>
+            <DisplayName>team-a</DisplayName>
         </Owner>
         <StorageClass>STANDARD</StorageClass>
     </Contents>
@@ -199,6 +917,19 @@ mod tests {
             out.contents.iter().map(|v| v.size).collect::<Vec<u64>>(),
             [9, 10],
         );
+        assert_eq!(
+            out.contents
+                .iter()
+                .map(|v| {
+                    let owner = v.owner.as_ref().expect("owner must exist");
+                    (owner.id.as_str(), owner.display_name.as_str())
+                })
+                .collect::<Vec<_>>(),
+            [
+                ("b4bf1b36d9ca43d984fbcb9491b6fce9", ""),
+                ("b4bf1b36d9ca43d984fbcb9491b6fce9", "team-a")
+            ],
+        );
         assert_eq!(
             out.common_prefixes
                 .iter()
@@ -207,4 +938,88 @@ mod tests {
             ["hello", "world"],
         )
     }
+
+    #[test]
+    fn test_parse_versions_xml() {
+        let bs = bytes::Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<ListVersionsResult xmlns="http://obs.myhwclouds.com/doc/2015-06-30/">
+    <Name>bucket02</Name>
+    <Prefix>obj</Prefix>
+    <KeyMarker></KeyMarker>
+    <VersionIdMarker></VersionIdMarker>
+    <NextKeyMarker>object001</NextKeyMarker>
+    <NextVersionIdMarker>00011000000000013F16000001643A22</NextVersionIdMarker>
+    <MaxKeys>2</MaxKeys>
+    <IsTruncated>true</IsTruncated>
+    <Version>
+        <Key>object001</Key>
+        <VersionId>00011000000000013F16000001643A22</VersionId>
+        <IsLatest>true</IsLatest>
+        <LastModified>2015-07-01T00:32:16.482Z</LastModified>
+        <ETag>"2fa3bcaaec668adc5da177e67a122d7c"</ETag>
+        <Size>12041</Size>
+        <Owner>
+            <ID>ce2c2d65fa3d4d9b8ccbf4c0a3c0b5e6</ID>
+        </Owner>
+        <StorageClass>STANDARD</StorageClass>
+    </Version>
+    <DeleteMarker>
+        <Key>object001</Key>
+        <VersionId>00011000000000013F16000001643A21</VersionId>
+        <IsLatest>false</IsLatest>
+        <LastModified>2015-07-01T00:31:16.482Z</LastModified>
+        <Owner>
+            <ID>ce2c2d65fa3d4d9b8ccbf4c0a3c0b5e6</ID>
+        </Owner>
+    </DeleteMarker>
+    <Version>
+        <Key>object001</Key>
+        <VersionId>00011000000000013F16000001643A20</VersionId>
+        <IsLatest>false</IsLatest>
+        <LastModified>2015-07-01T00:30:16.482Z</LastModified>
+        <ETag>"2fa3bcaaec668adc5da177e67a122d7b"</ETag>
+        <Size>100</Size>
+        <Owner>
+            <ID>ce2c2d65fa3d4d9b8ccbf4c0a3c0b5e6</ID>
+        </Owner>
+        <StorageClass>STANDARD</StorageClass>
+    </Version>
+</ListVersionsResult>"#,
+        );
+        let out: VersionsOutput = de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(out.name, "bucket02".to_string());
+        assert_eq!(out.prefix, "obj".to_string());
+        assert!(out.is_truncated);
+        assert_eq!(out.next_key_marker, Some("object001".to_string()));
+        assert_eq!(
+            out.next_version_id_marker,
+            Some("00011000000000013F16000001643A22".to_string())
+        );
+        assert_eq!(
+            out.versions
+                .iter()
+                .map(|v| v.version_id.clone())
+                .collect::<Vec<String>>(),
+            [
+                "00011000000000013F16000001643A22",
+                "00011000000000013F16000001643A20"
+            ],
+        );
+        assert!(out.versions[0].is_latest);
+        assert_eq!(
+            out.versions[0].etag,
+            r#""2fa3bcaaec668adc5da177e67a122d7c""#
+        );
+        assert_eq!(
+            out.versions.iter().map(|v| v.size).collect::<Vec<u64>>(),
+            [12041, 100],
+        );
+        assert_eq!(out.delete_markers.len(), 1);
+        assert_eq!(
+            out.delete_markers[0].version_id,
+            "00011000000000013F16000001643A21"
+        );
+    }
 }
@@ -0,0 +1,194 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::min;
+use std::io;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+use bytes::BytesMut;
+use futures::ready;
+
+use crate::raw::*;
+use crate::*;
+
+/// ObsReader counts the bytes read from response body.
+///
+/// If `buffer_size` is set, ObsReader will collect chunks from the body
+/// until at least `buffer_size` bytes are buffered before returning them.
+pub struct ObsReader {
+    inner: IncomingAsyncBody,
+    bytes_read: Arc<AtomicU64>,
+
+    buffer_size: Option<usize>,
+    buf: BytesMut,
+    /// Bytes that have been buffered but not consumed by `poll_read`.
+    chunk: Bytes,
+    eof: bool,
+}
+
+impl ObsReader {
+    pub fn new(inner: IncomingAsyncBody, bytes_read: Arc<AtomicU64>) -> Self {
+        Self {
+            inner,
+            bytes_read,
+            buffer_size: None,
+            buf: BytesMut::new(),
+            chunk: Bytes::new(),
+            eof: false,
+        }
+    }
+
+    /// Set the buffer size of this reader.
+    pub fn with_buffer_size(mut self, buffer_size: Option<usize>) -> Self {
+        self.buffer_size = buffer_size.filter(|v| *v > 0);
+        self
+    }
+
+    fn poll_inner_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        let res = oio::Read::poll_next(&mut self.inner, cx);
+        if let Poll::Ready(Some(Ok(bs))) = &res {
+            self.bytes_read
+                .fetch_add(bs.len() as u64, Ordering::Relaxed);
+        }
+        res
+    }
+
+    /// Fill the buffer until it reaches `buffer_size` or the body reaches
+    /// the end, and return the buffered bytes.
+    fn poll_fill(&mut self, cx: &mut Context<'_>, size: usize) -> Poll<Option<Result<Bytes>>> {
+        while !self.eof && self.buf.len() < size {
+            match ready!(self.poll_inner_next(cx)) {
+                Some(Ok(bs)) => self.buf.extend_from_slice(&bs),
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                None => self.eof = true,
+            }
+        }
+
+        if self.buf.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Ready(Some(Ok(self.buf.split().freeze())))
+        }
+    }
+}
+
+impl oio::Read for ObsReader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let size = match self.buffer_size {
+            Some(size) => size,
+            None => {
+                let res = self.inner.poll_read(cx, buf);
+                if let Poll::Ready(Ok(n)) = &res {
+                    self.bytes_read.fetch_add(*n as u64, Ordering::Relaxed);
+                }
+                return res;
+            }
+        };
+
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        if self.chunk.is_empty() {
+            self.chunk = match ready!(self.poll_fill(cx, size)) {
+                Some(Ok(bs)) => bs,
+                Some(Err(err)) => return Poll::Ready(Err(err)),
+                None => return Poll::Ready(Ok(0)),
+            };
+        }
+
+        let n = min(buf.len(), self.chunk.len());
+        buf[..n].copy_from_slice(&self.chunk.split_to(n));
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: io::SeekFrom) -> Poll<Result<u64>> {
+        self.inner.poll_seek(cx, pos)
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        match self.buffer_size {
+            Some(size) => {
+                if !self.chunk.is_empty() {
+                    return Poll::Ready(Some(Ok(std::mem::take(&mut self.chunk))));
+                }
+                self.poll_fill(cx, size)
+            }
+            None => self.poll_inner_next(cx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oio::ReadExt;
+
+    use super::*;
+
+    fn new_reader(buffer_size: Option<usize>) -> ObsReader {
+        let chunks = (0..10).map(|i| Ok(Bytes::from(vec![i as u8; 3])));
+        let body = IncomingAsyncBody::new(
+            Box::new(oio::into_stream::from_futures_stream(
+                futures::stream::iter(chunks),
+            )),
+            None,
+        );
+        ObsReader::new(body, Arc::new(AtomicU64::new(0))).with_buffer_size(buffer_size)
+    }
+
+    #[tokio::test]
+    async fn test_read_with_buffer_size() -> Result<()> {
+        let mut r = new_reader(Some(8));
+
+        let mut sizes = vec![];
+        while let Some(bs) = r.next().await {
+            sizes.push(bs?.len());
+        }
+        assert_eq!(sizes, vec![9, 9, 9, 3]);
+        assert_eq!(r.bytes_read.load(Ordering::Relaxed), 30);
+
+        let mut r = new_reader(Some(8));
+        let mut content = vec![];
+        let mut buf = [0; 4];
+        loop {
+            let n = r.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            content.extend_from_slice(&buf[..n]);
+        }
+        let expected: Vec<u8> = (0..10).flat_map(|i| vec![i as u8; 3]).collect();
+        assert_eq!(content, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_without_buffer_size() -> Result<()> {
+        let mut r = new_reader(None);
+
+        let mut sizes = vec![];
+        while let Some(bs) = r.next().await {
+            sizes.push(bs?.len());
+        }
+        assert_eq!(sizes, vec![3; 10]);
+        Ok(())
+    }
+}
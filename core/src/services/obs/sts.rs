@@ -0,0 +1,411 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
+use http::header::CONTENT_TYPE;
+use http::header::HOST;
+use http::Request;
+use http::StatusCode;
+use reqsign::HuaweicloudObsCredential;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use tokio::sync::RwLock;
+
+use super::error::parse_error;
+use crate::raw::*;
+use crate::*;
+
+/// Default endpoint of Huawei Cloud IAM service.
+pub const DEFAULT_IAM_ENDPOINT: &str = "https://iam.myhuaweicloud.com";
+
+/// Duration of the assumed credentials in seconds.
+const DURATION_SECONDS: u64 = 3600;
+
+/// Credentials will be refreshed if they will expire in this duration.
+const REFRESH_AHEAD_MINUTES: i64 = 5;
+
+const X_SDK_DATE: &str = "x-sdk-date";
+const X_SECURITY_TOKEN: &str = "x-security-token";
+
+/// StsCredentials is the temporary credentials returned by `AssumeRole`.
+#[derive(Clone)]
+pub struct StsCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub security_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Debug for StsCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StsCredentials")
+            .field("access_key_id", &"<redacted>")
+            .field("secret_access_key", &"<redacted>")
+            .field("security_token", &"<redacted>")
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+impl StsCredentials {
+    fn is_valid(&self) -> bool {
+        self.expires_at - chrono::Duration::minutes(REFRESH_AHEAD_MINUTES) >= Utc::now()
+    }
+}
+
+impl From<&StsCredentials> for HuaweicloudObsCredential {
+    fn from(v: &StsCredentials) -> Self {
+        HuaweicloudObsCredential {
+            access_key_id: v.access_key_id.clone(),
+            secret_access_key: v.secret_access_key.clone(),
+            security_token: Some(v.security_token.clone()),
+        }
+    }
+}
+
+/// StsLoader assumes the IAM role (which is called agency in Huawei Cloud)
+/// and caches the temporary credentials.
+///
+/// Credentials will be refreshed while loading if they will expire in 5
+/// minutes, so the backend keeps working after the first credentials expired.
+pub struct StsLoader {
+    client: HttpClient,
+    endpoint: String,
+    domain_id: String,
+    agency_name: String,
+
+    credentials: Arc<RwLock<Option<StsCredentials>>>,
+}
+
+impl Debug for StsLoader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StsLoader")
+            .field("endpoint", &self.endpoint)
+            .field("domain_id", &self.domain_id)
+            .field("agency_name", &self.agency_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StsLoader {
+    /// Create a new loader for given role.
+    ///
+    /// `role_arn` is in the format of `iam::<domain_id>:agency:<agency_name>`.
+    pub fn new(client: HttpClient, endpoint: &str, role_arn: &str) -> Result<Self> {
+        let (domain_id, agency_name) = parse_role_arn(role_arn).ok_or_else(|| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "iam_role must be in format of iam::<domain_id>:agency:<agency_name>",
+            )
+            .with_context("service", Scheme::Obs)
+            .with_context("iam_role", role_arn)
+        })?;
+
+        Ok(Self {
+            client,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            domain_id,
+            agency_name,
+            credentials: Arc::default(),
+        })
+    }
+
+    /// Load the assumed credentials, `base` will be used to call `AssumeRole`
+    /// if the cached credentials are missing or expiring.
+    pub async fn load(&self, base: &HuaweicloudObsCredential) -> Result<HuaweicloudObsCredential> {
+        if let Some(cred) = self.credentials.read().await.as_ref() {
+            if cred.is_valid() {
+                return Ok(cred.into());
+            }
+        }
+
+        let mut guard = self.credentials.write().await;
+        // Others could have refreshed the credentials while we are waiting.
+        if let Some(cred) = guard.as_ref() {
+            if cred.is_valid() {
+                return Ok(cred.into());
+            }
+        }
+
+        let cred = self.assume_role(base).await?;
+        let out = (&cred).into();
+        *guard = Some(cred);
+        Ok(out)
+    }
+
+    async fn assume_role(&self, base: &HuaweicloudObsCredential) -> Result<StsCredentials> {
+        let url = format!("{}/v3.0/OS-CREDENTIAL/securitytokens", self.endpoint);
+
+        let body = serde_json::to_vec(&AssumeRoleRequest {
+            auth: AssumeRoleAuth {
+                identity: AssumeRoleIdentity {
+                    methods: vec!["assume_role"],
+                    assume_role: AssumeRoleParams {
+                        domain_id: &self.domain_id,
+                        agency_name: &self.agency_name,
+                        duration_seconds: DURATION_SECONDS,
+                    },
+                },
+            },
+        })
+        .map_err(new_json_serialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_TYPE, "application/json;charset=utf8")
+            .body(body)
+            .map_err(new_request_build_error)?;
+        sign_sdk_request(&mut req, base, Utc::now())?;
+
+        let (parts, body) = req.into_parts();
+        let req = Request::from_parts(parts, AsyncBody::Bytes(body.into()));
+
+        let resp = self.client.send(req).await?;
+        if resp.status() != StatusCode::CREATED && resp.status() != StatusCode::OK {
+            return Err(parse_error(resp)
+                .await?
+                .with_operation("StsLoader::assume_role")
+                .with_context("agency_name", &self.agency_name));
+        }
+
+        let bs = resp.into_body().bytes().await?;
+        let out: AssumeRoleResponse =
+            serde_json::from_slice(&bs).map_err(new_json_deserialize_error)?;
+
+        Ok(StsCredentials {
+            access_key_id: out.credential.access,
+            secret_access_key: out.credential.secret,
+            security_token: out.credential.securitytoken,
+            expires_at: parse_datetime_from_rfc3339(&out.credential.expires_at)?,
+        })
+    }
+}
+
+/// Parse `iam::<domain_id>:agency:<agency_name>` into `(domain_id, agency_name)`.
+fn parse_role_arn(role_arn: &str) -> Option<(String, String)> {
+    let (domain_id, agency_name) = role_arn.strip_prefix("iam::")?.split_once(":agency:")?;
+    if domain_id.is_empty() || agency_name.is_empty() {
+        return None;
+    }
+
+    Some((domain_id.to_string(), agency_name.to_string()))
+}
+
+/// Sign the request to Huawei Cloud API with `SDK-HMAC-SHA256`.
+///
+/// Reference: <https://support.huaweicloud.com/intl/en-us/devg-apisign/api-sign-algorithm-002.html>
+fn sign_sdk_request(
+    req: &mut Request<Vec<u8>>,
+    cred: &HuaweicloudObsCredential,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let host = req
+        .uri()
+        .authority()
+        .map(|v| v.as_str().to_string())
+        .unwrap_or_default();
+    let headers = req.headers_mut();
+    headers.insert(HOST, build_header_value(&host)?);
+    headers.insert(
+        X_SDK_DATE,
+        build_header_value(&now.format("%Y%m%dT%H%M%SZ").to_string())?,
+    );
+    if let Some(token) = &cred.security_token {
+        headers.insert(X_SECURITY_TOKEN, build_header_value(token)?);
+    }
+
+    let mut signed: Vec<(String, String)> = req
+        .headers()
+        .iter()
+        .map(|(k, v)| {
+            (
+                k.as_str().to_lowercase(),
+                String::from_utf8_lossy(v.as_bytes()).trim().to_string(),
+            )
+        })
+        .collect();
+    signed.sort();
+    let signed_headers = signed
+        .iter()
+        .map(|(k, _)| k.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let mut path = req.uri().path().to_string();
+    if !path.ends_with('/') {
+        path.push('/');
+    }
+
+    let mut canonical_request = format!(
+        "{}\n{}\n{}\n",
+        req.method(),
+        path,
+        req.uri().query().unwrap_or_default()
+    );
+    for (k, v) in &signed {
+        writeln!(canonical_request, "{k}:{v}").expect("write into string must succeed");
+    }
+    write!(
+        canonical_request,
+        "\n{}\n{}",
+        signed_headers,
+        hex_sha256(req.body())
+    )
+    .expect("write into string must succeed");
+
+    let string_to_sign = format!(
+        "SDK-HMAC-SHA256\n{}\n{}",
+        now.format("%Y%m%dT%H%M%SZ"),
+        hex_sha256(canonical_request.as_bytes())
+    );
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(cred.secret_access_key.as_bytes())
+        .expect("hmac can take key of any size");
+    mac.update(string_to_sign.as_bytes());
+    let signature = to_hex(&mac.finalize().into_bytes());
+
+    let authorization = format!(
+        "SDK-HMAC-SHA256 Access={}, SignedHeaders={}, Signature={}",
+        cred.access_key_id, signed_headers, signature
+    );
+    let mut value = build_header_value(&authorization)?;
+    value.set_sensitive(true);
+    req.headers_mut().insert(http::header::AUTHORIZATION, value);
+
+    Ok(())
+}
+
+fn hex_sha256(bs: &[u8]) -> String {
+    to_hex(&Sha256::digest(bs))
+}
+
+fn to_hex(bs: &[u8]) -> String {
+    bs.iter()
+        .fold(String::with_capacity(bs.len() * 2), |mut s, b| {
+            write!(s, "{b:02x}").expect("write into string must succeed");
+            s
+        })
+}
+
+#[derive(Serialize)]
+struct AssumeRoleRequest<'a> {
+    auth: AssumeRoleAuth<'a>,
+}
+
+#[derive(Serialize)]
+struct AssumeRoleAuth<'a> {
+    identity: AssumeRoleIdentity<'a>,
+}
+
+#[derive(Serialize)]
+struct AssumeRoleIdentity<'a> {
+    methods: Vec<&'static str>,
+    assume_role: AssumeRoleParams<'a>,
+}
+
+#[derive(Serialize)]
+struct AssumeRoleParams<'a> {
+    domain_id: &'a str,
+    agency_name: &'a str,
+    duration_seconds: u64,
+}
+
+#[derive(Deserialize)]
+struct AssumeRoleResponse {
+    credential: AssumeRoleCredential,
+}
+
+#[derive(Deserialize)]
+struct AssumeRoleCredential {
+    access: String,
+    secret: String,
+    securitytoken: String,
+    expires_at: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_role_arn() {
+        let cases = vec![
+            (
+                "iam::0123456789:agency:opendal",
+                Some(("0123456789".to_string(), "opendal".to_string())),
+            ),
+            ("iam::0123456789:agency:", None),
+            ("iam:::agency:opendal", None),
+            ("0123456789:agency:opendal", None),
+            ("iam::0123456789:user:opendal", None),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(parse_role_arn(input), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_sign_sdk_request() -> Result<()> {
+        let cred = HuaweicloudObsCredential {
+            access_key_id: "access_key_id".to_string(),
+            secret_access_key: "secret_access_key".to_string(),
+            security_token: None,
+        };
+        let now = parse_datetime_from_rfc3339("2023-06-01T00:00:00Z")?;
+
+        let mut req =
+            Request::post("https://iam.myhuaweicloud.com/v3.0/OS-CREDENTIAL/securitytokens")
+                .header(CONTENT_TYPE, "application/json;charset=utf8")
+                .body(b"{}".to_vec())
+                .expect("request must build succeed");
+        sign_sdk_request(&mut req, &cred, now)?;
+
+        assert_eq!(req.headers()[X_SDK_DATE], "20230601T000000Z");
+        assert_eq!(req.headers()[HOST], "iam.myhuaweicloud.com");
+        let authorization = req.headers()[http::header::AUTHORIZATION]
+            .to_str()
+            .expect("must be valid string");
+        assert!(authorization.starts_with(
+            "SDK-HMAC-SHA256 Access=access_key_id, SignedHeaders=content-type;host;x-sdk-date, Signature="
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_credentials_is_valid() {
+        let mut cred = StsCredentials {
+            access_key_id: "ak".to_string(),
+            secret_access_key: "sk".to_string(),
+            security_token: "token".to_string(),
+            expires_at: Utc::now() + chrono::Duration::hours(1),
+        };
+        assert!(cred.is_valid());
+
+        cred.expires_at = Utc::now() + chrono::Duration::minutes(3);
+        assert!(!cred.is_valid());
+    }
+}
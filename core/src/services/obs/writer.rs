@@ -15,14 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::cmp::max;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Buf;
 use bytes::Bytes;
+use futures::StreamExt;
 use http::StatusCode;
+use log::warn;
 
-use super::core::ObsCore;
+use super::core::*;
 use super::error::parse_error;
+use super::error::with_request_context;
 use crate::raw::*;
 use crate::*;
 
@@ -31,54 +37,551 @@ pub struct ObsWriter {
 
     op: OpWrite,
     path: String,
+
+    /// Writes larger than this will be switched to multipart upload.
+    ///
+    /// It's the `multipart_threshold` of op if given, but never exceeds
+    /// [`MAX_SINGLE_PUT_SIZE`].
+    max_single_put_size: u64,
+    upload_id: Option<String>,
+    parts: Vec<CompleteMultipartUploadRequestPart>,
+    buffer: oio::VectorCursor,
 }
 
 impl ObsWriter {
     pub fn new(core: Arc<ObsCore>, op: OpWrite, path: String) -> Self {
-        ObsWriter { core, op, path }
+        let max_single_put_size = op
+            .multipart_threshold()
+            .map_or(MAX_SINGLE_PUT_SIZE, |v| v.min(MAX_SINGLE_PUT_SIZE));
+
+        ObsWriter {
+            core,
+            op,
+            path,
+
+            max_single_put_size,
+            upload_id: None,
+            parts: vec![],
+            buffer: oio::VectorCursor::new(),
+        }
+    }
+
+    /// Whether this write is larger than a single PUT allows.
+    fn is_multipart(&self) -> bool {
+        self.op.content_length().unwrap_or_default() > self.max_single_put_size
+    }
+
+    /// Part size of multipart upload, all parts except the last one will
+    /// have this size.
+    ///
+    /// OBS allows at most 10000 parts, so the part size grows with the
+    /// content length.
+    fn part_size(&self) -> usize {
+        let size = self.op.content_length().unwrap_or_default();
+        max(
+            MIN_PART_SIZE,
+            (size + MAX_MULTIPART_PARTS - 1) / MAX_MULTIPART_PARTS,
+        ) as usize
     }
 
     async fn write_oneshot(&self, size: u64, body: AsyncBody) -> Result<()> {
-        let mut req = self.core.obs_put_object_request(
+        let mut req = self
+            .core
+            .obs_put_object_request(&self.path, Some(size), &self.op, body)?;
+
+        self.core.sign(&mut req).await?;
+
+        let resp = self.core.send(Operation::Write, &self.path, req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                self.core.bytes_written.fetch_add(size, Ordering::Relaxed);
+                Ok(())
+            }
+            _ => Err(with_request_context(
+                parse_error(resp).await?,
+                self.op.request_context(),
+            )),
+        }
+    }
+
+    async fn initiate_upload(&self) -> Result<String> {
+        if self.op.expected_checksum().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "obs doesn't support expected checksum for multipart upload",
+            )
+            .with_operation(Operation::Write)
+            .with_context("path", &self.path));
+        }
+        if self.op.if_none_match().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "obs doesn't support if none match for multipart upload",
+            )
+            .with_operation(Operation::Write)
+            .with_context("path", &self.path));
+        }
+
+        let resp = self
+            .core
+            .obs_initiate_multipart_upload(
+                &self.path,
+                self.op.content_type(),
+                self.op.content_encoding(),
+                self.op.cache_control(),
+            )
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+                Ok(result.upload_id)
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        bs: Bytes,
+    ) -> Result<CompleteMultipartUploadRequestPart> {
+        // OBS requires part number must between [1..=10000]
+        let part_number = self.parts.len() + 1;
+        let size = bs.len() as u64;
+
+        let mut req = self.core.obs_upload_part_request(
             &self.path,
+            upload_id,
+            part_number,
             Some(size),
-            self.op.content_type(),
-            self.op.cache_control(),
-            body,
+            AsyncBody::Bytes(bs),
         )?;
 
         self.core.sign(&mut req).await?;
 
-        let resp = self.core.send(req).await?;
+        let resp = self.core.send(Operation::Write, &self.path, req).await?;
 
         let status = resp.status();
 
         match status {
-            StatusCode::CREATED | StatusCode::OK => {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
                 resp.into_body().consume().await?;
+                self.core.bytes_written.fetch_add(size, Ordering::Relaxed);
+
+                Ok(CompleteMultipartUploadRequestPart { part_number, etag })
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    /// Abort the in-progress multipart upload after a failure that can't be
+    /// retried, so that uploaded parts don't accumulate in the bucket.
+    ///
+    /// The original error will be returned, errors of abort are only logged.
+    async fn abort_on_error(&mut self, err: Error) -> Error {
+        if let Err(abort_err) = oio::Write::abort(self).await {
+            warn!(
+                "abort multipart upload of {} failed: {abort_err}",
+                self.path
+            );
+        }
+        err
+    }
+
+    /// Upload all buffered data as parts and complete the multipart upload.
+    async fn complete_upload(&mut self, upload_id: &str) -> Result<()> {
+        // Make sure internal buffer has been flushed.
+        let part_size = self.part_size();
+        while !self.buffer.is_empty() {
+            let size = self.buffer.len().min(part_size);
+            let bs = self.buffer.peak_exact(size);
+
+            let part = self.write_part(upload_id, bs).await?;
+            self.buffer.take(size);
+            self.parts.push(part);
+        }
+
+        let resp = self
+            .core
+            .obs_complete_multipart_upload(&self.path, upload_id, &self.parts)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+
                 Ok(())
             }
             _ => Err(parse_error(resp).await?),
         }
     }
+
+    async fn write_multipart(&mut self, bs: Bytes) -> Result<()> {
+        let upload_id = match &self.upload_id {
+            Some(upload_id) => upload_id.clone(),
+            None => {
+                let upload_id = self.initiate_upload().await?;
+                self.upload_id = Some(upload_id.clone());
+                upload_id
+            }
+        };
+
+        // Ignore empty bytes
+        if bs.is_empty() {
+            return Ok(());
+        }
+
+        self.buffer.push(bs);
+        let part_size = self.part_size();
+        // Return directly if the buffer is not full
+        if self.buffer.len() < part_size {
+            return Ok(());
+        }
+
+        let bs = self.buffer.peak_exact(part_size);
+        match self.write_part(&upload_id, bs).await {
+            Ok(part) => {
+                self.buffer.take(part_size);
+                self.parts.push(part);
+                Ok(())
+            }
+            Err(e) => {
+                // If the upload fails, we should pop the given bs to make sure
+                // write is re-enter safe.
+                self.buffer.pop();
+                Err(e)
+            }
+        }
+    }
 }
 
 #[async_trait]
 impl oio::Write for ObsWriter {
     async fn write(&mut self, bs: Bytes) -> Result<()> {
+        if self.is_multipart() {
+            // Keep the upload for temporary errors so that write can be retried.
+            return match self.write_multipart(bs).await {
+                Err(err) if !err.is_temporary() => Err(self.abort_on_error(err).await),
+                res => res,
+            };
+        }
+
         self.write_oneshot(bs.len() as u64, AsyncBody::Bytes(bs))
             .await
     }
 
-    async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
-        self.write_oneshot(size, AsyncBody::Stream(s)).await
+    async fn sink(&mut self, size: u64, mut s: oio::Streamer) -> Result<()> {
+        if !self.is_multipart() {
+            return self.write_oneshot(size, AsyncBody::Stream(s)).await;
+        }
+
+        // Data consumed from the stream can't be written again, so the
+        // upload must be aborted on failure.
+        while let Some(res) = s.next().await {
+            if let Err(err) = match res {
+                Ok(bs) => self.write_multipart(bs).await,
+                Err(err) => Err(err),
+            } {
+                return Err(self.abort_on_error(err).await);
+            }
+        }
+        Ok(())
     }
 
     async fn abort(&mut self) -> Result<()> {
-        Ok(())
+        let upload_id = if let Some(upload_id) = &self.upload_id {
+            upload_id
+        } else {
+            return Ok(());
+        };
+
+        let resp = self
+            .core
+            .obs_abort_multipart_upload(&self.path, upload_id)
+            .await?;
+        match resp.status() {
+            // obs returns code 204 if abort succeeds.
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
     }
 
     async fn close(&mut self) -> Result<()> {
+        let upload_id = if let Some(upload_id) = self.upload_id.clone() {
+            upload_id
+        } else {
+            return Ok(());
+        };
+
+        // Keep the upload for temporary errors so that close can be retried.
+        match self.complete_upload(&upload_id).await {
+            Err(err) if !err.is_temporary() => Err(self.abort_on_error(err).await),
+            res => res,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oio::Write;
+    use wiremock::matchers::body_string_contains;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::matchers::query_param;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+    use crate::services::Obs;
+
+    #[test]
+    fn test_part_size() {
+        let core = new_core("http://127.0.0.1:1");
+
+        let w = ObsWriter::new(
+            core.clone(),
+            OpWrite::new().with_content_length(1024),
+            "f".into(),
+        );
+        assert!(!w.is_multipart());
+        assert_eq!(w.part_size(), MIN_PART_SIZE as usize);
+
+        let w = ObsWriter::new(
+            core.clone(),
+            OpWrite::new().with_content_length(MAX_OBJECT_SIZE),
+            "f".into(),
+        );
+        assert!(w.is_multipart());
+        assert_eq!(w.part_size(), MAX_SINGLE_PUT_SIZE as usize);
+
+        let w = ObsWriter::new(
+            core.clone(),
+            OpWrite::new()
+                .with_content_length(1024)
+                .with_multipart_threshold(512),
+            "f".into(),
+        );
+        assert!(w.is_multipart());
+        assert_eq!(w.part_size(), MIN_PART_SIZE as usize);
+
+        // Threshold larger than a single PUT allows should be ignored.
+        let w = ObsWriter::new(
+            core,
+            OpWrite::new()
+                .with_content_length(MAX_SINGLE_PUT_SIZE + 1)
+                .with_multipart_threshold(u64::MAX),
+            "f".into(),
+        );
+        assert!(w.is_multipart());
+    }
+
+    #[tokio::test]
+    async fn test_write_switch_to_multipart() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/file"))
+            .and(query_param("uploads", ""))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<InitiateMultipartUploadResult><UploadId>abc</UploadId></InitiateMultipartUploadResult>",
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/file"))
+            .and(query_param("partNumber", "1"))
+            .and(query_param("uploadId", "abc"))
+            .respond_with(ResponseTemplate::new(200).insert_header("etag", "\"etag-1\""))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/file"))
+            .and(query_param("uploadId", "abc"))
+            .and(body_string_contains("<PartNumber>1</PartNumber>"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let core = new_core(&mock_server.uri());
+        let mut w = ObsWriter::new(core, OpWrite::new().with_content_length(8), "file".into());
+        w.max_single_put_size = 4;
+
+        w.write(Bytes::from("Hello")).await?;
+        w.write(Bytes::from("!!!")).await?;
+        w.close().await
+    }
+
+    async fn mount_initiate_and_upload_part(mock_server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/file"))
+            .and(query_param("uploads", ""))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<InitiateMultipartUploadResult><UploadId>abc</UploadId></InitiateMultipartUploadResult>",
+            ))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/file"))
+            .and(query_param("uploadId", "abc"))
+            .respond_with(ResponseTemplate::new(200).insert_header("etag", "\"etag-1\""))
+            .mount(mock_server)
+            .await;
+    }
+
+    async fn mount_abort(mock_server: &MockServer, times: u64) {
+        Mock::given(method("DELETE"))
+            .and(path("/file"))
+            .and(query_param("uploadId", "abc"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(times)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_write_abort_on_error() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/file"))
+            .and(query_param("uploads", ""))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<InitiateMultipartUploadResult><UploadId>abc</UploadId></InitiateMultipartUploadResult>",
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/file"))
+            .and(query_param("uploadId", "abc"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        mount_abort(&mock_server, 1).await;
+
+        let core = new_core(&mock_server.uri());
+        let op = OpWrite::new()
+            .with_content_length(MIN_PART_SIZE + 1)
+            .with_multipart_threshold(4);
+        let mut w = ObsWriter::new(core, op, "file".into());
+
+        // Write a whole part so that it will be uploaded at once.
+        let bs = Bytes::from(vec![0; MIN_PART_SIZE as usize]);
+        assert!(w.write(bs).await.is_err());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_close_abort_on_error() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mount_initiate_and_upload_part(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("/file"))
+            .and(query_param("uploadId", "abc"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        mount_abort(&mock_server, 1).await;
+
+        let core = new_core(&mock_server.uri());
+        let op = OpWrite::new()
+            .with_content_length(8)
+            .with_multipart_threshold(4);
+        let mut w = ObsWriter::new(core, op, "file".into());
+
+        w.write(Bytes::from("Hello!!!")).await?;
+        assert!(w.close().await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_close_keep_upload_on_temporary_error() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mount_initiate_and_upload_part(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("/file"))
+            .and(query_param("uploadId", "abc"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        mount_abort(&mock_server, 0).await;
+
+        let core = new_core(&mock_server.uri());
+        let op = OpWrite::new()
+            .with_content_length(8)
+            .with_multipart_threshold(4);
+        let mut w = ObsWriter::new(core, op, "file".into());
+
+        w.write(Bytes::from("Hello!!!")).await?;
+        let err = w.close().await.unwrap_err();
+        assert!(err.is_temporary());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sink_abort_on_stream_error() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        mount_initiate_and_upload_part(&mock_server).await;
+        mount_abort(&mock_server, 1).await;
+
+        let core = new_core(&mock_server.uri());
+        let op = OpWrite::new()
+            .with_content_length(8)
+            .with_multipart_threshold(4);
+        let mut w = ObsWriter::new(core, op, "file".into());
+
+        let s = futures::stream::iter(vec![
+            Ok(Bytes::from("Hello")),
+            Err(Error::new(ErrorKind::Unexpected, "stream failed")),
+        ]);
+        let err = w
+            .sink(8, Box::new(oio::into_stream::from_futures_stream(s)))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        Ok(())
+    }
+
+    fn new_core(endpoint: &str) -> Arc<ObsCore> {
+        let mut builder = Obs::default();
+        builder
+            .endpoint(endpoint)
+            .bucket("test")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key");
+        Builder::build(&mut builder)
+            .expect("backend must build succeed")
+            .core
+    }
 }
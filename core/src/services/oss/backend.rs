@@ -414,6 +414,7 @@ impl Accessor for OssBackend {
                 write_without_content_length: true,
                 delete: true,
                 create_dir: true,
+                directory_create: true,
                 copy: true,
 
                 append: true,
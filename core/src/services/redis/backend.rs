@@ -37,7 +37,6 @@ use crate::raw::*;
 use crate::*;
 
 const DEFAULT_REDIS_ENDPOINT: &str = "tcp://127.0.0.1:6379";
-const DEFAULT_REDIS_PORT: u16 = 6379;
 
 /// [Redis](https://redis.io/) services support.
 #[doc = include_str!("docs.md")]
@@ -182,7 +181,9 @@ impl Builder for RedisBuilder {
                     .host()
                     .map(|h| h.to_string())
                     .unwrap_or_else(|| "127.0.0.1".to_string());
-                let port = ep_url.port_u16().unwrap_or(DEFAULT_REDIS_PORT);
+                let port = ep_url
+                    .port_u16()
+                    .unwrap_or_else(|| Scheme::Redis.default_port());
                 ConnectionAddr::Tcp(host, port)
             }
             // TODO: wait for upstream to support `rustls` based TLS connection.
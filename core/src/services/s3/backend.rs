@@ -25,6 +25,7 @@ use async_trait::async_trait;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use bytes::Buf;
+use bytes::Bytes;
 use http::StatusCode;
 use log::debug;
 use log::warn;
@@ -886,7 +887,9 @@ impl Accessor for S3Backend {
                 write_with_cache_control: true,
                 write_with_content_type: true,
                 write_without_content_length: true,
+                write_multipart: true,
                 create_dir: true,
+                directory_create: true,
                 delete: true,
                 copy: true,
 
@@ -991,7 +994,11 @@ impl Accessor for S3Backend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut meta = parse_into_metadata(path, resp.headers())?;
+                parse_into_custom_metadata(resp.headers(), CUSTOM_METADATA_HEADERS, &mut meta)?;
+                Ok(RpStat::new(meta))
+            }
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
@@ -1023,6 +1030,129 @@ impl Accessor for S3Backend {
         ))
     }
 
+    async fn create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let resp = self
+            .core
+            .s3_initiate_multipart_upload(
+                path,
+                args.content_type(),
+                args.content_disposition(),
+                args.cache_control(),
+            )
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+                Ok(RpCreateMultipart::new(&result.upload_id))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        bs: Bytes,
+    ) -> Result<RpWriteMultipart> {
+        let mut req = self.core.s3_upload_part_request(
+            path,
+            args.upload_id(),
+            args.part_number(),
+            Some(args.size()),
+            AsyncBody::Bytes(bs),
+        )?;
+
+        self.core.sign(&mut req).await?;
+
+        let resp = self.core.send(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                resp.into_body().consume().await?;
+
+                Ok(RpWriteMultipart::new(MultipartPart::new(
+                    args.part_number(),
+                    &etag,
+                )))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let parts = args
+            .parts()
+            .iter()
+            .map(|p| CompleteMultipartUploadRequestPart {
+                part_number: p.part_number(),
+                etag: p.etag().to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let resp = self
+            .core
+            .s3_complete_multipart_upload(path, args.upload_id(), &parts)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+
+                Ok(RpCompleteMultipart::new())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self
+            .core
+            .s3_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        match resp.status() {
+            // s3 returns code 204 if abort succeeds.
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                Ok(RpAbortMultipart::new())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         // We will not send this request out, just for signing.
         let mut req = match args.operation() {
@@ -41,6 +41,14 @@ use serde::Serialize;
 use crate::raw::*;
 use crate::*;
 
+/// Backend-specific headers returned by stat which will be kept in custom metadata.
+pub const CUSTOM_METADATA_HEADERS: &[&str] = &[
+    "x-amz-storage-class",
+    "x-amz-replication-status",
+    "x-amz-server-side-encryption",
+    "x-amz-expiration",
+];
+
 mod constants {
     pub const X_AMZ_COPY_SOURCE: &str = "x-amz-copy-source";
 
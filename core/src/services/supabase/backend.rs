@@ -225,6 +225,7 @@ impl Accessor for SupabaseBackend {
 
                 write: true,
                 create_dir: true,
+                directory_create: true,
                 delete: true,
 
                 ..Default::default()
@@ -916,6 +916,7 @@ impl Accessor for WasabiBackend {
 
                 write: true,
                 create_dir: true,
+                directory_create: true,
                 delete: true,
                 copy: true,
                 rename: true,
@@ -0,0 +1,46 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// BucketAcl describes who can access the bucket and what they can do.
+///
+/// All fields of BucketAcl are public and can be accessed directly.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BucketAcl {
+    /// ID of the bucket owner.
+    pub owner: String,
+    /// Permissions granted to users or groups.
+    pub grants: Vec<AclGrant>,
+}
+
+/// AclGrant grants a permission of the bucket to a grantee.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AclGrant {
+    /// Who the permission is granted to.
+    pub grantee: AclGrantee,
+    /// Permission granted, for example, `READ`, `WRITE`, `READ_ACP`,
+    /// `WRITE_ACP` or `FULL_CONTROL`.
+    pub permission: String,
+}
+
+/// AclGrantee is who a permission is granted to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AclGrantee {
+    /// The user with given ID.
+    User(String),
+    /// All users, including anonymous ones.
+    Everyone,
+}
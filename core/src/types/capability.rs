@@ -52,6 +52,9 @@ pub struct Capability {
     pub stat_with_if_match: bool,
     /// If operator supports stat with if none match natively, it will be true.
     pub stat_with_if_none_match: bool,
+    /// If operator supports checking existence without fetching the whole
+    /// metadata, it will be true.
+    pub exists: bool,
 
     /// If operator supports read natively, it will be true.
     pub read: bool,
@@ -87,6 +90,16 @@ pub struct Capability {
     pub write_with_content_disposition: bool,
     /// If operator supports write with cache control natively, it will be true.
     pub write_with_cache_control: bool,
+    /// If operator supports write with if none match natively, it will be true.
+    pub write_with_if_none_match: bool,
+    /// If operator supports write with expected checksum natively, it will be true.
+    pub write_with_expected_checksum: bool,
+
+    /// If operator supports write by multipart upload natively, it will be true.
+    ///
+    /// Services with multipart write can upload parts separately and
+    /// complete them into one file.
+    pub write_multipart: bool,
 
     /// If operator supports append natively, it will be true.
     pub append: bool,
@@ -99,6 +112,14 @@ pub struct Capability {
 
     /// If operator supports create dir natively, it will be true.
     pub create_dir: bool,
+    /// If operator creates dirs by writing empty objects whose paths end
+    /// with `/`, it will be true.
+    ///
+    /// Dirs of these services are virtual: files can be written without
+    /// creating their parent dirs, and dirs exist as long as files under
+    /// them exist. Otherwise, dirs are real and must be created before
+    /// writing files into them if `create_dir` is supported.
+    pub directory_create: bool,
 
     /// If operator supports delete natively, it will be true.
     pub delete: bool,
@@ -109,17 +130,77 @@ pub struct Capability {
     /// If operator supports rename natively, it will be true.
     pub rename: bool,
 
+    /// If operator supports creating symlinks, it will be true.
+    pub symlink: bool,
+
+    /// If operator supports touch natively, it will be true.
+    pub touch: bool,
+
     /// If operator supports list natively, it will be true.
     pub list: bool,
     /// If backend supports list with limit, it will be true.
     pub list_with_limit: bool,
     /// If backend supports list with start after, it will be true.
     pub list_with_start_after: bool,
+    /// If backend supports list with prefix filter, it will be true.
+    pub list_with_prefix_filter: bool,
     /// If backend support list with using slash as delimiter.
     pub list_with_delimiter_slash: bool,
     /// If backend supports list without delimiter.
     pub list_without_delimiter: bool,
 
+    /// If operator supports object versioning natively, it will be true.
+    ///
+    /// Services with versioning can list all versions of objects and
+    /// delete a specified version.
+    ///
+    /// This only means the versioning API is supported by the service. It
+    /// doesn't mean versioning is enabled for current bucket, files of
+    /// buckets without versioning only have one version.
+    pub versioning: bool,
+
+    /// If operator supports lifecycle rules natively, it will be true.
+    ///
+    /// Services with lifecycle can get and set lifecycle rules of the
+    /// bucket, for example, transit or expire objects after given days.
+    pub lifecycle: bool,
+
+    /// If operator supports cors config natively, it will be true.
+    pub cors: bool,
+
+    /// If operator supports object tagging natively, it will be true.
+    ///
+    /// Services with tagging can get and set key-value tags of files.
+    pub tagging: bool,
+
+    /// If operator supports getting restore status of archived files
+    /// natively, it will be true.
+    pub restore_status: bool,
+
+    /// If operator supports importing files from url server-side, it
+    /// will be true.
+    pub upload_from_url: bool,
+
+    /// If operator supports moving files across buckets, it will be true.
+    ///
+    /// Services like `obs` only support it with their default endpoints.
+    pub move_object: bool,
+
+    /// If operator supports concatenating files server-side, it will be
+    /// true.
+    pub concat_objects: bool,
+
+    /// If operator supports updating user metadata of files without
+    /// rewriting the content, it will be true.
+    pub update_metadata: bool,
+
+    /// If operator supports locking files with retention, it will be true.
+    pub object_lock: bool,
+
+    /// If operator supports getting and setting the ACL of the bucket
+    /// natively, it will be true.
+    pub bucket_acl: bool,
+
     /// If operator supports presign natively, it will be true.
     pub presign: bool,
     /// If operator supports presign read natively, it will be true.
@@ -128,6 +209,8 @@ pub struct Capability {
     pub presign_stat: bool,
     /// If operator supports presign write natively, it will be true.
     pub presign_write: bool,
+    /// If operator supports presign post natively, it will be true.
+    pub presign_post: bool,
 
     /// If operator supports batch natively, it will be true.
     pub batch: bool,
@@ -147,6 +230,9 @@ impl Debug for Capability {
         if self.stat {
             s.push("Stat");
         }
+        if self.exists {
+            s.push("Exists");
+        }
         if self.read {
             s.push("Read");
         }
@@ -159,6 +245,9 @@ impl Debug for Capability {
         if self.create_dir {
             s.push("CreateDir");
         }
+        if self.directory_create {
+            s.push("DirectoryCreate");
+        }
         if self.delete {
             s.push("Delete");
         }
@@ -168,9 +257,48 @@ impl Debug for Capability {
         if self.rename {
             s.push("Rename");
         }
+        if self.symlink {
+            s.push("Symlink");
+        }
+        if self.touch {
+            s.push("Touch");
+        }
         if self.list {
             s.push("List");
         }
+        if self.versioning {
+            s.push("Versioning");
+        }
+        if self.lifecycle {
+            s.push("Lifecycle");
+        }
+        if self.cors {
+            s.push("Cors");
+        }
+        if self.tagging {
+            s.push("Tagging");
+        }
+        if self.restore_status {
+            s.push("RestoreStatus");
+        }
+        if self.upload_from_url {
+            s.push("UploadFromUrl");
+        }
+        if self.move_object {
+            s.push("MoveObject");
+        }
+        if self.concat_objects {
+            s.push("ConcatObjects");
+        }
+        if self.update_metadata {
+            s.push("UpdateMetadata");
+        }
+        if self.object_lock {
+            s.push("ObjectLock");
+        }
+        if self.bucket_acl {
+            s.push("BucketAcl");
+        }
         if self.presign {
             s.push("Presign");
         }
@@ -0,0 +1,63 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use base64::engine::general_purpose;
+use base64::Engine;
+
+/// Checksum is the expected digest of the content to be written.
+///
+/// Services will verify the content against it and reject the write
+/// with [`ErrorKind::InvalidData`](crate::ErrorKind::InvalidData) if
+/// mismatched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Checksum {
+    /// MD5 digest of the content.
+    Md5([u8; 16]),
+    /// SHA256 digest of the content.
+    Sha256([u8; 32]),
+}
+
+impl Checksum {
+    /// Get the raw digest of this checksum.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Checksum::Md5(v) => v,
+            Checksum::Sha256(v) => v,
+        }
+    }
+
+    /// Format the digest into base64 which is used in headers like
+    /// `Content-MD5`.
+    pub fn to_base64(&self) -> String {
+        general_purpose::STANDARD.encode(self.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base64() {
+        // MD5 of empty content.
+        let checksum = Checksum::Md5([
+            0xd4, 0x1d, 0x8c, 0xd9, 0x8f, 0x00, 0xb2, 0x04, 0xe9, 0x80, 0x09, 0x98, 0xec, 0xf8,
+            0x42, 0x7e,
+        ]);
+        assert_eq!(checksum.to_base64(), "1B2M2Y8AsgTpgAmY7PhCfg==");
+    }
+}
@@ -0,0 +1,34 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// CorsRule describes which cross-origin requests are allowed by the
+/// service.
+///
+/// All fields of CorsRule are public and can be accessed directly.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CorsRule {
+    /// Origins allowed to access, for example, `https://example.com` or `*`.
+    pub allowed_origins: Vec<String>,
+    /// HTTP methods allowed, for example, `GET` or `PUT`.
+    pub allowed_methods: Vec<String>,
+    /// Headers allowed in the preflight request.
+    pub allowed_headers: Vec<String>,
+    /// Headers in the response that clients are allowed to access.
+    pub expose_headers: Vec<String>,
+    /// Seconds that clients can cache the preflight response.
+    pub max_age_seconds: u32,
+}
@@ -103,6 +103,17 @@ pub enum ErrorKind {
     ///
     /// For example, user try to seek to a negative position
     InvalidInput,
+    /// The quota of this operation has been exceeded.
+    ///
+    /// For example, writing more bytes than [`QuotaLayer`](crate::layers::QuotaLayer) allowed.
+    QuotaExceeded,
+    /// The content is invalid.
+    ///
+    /// For example, the written content doesn't match the expected checksum.
+    InvalidData,
+    /// The file is locked by its retention, it can't be overwritten or
+    /// deleted until the lock expired.
+    ObjectLocked,
 }
 
 impl ErrorKind {
@@ -135,6 +146,9 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::ContentTruncated => "ContentTruncated",
             ErrorKind::ContentIncomplete => "ContentIncomplete",
             ErrorKind::InvalidInput => "InvalidInput",
+            ErrorKind::QuotaExceeded => "QuotaExceeded",
+            ErrorKind::InvalidData => "InvalidData",
+            ErrorKind::ObjectLocked => "ObjectLocked",
         }
     }
 }
@@ -348,6 +362,7 @@ impl From<Error> for io::Error {
             ErrorKind::NotFound => io::ErrorKind::NotFound,
             ErrorKind::PermissionDenied => io::ErrorKind::PermissionDenied,
             ErrorKind::InvalidInput => io::ErrorKind::InvalidInput,
+            ErrorKind::InvalidData => io::ErrorKind::InvalidData,
             _ => io::ErrorKind::Other,
         };
 
@@ -0,0 +1,252 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use futures::TryStreamExt;
+use log::debug;
+#[cfg(not(target_arch = "wasm32"))]
+use log::warn;
+use tokio::sync::Notify;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::task::JoinHandle;
+
+use crate::*;
+
+/// GarbageCollector removes files under given prefix which are older than
+/// `max_age` periodically in background.
+///
+/// It's useful for temporary files like upload buffers and lock files.
+/// Unlike lifecycle rules provided by services, GarbageCollector runs in
+/// current process and works on every service that supports list and
+/// delete.
+///
+/// # Notes
+///
+/// - The age of file is decided by its last modified time. Files without
+///   last modified will be kept.
+/// - GarbageCollector requires a tokio runtime to start, so `start` is not
+///   available on wasm32. Call `run_once` periodically instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use anyhow::Result;
+/// use opendal::GarbageCollector;
+/// use opendal::Operator;
+///
+/// # #[tokio::main]
+/// # async fn test(op: Operator) -> Result<()> {
+/// let gc = GarbageCollector::new(&op, "tmp/", Duration::from_secs(3600))
+///     .with_interval(Duration::from_secs(60));
+/// let handle = gc.start();
+///
+/// // Stop gc while shutting down.
+/// gc.stop();
+/// handle.await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct GarbageCollector {
+    op: Operator,
+    prefix: String,
+    max_age: Duration,
+    interval: Duration,
+
+    stopped: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl GarbageCollector {
+    /// Create a new GarbageCollector which removes files under `prefix`
+    /// older than `max_age`.
+    ///
+    /// The scan interval is `max_age` by default.
+    pub fn new(op: &Operator, prefix: &str, max_age: Duration) -> Self {
+        Self {
+            op: op.clone(),
+            prefix: prefix.to_string(),
+            max_age,
+            interval: max_age,
+
+            stopped: Arc::new(AtomicBool::new(false)),
+            notify: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Set the interval between two scans.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        assert!(!interval.is_zero(), "interval must be greater than 0");
+
+        self.interval = interval;
+        self
+    }
+
+    /// Scan the prefix once and remove all expired files.
+    ///
+    /// Returns the count of removed files.
+    pub async fn run_once(&self) -> Result<usize> {
+        let now = Utc::now();
+        let max_age = chrono::Duration::from_std(self.max_age).map_err(|err| {
+            Error::new(ErrorKind::InvalidInput, "max_age is too large").set_source(err)
+        })?;
+
+        let mut lister = self.op.scan(&self.prefix).await?;
+        let mut removed = 0;
+        while let Some(de) = lister.try_next().await? {
+            let meta = match self
+                .op
+                .metadata(&de, Metakey::Mode | Metakey::LastModified)
+                .await
+            {
+                Ok(meta) => meta,
+                // The file has been removed by others.
+                Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            };
+            if !meta.is_file() {
+                continue;
+            }
+
+            let expired = match meta.last_modified() {
+                Some(v) => now - v > max_age,
+                None => false,
+            };
+            if !expired {
+                continue;
+            }
+
+            debug!("garbage collector removes expired file: {}", de.path());
+            self.op.delete(de.path()).await?;
+            removed += 1;
+        }
+
+        Ok(removed)
+    }
+
+    /// Start the garbage collector in background.
+    ///
+    /// The first scan happens immediately. Errors during scans will be
+    /// logged and retried in next scan.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn start(&self) -> JoinHandle<()> {
+        let gc = self.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(gc.interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                // Create the future before checking the flag so that we
+                // won't miss the notification.
+                let notified = gc.notify.notified();
+                if gc.stopped.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                let tick = interval.tick();
+                futures::pin_mut!(notified, tick);
+                if let futures::future::Either::Left(_) =
+                    futures::future::select(notified, tick).await
+                {
+                    break;
+                }
+
+                if let Err(err) = gc.run_once().await {
+                    warn!("garbage collector scan {} failed: {err}", gc.prefix);
+                }
+            }
+        })
+    }
+
+    /// Stop the garbage collector.
+    ///
+    /// The running scan will be finished before stopping, await the
+    /// handle returned by [`GarbageCollector::start`] to wait for it.
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        self.notify.notify_waiters();
+    }
+}
+
+#[cfg(all(test, feature = "services-fs"))]
+mod tests {
+    use super::*;
+    use crate::services::Fs;
+
+    fn new_operator() -> Operator {
+        let dir = std::env::temp_dir().join(format!("opendal-gc-{}", uuid::Uuid::new_v4()));
+
+        let mut builder = Fs::default();
+        builder.root(dir.to_str().unwrap());
+        Operator::new(builder).unwrap().finish()
+    }
+
+    #[tokio::test]
+    async fn test_run_once() -> Result<()> {
+        let op = new_operator();
+        op.write("tmp/a", "a").await?;
+        op.write("tmp/b/c", "c").await?;
+        op.write("keep", "keep").await?;
+
+        let gc = GarbageCollector::new(&op, "tmp/", Duration::from_secs(3600));
+        assert_eq!(gc.run_once().await?, 0);
+        assert!(op.is_exist("tmp/a").await?);
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let gc = GarbageCollector::new(&op, "tmp/", Duration::from_millis(1));
+        assert_eq!(gc.run_once().await?, 2);
+        assert!(!op.is_exist("tmp/a").await?);
+        assert!(!op.is_exist("tmp/b/c").await?);
+        assert!(op.is_exist("keep").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_start_and_stop() -> Result<()> {
+        let op = new_operator();
+        op.write("tmp/a", "a").await?;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let gc = GarbageCollector::new(&op, "tmp/", Duration::from_millis(1))
+            .with_interval(Duration::from_secs(3600));
+        let handle = gc.start();
+
+        // The first scan happens immediately.
+        while op.is_exist("tmp/a").await? {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        gc.stop();
+        handle.await.expect("gc task must succeed");
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// LifecycleRule describes how objects under given prefix will be
+/// transited or expired by the service.
+///
+/// All fields of LifecycleRule are public and can be accessed directly.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LifecycleRule {
+    /// The id of this rule.
+    pub id: String,
+    /// The prefix of objects this rule applies to.
+    ///
+    /// Empty prefix means this rule applies to all objects.
+    pub prefix: String,
+    /// Whether this rule is enabled.
+    pub enabled: bool,
+    /// Transitions of objects' storage class.
+    pub transitions: Vec<Transition>,
+    /// Expiration of objects.
+    pub expiration: Option<Expiration>,
+}
+
+/// Transition describes when objects will be transited to another
+/// storage class.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Transition {
+    /// Days after the object's creation.
+    pub days: u32,
+    /// The storage class to transit to, for example, `WARM` or `COLD`.
+    ///
+    /// Available values are decided by services.
+    pub storage_class: String,
+}
+
+/// Expiration describes when objects will be deleted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expiration {
+    /// Days after the object's creation.
+    pub days: u32,
+}
@@ -21,7 +21,9 @@ use std::pin::Pin;
 use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
 
+use backon::ExponentialBuilder;
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use futures::Stream;
@@ -29,6 +31,116 @@ use futures::Stream;
 use crate::raw::*;
 use crate::*;
 
+/// ErrorPolicy controls how list will behave while a page returns error.
+///
+/// Only services that list by marker (like `obs`) respect this policy,
+/// others will always fail fast.
+#[derive(Debug, Clone, Default)]
+pub enum ErrorPolicy {
+    /// Return the error and terminate the list. This is the default policy.
+    #[default]
+    FailFast,
+    /// Log the error and skip the failed page, entries in it will not be
+    /// returned.
+    ///
+    /// The list continues from the next marker carried by the failed page,
+    /// which is available when the page was received but can't be parsed.
+    /// If the request itself failed there is no marker to advance to, so
+    /// the page will be requested again once and the error will be returned
+    /// if it fails again.
+    SkipPage,
+    /// Retry the failing page with given config.
+    ///
+    /// Only temporary errors will be retried.
+    Retry(RetryConfig),
+}
+
+/// RetryConfig is the exponential backoff config used by [`ErrorPolicy::Retry`].
+#[derive(Debug, Clone, Default)]
+pub struct RetryConfig {
+    builder: ExponentialBuilder,
+}
+
+impl RetryConfig {
+    /// Create a new retry config.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set jitter of current backoff.
+    ///
+    /// If jitter is enabled, ExponentialBackoff will add a random jitter in `[0, min_delay)
+    /// to current delay.
+    pub fn with_jitter(mut self) -> Self {
+        self.builder = self.builder.with_jitter();
+        self
+    }
+
+    /// Set factor of current backoff.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if input factor smaller than `1.0`.
+    pub fn with_factor(mut self, factor: f32) -> Self {
+        self.builder = self.builder.with_factor(factor);
+        self
+    }
+
+    /// Set min_delay of current backoff.
+    pub fn with_min_delay(mut self, min_delay: Duration) -> Self {
+        self.builder = self.builder.with_min_delay(min_delay);
+        self
+    }
+
+    /// Set max_delay of current backoff.
+    ///
+    /// Delay will not increasing if current delay is larger than max_delay.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.builder = self.builder.with_max_delay(max_delay);
+        self
+    }
+
+    /// Set max_times of current backoff.
+    ///
+    /// Backoff will return `None` if max times is reaching.
+    pub fn with_max_times(mut self, max_times: usize) -> Self {
+        self.builder = self.builder.with_max_times(max_times);
+        self
+    }
+
+    /// Build the backoff iterator.
+    ///
+    /// Only used by services that support [`ErrorPolicy::Retry`].
+    #[allow(dead_code)]
+    pub(crate) fn backoff(&self) -> backon::ExponentialBackoff {
+        backon::BackoffBuilder::build(&self.builder)
+    }
+}
+
+/// SortBy is the key to sort entries returned by list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    /// Sort by the path of entries.
+    Name,
+    /// Sort by the content length of entries.
+    ///
+    /// Dirs are treated as smaller than all files.
+    Size,
+    /// Sort by the last modified time of entries.
+    ///
+    /// Entries without last modified time are treated as the oldest.
+    LastModified,
+}
+
+/// SortOrder is the order to sort entries returned by list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest, oldest or lexicographically first entries come first.
+    Ascending,
+    /// Largest, newest or lexicographically last entries come first.
+    Descending,
+}
+
 /// Lister is designed to list entries at given path in an asynchronous
 /// manner.
 ///
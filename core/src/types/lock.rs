@@ -0,0 +1,406 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::future::Future;
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::*;
+
+/// Guard files are only held for a few requests, a guard older than this
+/// is left by a crashed process and will be removed.
+const GUARD_TTL: Duration = Duration::from_secs(30);
+/// Interval to check the guard again while it's held by others.
+const GUARD_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// WriteLock is an advisory lock built on top of conditional write.
+///
+/// The lock is a file which contains the holder and the expire time of
+/// the lock. Acquiring the lock is done by writing this file with
+/// `If-None-Match: *`, so only one holder can create it at the same time.
+///
+/// Existing lock files are only changed (renewed, released or taken over
+/// after expired) while holding the guard file at `<path>.guard`, which is
+/// also created with `If-None-Match: *`. So a lock file will never be
+/// overwritten or removed by others after we checked its holder.
+///
+/// Services must support `write_with_if_none_match` to use `WriteLock`.
+///
+/// # Notes
+///
+/// WriteLock is advisory: it doesn't prevent others from writing
+/// the locked data. All parties must agree to acquire the lock first.
+///
+/// Empty lock files are written by crashed processes, or still being
+/// written by services that can't write atomically like `fs`. They are
+/// treated as expired after 30 seconds since last modified.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use anyhow::Result;
+/// use opendal::Operator;
+/// use opendal::WriteLock;
+///
+/// # #[tokio::main]
+/// # async fn test(op: Operator) -> Result<()> {
+/// let lock = WriteLock::acquire(&op, "path/to/lock", Duration::from_secs(30)).await?;
+/// // Do something while holding the lock.
+/// lock.renew(Duration::from_secs(30)).await?;
+/// lock.release().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WriteLock {
+    op: Operator,
+    path: String,
+    holder: String,
+}
+
+/// LockFile is the content stored in the lock file.
+#[derive(Debug, Serialize, Deserialize)]
+struct LockFile {
+    holder: String,
+    /// Expire time of this lock in unix milliseconds.
+    expires: i64,
+}
+
+impl LockFile {
+    fn new(holder: &str, ttl: Duration) -> Self {
+        Self {
+            holder: holder.to_string(),
+            expires: Utc::now().timestamp_millis() + ttl.as_millis() as i64,
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.expires <= Utc::now().timestamp_millis()
+    }
+
+    fn to_vec(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self)
+            .map_err(|err| Error::new(ErrorKind::Unexpected, "serialize lock file").set_source(err))
+    }
+
+    fn from_slice(bs: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bs).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "deserialize lock file").set_source(err)
+        })
+    }
+
+    /// Read the lock file at `path`, returns `None` if it doesn't exist.
+    ///
+    /// Empty files don't have a holder and expire after [`GUARD_TTL`]
+    /// since last modified.
+    async fn read(op: &Operator, path: &str) -> Result<Option<Self>> {
+        let bs = match op.read(path).await {
+            Ok(bs) => bs,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        if !bs.is_empty() {
+            return Self::from_slice(&bs).map(Some);
+        }
+
+        let meta = match op.stat(path).await {
+            Ok(meta) => meta,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let expires = meta
+            .last_modified()
+            .map(|v| v.timestamp_millis() + GUARD_TTL.as_millis() as i64)
+            .unwrap_or_default();
+        Ok(Some(Self {
+            holder: String::new(),
+            expires,
+        }))
+    }
+}
+
+/// Check whether the conditional write failed because the file exists.
+fn is_conflict(err: &Error) -> bool {
+    matches!(
+        err.kind(),
+        ErrorKind::AlreadyExists | ErrorKind::ConditionNotMatch
+    )
+}
+
+impl WriteLock {
+    /// Acquire the lock at `path` which will be expired after `ttl`.
+    ///
+    /// Returns an [`ErrorKind::AlreadyExists`] error if the lock is held by
+    /// others and not expired yet. Expired locks will be taken over.
+    pub async fn acquire(op: &Operator, path: &str, ttl: Duration) -> Result<Self> {
+        let lock = Self {
+            op: op.clone(),
+            path: path.to_string(),
+            holder: Uuid::new_v4().to_string(),
+        };
+
+        loop {
+            match lock.create(ttl).await {
+                Ok(()) => return Ok(lock),
+                Err(err) if is_conflict(&err) => {}
+                Err(err) => return Err(err),
+            }
+
+            if lock.with_guard(lock.take_over(ttl)).await? {
+                return Ok(lock);
+            }
+        }
+    }
+
+    /// Get the path of this lock.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get the holder id of this lock.
+    pub fn holder(&self) -> &str {
+        &self.holder
+    }
+
+    /// Renew the lock so that it will be expired after `ttl` from now.
+    ///
+    /// Returns an [`ErrorKind::ConditionNotMatch`] error if the lock is not
+    /// held by us anymore.
+    pub async fn renew(&self, ttl: Duration) -> Result<()> {
+        self.with_guard(async {
+            self.check_holder().await?;
+
+            let content = LockFile::new(&self.holder, ttl).to_vec()?;
+            self.op.write(&self.path, content).await
+        })
+        .await
+    }
+
+    /// Release the lock.
+    ///
+    /// The lock file will be removed only if it's still held by us.
+    pub async fn release(self) -> Result<()> {
+        self.with_guard(async {
+            match self.check_holder().await {
+                Ok(()) => self.op.delete(&self.path).await,
+                Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+                Err(err) if err.kind() == ErrorKind::ConditionNotMatch => Ok(()),
+                Err(err) => Err(err),
+            }
+        })
+        .await
+    }
+
+    async fn create(&self, ttl: Duration) -> Result<()> {
+        let content = LockFile::new(&self.holder, ttl).to_vec()?;
+        self.op
+            .write_with(&self.path, content)
+            .if_none_match("*")
+            .await
+    }
+
+    /// Take over the existing lock if it's expired, must be called with
+    /// the guard held.
+    ///
+    /// Returns `false` if the lock has been released so that it should be
+    /// created again.
+    async fn take_over(&self, ttl: Duration) -> Result<bool> {
+        let existing = match LockFile::read(&self.op, &self.path).await? {
+            Some(existing) => existing,
+            None => return Ok(false),
+        };
+
+        if !existing.is_expired() {
+            return Err(
+                Error::new(ErrorKind::AlreadyExists, "lock is held by others")
+                    .with_context("path", &self.path)
+                    .with_context("holder", existing.holder),
+            );
+        }
+
+        self.op.delete(&self.path).await?;
+        match self.create(ttl).await {
+            Ok(()) => Ok(true),
+            // Others created the lock without guard after we removed it.
+            Err(err) if is_conflict(&err) => Err(Error::new(
+                ErrorKind::AlreadyExists,
+                "lock is held by others",
+            )
+            .with_context("path", &self.path)
+            .set_source(err)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Run `fut` while holding the guard of this lock.
+    ///
+    /// The guard will be removed after `fut` returned, whatever it
+    /// succeeded or not.
+    async fn with_guard<T>(&self, fut: impl Future<Output = Result<T>>) -> Result<T> {
+        let guard = format!("{}.guard", self.path);
+        let content = LockFile::new(&self.holder, GUARD_TTL).to_vec()?;
+
+        loop {
+            match self
+                .op
+                .write_with(&guard, content.clone())
+                .if_none_match("*")
+                .await
+            {
+                Ok(()) => break,
+                Err(err) if is_conflict(&err) => {}
+                Err(err) => return Err(err),
+            }
+
+            match LockFile::read(&self.op, &guard).await? {
+                // The guard has been released, try again.
+                None => {}
+                // The guard is left by a crashed process.
+                Some(existing) if existing.is_expired() => match self.op.delete(&guard).await {
+                    Ok(()) => {}
+                    Err(err) if err.kind() == ErrorKind::NotFound => {}
+                    Err(err) => return Err(err),
+                },
+                Some(_) => tokio::time::sleep(GUARD_RETRY_INTERVAL).await,
+            }
+        }
+
+        let res = fut.await;
+        match self.op.delete(&guard).await {
+            Ok(()) => res,
+            Err(err) => res.and(Err(err)),
+        }
+    }
+
+    async fn check_holder(&self) -> Result<()> {
+        let existing = LockFile::read(&self.op, &self.path).await?.ok_or_else(|| {
+            Error::new(ErrorKind::NotFound, "lock is not found").with_context("path", &self.path)
+        })?;
+
+        if existing.holder != self.holder {
+            return Err(
+                Error::new(ErrorKind::ConditionNotMatch, "lock is held by others")
+                    .with_context("path", &self.path)
+                    .with_context("holder", existing.holder),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "services-fs"))]
+mod tests {
+    use super::*;
+    use crate::services::Fs;
+
+    fn new_operator() -> Operator {
+        let dir = std::env::temp_dir().join(format!("opendal-lock-{}", Uuid::new_v4()));
+
+        let mut builder = Fs::default();
+        builder.root(dir.to_str().unwrap());
+        Operator::new(builder).unwrap().finish()
+    }
+
+    #[tokio::test]
+    async fn test_acquire_and_release() -> Result<()> {
+        let op = new_operator();
+
+        let lock = WriteLock::acquire(&op, "lock", Duration::from_secs(60)).await?;
+        let err = WriteLock::acquire(&op, "lock", Duration::from_secs(60))
+            .await
+            .expect_err("lock should be held");
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        lock.renew(Duration::from_secs(60)).await?;
+        lock.release().await?;
+        assert!(!op.is_exist("lock").await?);
+
+        let lock = WriteLock::acquire(&op, "lock", Duration::from_secs(60)).await?;
+        lock.release().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_acquire_expired() -> Result<()> {
+        let op = new_operator();
+
+        let old = WriteLock::acquire(&op, "lock", Duration::ZERO).await?;
+        let new = WriteLock::acquire(&op, "lock", Duration::from_secs(60)).await?;
+        assert_ne!(old.holder(), new.holder());
+
+        let err = old
+            .renew(Duration::from_secs(60))
+            .await
+            .expect_err("outdated lock should not be renewed");
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+        // Release an outdated lock should not remove the new one.
+        old.release().await?;
+        assert!(op.is_exist("lock").await?);
+
+        new.release().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_acquire_empty() -> Result<()> {
+        let op = new_operator();
+
+        // Empty file is treated as being written until it's expired.
+        op.write("lock", vec![]).await?;
+        let err = WriteLock::acquire(&op, "lock", Duration::from_secs(60))
+            .await
+            .expect_err("empty lock should be held");
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        let mut existing = LockFile::read(&op, "lock").await?.expect("lock must exist");
+        assert_eq!(existing.holder, "");
+        assert!(!existing.is_expired());
+        existing.expires = Utc::now().timestamp_millis() - GUARD_TTL.as_millis() as i64;
+        assert!(existing.is_expired());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_acquire_concurrently() -> Result<()> {
+        let op = new_operator();
+
+        let old = WriteLock::acquire(&op, "lock", Duration::ZERO).await?;
+        let results = futures::future::join_all(
+            (0..8).map(|_| WriteLock::acquire(&op, "lock", Duration::from_secs(60))),
+        )
+        .await;
+
+        // Only one of them can take over the expired lock.
+        let locks: Vec<_> = results.into_iter().filter_map(|v| v.ok()).collect();
+        assert_eq!(locks.len(), 1);
+        assert!(!op.is_exist("lock.guard").await?);
+
+        let err = old
+            .renew(Duration::from_secs(60))
+            .await
+            .expect_err("outdated lock should not be renewed");
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+        Ok(())
+    }
+}
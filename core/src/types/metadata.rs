@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use chrono::prelude::*;
 use flagset::flags;
 use flagset::FlagSet;
@@ -45,6 +47,9 @@ pub struct Metadata {
     etag: Option<String>,
     last_modified: Option<DateTime<Utc>>,
     version: Option<String>,
+    owner: Option<Owner>,
+    is_delete_marker: bool,
+    custom: HashMap<String, String>,
 }
 
 impl Metadata {
@@ -71,6 +76,9 @@ impl Metadata {
             etag: None,
             content_disposition: None,
             version: None,
+            owner: None,
+            is_delete_marker: false,
+            custom: HashMap::new(),
         }
     }
 
@@ -105,6 +113,13 @@ impl Metadata {
         matches!(self.mode, EntryMode::DIR)
     }
 
+    /// Returns `true` if the mode of this metadata is unknown.
+    ///
+    /// Call `stat` on the path to resolve it.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self.mode, EntryMode::Unknown)
+    }
+
     /// Set mode for entry.
     pub fn set_mode(&mut self, v: EntryMode) -> &mut Self {
         self.mode = v;
@@ -293,6 +308,11 @@ impl Metadata {
         self.last_modified
     }
 
+    /// Fetch the raw last modified time.
+    pub(crate) fn last_modified_raw(&self) -> Option<DateTime<Utc>> {
+        self.last_modified
+    }
+
     /// Set Last modified of this entry.
     ///
     /// `Last-Modified` is defined by [RFC 7232](https://httpwg.org/specs/rfc7232.html#header.last-modified)
@@ -333,6 +353,11 @@ impl Metadata {
         self.etag.as_deref()
     }
 
+    /// Fetch the raw etag.
+    pub(crate) fn etag_raw(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
     /// Set ETag of this entry.
     ///
     /// `ETag` is defined by [RFC 7232](https://httpwg.org/specs/rfc7232.html#header.etag)
@@ -456,6 +481,103 @@ impl Metadata {
         self.bit |= Metakey::Version;
         self
     }
+
+    /// Owner of this entry.
+    ///
+    /// This field is only available on services that report ownership
+    /// information, like `obs` during list.
+    ///
+    /// `None` will be returned if the service doesn't report owner, even
+    /// if [`Metakey::Owner`] is not requested.
+    pub fn owner(&self) -> Option<&Owner> {
+        self.owner.as_ref()
+    }
+
+    /// Set owner of this entry.
+    pub fn with_owner(mut self, v: Owner) -> Self {
+        self.owner = Some(v);
+        self.bit |= Metakey::Owner;
+        self
+    }
+
+    /// Set owner of this entry.
+    pub fn set_owner(&mut self, v: Owner) -> &mut Self {
+        self.owner = Some(v);
+        self.bit |= Metakey::Owner;
+        self
+    }
+
+    /// Whether this entry is a delete marker.
+    ///
+    /// Delete markers are returned by `list_versions` on versioned services
+    /// which mark the file as deleted at this version. They don't have content.
+    pub fn is_delete_marker(&self) -> bool {
+        self.is_delete_marker
+    }
+
+    /// Set whether this entry is a delete marker.
+    pub fn with_delete_marker(mut self, v: bool) -> Self {
+        self.is_delete_marker = v;
+        self
+    }
+
+    /// Set whether this entry is a delete marker.
+    pub fn set_delete_marker(&mut self, v: bool) -> &mut Self {
+        self.is_delete_marker = v;
+        self
+    }
+
+    /// Backend-specific metadata of this entry which is not in the standard
+    /// set, like `x-obs-storage-redundancy` of `obs` or `x-ms-access-tier`
+    /// of `azblob`.
+    ///
+    /// # Notes
+    ///
+    /// Custom metadata is informational only: it's filled by `stat` on a
+    /// best-effort basis and not covered by [`Metakey`]. Keys are header
+    /// names in lower case.
+    pub fn custom(&self) -> &HashMap<String, String> {
+        &self.custom
+    }
+
+    /// Get custom metadata of given key.
+    pub fn get_custom(&self, key: &str) -> Option<&str> {
+        self.custom.get(key).map(|v| v.as_str())
+    }
+
+    /// Set custom metadata of given key.
+    pub fn set_custom(&mut self, key: &str, value: &str) -> &mut Self {
+        self.custom.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+/// Owner carries the ownership information of an entry.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Owner {
+    id: String,
+    display_name: String,
+}
+
+impl Owner {
+    /// Create a new owner.
+    pub fn new(id: &str, display_name: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            display_name: display_name.to_string(),
+        }
+    }
+
+    /// ID of the owner.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Display name of the owner, could be empty if service doesn't
+    /// return it.
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
 }
 
 flags! {
@@ -497,5 +619,7 @@ flags! {
         LastModified,
         /// Key for version.
         Version,
+        /// Key for owner.
+        Owner,
     }
 }
@@ -24,9 +24,12 @@ pub use entry::Entry;
 mod metadata;
 pub use metadata::Metadata;
 pub use metadata::Metakey;
+pub use metadata::Owner;
 
 mod reader;
 pub use reader::BlockingReader;
+pub use reader::ChunkedReader;
+pub use reader::PeekableReader;
 pub use reader::Reader;
 
 mod writer;
@@ -38,7 +41,56 @@ pub use appender::Appender;
 
 mod list;
 pub use list::BlockingLister;
+pub use list::ErrorPolicy;
 pub use list::Lister;
+pub use list::RetryConfig;
+pub use list::SortBy;
+pub use list::SortOrder;
+
+mod gc;
+pub use gc::GarbageCollector;
+
+mod lock;
+pub use lock::WriteLock;
+
+mod transaction;
+pub use transaction::TransactionHandle;
+
+mod checksum;
+pub use checksum::Checksum;
+
+mod cors;
+pub use cors::CorsRule;
+
+mod acl;
+pub use acl::AclGrant;
+pub use acl::AclGrantee;
+pub use acl::BucketAcl;
+
+mod lifecycle;
+pub use lifecycle::Expiration;
+pub use lifecycle::LifecycleRule;
+pub use lifecycle::Transition;
+
+mod restore;
+pub use restore::RestoreStatus;
+
+mod object_lock;
+pub use object_lock::LockMode;
+pub use object_lock::ObjectLockConfig;
+
+mod snapshot;
+pub use snapshot::Snapshot;
+pub use snapshot::SnapshotDiff;
+
+mod watch;
+pub use watch::WatchConfig;
+pub use watch::WatchEvent;
+pub use watch::Watcher;
+
+mod sync;
+pub use sync::SyncStats;
+pub use sync::SyncStrategy;
 
 mod operator;
 pub use operator::operator_functions;
@@ -58,6 +110,7 @@ pub use error::Result;
 
 mod scheme;
 pub use scheme::Scheme;
+pub use scheme::SchemeExt;
 
 mod capability;
 pub use capability::Capability;
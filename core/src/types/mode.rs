@@ -25,7 +25,18 @@ pub enum EntryMode {
     FILE,
     /// DIR means the path can be listed.
     DIR,
+    /// SYMLINK means the path is a symbolic link to another path.
+    ///
+    /// Reading or writing a symlink will operate on its target.
+    ///
+    /// Services like `fs` return `SYMLINK` for all symlinks no matter
+    /// what their targets are, while other metadata comes from targets.
+    SYMLINK,
     /// Unknown means we don't know what we can do on this path.
+    ///
+    /// Services like ftp could return entries whose mode can't be decided
+    /// from the listing response. Callers must handle `Unknown` and may
+    /// call `stat` on the path to resolve it.
     Unknown,
 }
 
@@ -38,6 +49,14 @@ impl EntryMode {
     pub fn is_dir(self) -> bool {
         self == EntryMode::DIR
     }
+    /// Check if this mode is SYMLINK.
+    pub fn is_symlink(self) -> bool {
+        self == EntryMode::SYMLINK
+    }
+    /// Check if this mode is Unknown.
+    pub fn is_unknown(self) -> bool {
+        self == EntryMode::Unknown
+    }
 }
 
 impl Default for EntryMode {
@@ -51,6 +70,7 @@ impl Display for EntryMode {
         match self {
             EntryMode::FILE => write!(f, "file"),
             EntryMode::DIR => write!(f, "dir"),
+            EntryMode::SYMLINK => write!(f, "symlink"),
             EntryMode::Unknown => write!(f, "unknown"),
         }
     }
@@ -0,0 +1,55 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// LockMode decides who can remove the lock of an object before it
+/// expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// Users with special permissions can shorten or remove the lock.
+    Governance,
+    /// Nobody, including the root account, can shorten or remove the lock.
+    Compliance,
+}
+
+/// ObjectLockConfig describes the WORM (write-once-read-many) retention
+/// of a file.
+///
+/// Locked files can't be overwritten or deleted until `retain_until`.
+///
+/// All fields of ObjectLockConfig are public and can be accessed directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectLockConfig {
+    /// Mode of the lock.
+    pub mode: LockMode,
+    /// Time until which the file is locked.
+    pub retain_until: DateTime<Utc>,
+}
+
+impl ObjectLockConfig {
+    /// Create a new ObjectLockConfig.
+    pub fn new(mode: LockMode, retain_until: DateTime<Utc>) -> Self {
+        Self { mode, retain_until }
+    }
+
+    /// Check if the lock is still active at current time.
+    pub fn is_active(&self) -> bool {
+        self.retain_until > Utc::now()
+    }
+}
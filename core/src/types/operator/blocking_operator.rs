@@ -566,6 +566,63 @@ impl BlockingOperator {
         Ok(())
     }
 
+    /// Create a symlink at `dst` that points to `src`.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::symlink`] and [`Capability::blocking`].
+    /// - `src` and `dst` must be a file, `src` is not required to exist.
+    /// - `AlreadyExists` will be returned if `dst` exists.
+    /// - Symlinks will be returned with [`EntryMode::SYMLINK`] by `stat`
+    ///   and `list`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::BlockingOperator;
+    ///
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// op.create_symlink("path/to/file", "path/to/link")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        let src = normalize_path(src);
+
+        if !validate_path(&src, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "src path is a directory")
+                    .with_operation("BlockingOperator::create_symlink")
+                    .with_context("service", self.info().scheme())
+                    .with_context("src", src),
+            );
+        }
+
+        let dst = normalize_path(dst);
+
+        if !validate_path(&dst, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "dst path is a directory")
+                    .with_operation("BlockingOperator::create_symlink")
+                    .with_context("service", self.info().scheme())
+                    .with_context("dst", dst),
+            );
+        }
+
+        if src == dst {
+            return Err(
+                Error::new(ErrorKind::IsSameFile, "src and dst paths are same")
+                    .with_operation("BlockingOperator::create_symlink")
+                    .with_context("service", self.info().scheme())
+                    .with_context("src", src)
+                    .with_context("dst", dst),
+            );
+        }
+
+        self.inner().blocking_create_symlink(&src, &dst)
+    }
+
     /// Write data with option described in OpenDAL [rfc-0661](../../docs/rfcs/0661-path-in-accessor.md)
     ///
     /// # Notes
@@ -836,27 +893,75 @@ impl BlockingOperator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via de.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::SYMLINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
     /// # }
     /// ```
     pub fn list(&self, path: &str) -> Result<BlockingLister> {
+        self.list_with(path).call()
+    }
+
+    /// List given path with options.
+    ///
+    /// An error will be returned if given path doesn't end with `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// # use opendal::BlockingOperator;
+    /// # use opendal::SortBy;
+    /// # use opendal::SortOrder;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// let mut ds = op
+    ///     .list_with("path/to/dir/")
+    ///     .min_size(1024)
+    ///     .sort_by(SortBy::Size, SortOrder::Descending)
+    ///     .call()?;
+    /// while let Some(de) = ds.next() {
+    ///     println!("{}", de?.path());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_with(&self, path: &str) -> FunctionList {
         let path = normalize_path(path);
 
-        if !validate_path(&path, EntryMode::DIR) {
-            return Err(Error::new(
-                ErrorKind::NotADirectory,
-                "the path trying to list should end with `/`",
-            )
-            .with_operation("BlockingOperator::list")
-            .with_context("service", self.info().scheme().into_static())
-            .with_context("path", &path));
-        }
+        FunctionList(OperatorFunction::new(
+            self.inner().clone(),
+            path,
+            OpList::new(),
+            |inner, path, args| {
+                if !validate_path(&path, EntryMode::DIR) {
+                    return Err(Error::new(
+                        ErrorKind::NotADirectory,
+                        "the path trying to list should end with `/`",
+                    )
+                    .with_operation("BlockingOperator::list")
+                    .with_context("service", inner.info().scheme().into_static())
+                    .with_context("path", &path));
+                }
 
-        let (_, pager) = self.inner().blocking_list(&path, OpList::new())?;
-        Ok(BlockingLister::new(pager))
+                let (min_size, max_size) = (args.min_size(), args.max_size());
+                let sort_by = args.sort_by();
+                let (_, mut pager) = inner.blocking_list(&path, args)?;
+                if min_size.is_some() || max_size.is_some() {
+                    pager = Box::new(oio::to_size_filter_pager(
+                        inner.clone(),
+                        pager,
+                        min_size,
+                        max_size,
+                    ));
+                }
+                if let Some((by, order)) = sort_by {
+                    pager = Box::new(oio::to_sorted_pager(inner, pager, by, order));
+                }
+
+                Ok(BlockingLister::new(pager))
+            },
+        ))
     }
 
     /// List dir in flat way.
@@ -891,7 +996,7 @@ impl BlockingOperator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::SYMLINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -47,16 +47,64 @@ impl OperatorInfo {
         self.0.name()
     }
 
+    /// Endpoint that backend sends requests to, like
+    /// `https://obs.cn-north-4.myhuaweicloud.com`.
+    ///
+    /// Returns `None` if the backend doesn't report its endpoint.
+    pub fn endpoint(&self) -> Option<&str> {
+        self.0.endpoint()
+    }
+
     /// Get [`Capability`] of operator.
     pub fn capability(&self) -> Capability {
         self.0.capability()
     }
 
+    /// Max size of a single object, `None` means unknown or unlimited.
+    pub fn max_object_size(&self) -> Option<u64> {
+        self.0.max_object_size()
+    }
+
+    /// Max size of object that can be written in a single request, `None`
+    /// means unknown or unlimited.
+    pub fn max_single_put_size(&self) -> Option<u64> {
+        self.0.max_single_put_size()
+    }
+
+    /// Total bytes read from backend, `None` means backend doesn't track it.
+    pub fn bytes_read(&self) -> Option<u64> {
+        self.0.bytes_read()
+    }
+
+    /// Total bytes written into backend, `None` means backend doesn't track it.
+    pub fn bytes_written(&self) -> Option<u64> {
+        self.0.bytes_written()
+    }
+
+    /// Reset counters of bytes read and written to zero.
+    pub fn reset_bytes_counters(&self) {
+        self.0.reset_bytes_counters()
+    }
+
+    /// Check if current backend supports [`Accessor::object_exists`]
+    /// natively or not.
+    pub fn can_exists(&self) -> bool {
+        self.0.capability().exists
+    }
+
     /// Check if current backend supports [`Accessor::read`] or not.
     pub fn can_read(&self) -> bool {
         self.0.capability().read
     }
 
+    /// Check if dirs of current backend are virtual or not.
+    ///
+    /// Virtual dirs don't need to be created before writing files into
+    /// them, see [`Capability::directory_create`] for more details.
+    pub fn is_dir_virtual(&self) -> bool {
+        self.0.capability().directory_create
+    }
+
     /// Check if current backend supports [`Accessor::write`] or not.
     pub fn can_write(&self) -> bool {
         self.0.capability().write
@@ -77,6 +125,11 @@ impl OperatorInfo {
         self.0.capability().rename
     }
 
+    /// Check if current backend supports [`Accessor::create_symlink`] or not.
+    pub fn can_symlink(&self) -> bool {
+        self.0.capability().symlink
+    }
+
     /// Check if current backend supports [`Accessor::list`] or not.
     pub fn can_list(&self) -> bool {
         self.0.capability().list
@@ -96,4 +149,66 @@ impl OperatorInfo {
     pub fn can_blocking(&self) -> bool {
         self.0.capability().blocking
     }
+
+    /// Check if current backend supports object versioning or not.
+    ///
+    /// Refer to [`Capability::versioning`] for more details.
+    pub fn can_versioning(&self) -> bool {
+        self.0.capability().versioning
+    }
+
+    /// Check if current backend supports lifecycle rules or not.
+    pub fn can_lifecycle(&self) -> bool {
+        self.0.capability().lifecycle
+    }
+
+    /// Check if current backend supports cors config or not.
+    pub fn can_cors(&self) -> bool {
+        self.0.capability().cors
+    }
+
+    /// Check if current backend supports bucket acl or not.
+    pub fn can_bucket_acl(&self) -> bool {
+        self.0.capability().bucket_acl
+    }
+
+    /// Check if current backend supports object tagging or not.
+    pub fn can_tagging(&self) -> bool {
+        self.0.capability().tagging
+    }
+
+    /// Check if current backend supports getting restore status of
+    /// archived files or not.
+    pub fn can_restore_status(&self) -> bool {
+        self.0.capability().restore_status
+    }
+
+    /// Check if current backend supports importing files from url
+    /// server-side or not.
+    pub fn can_upload_from_url(&self) -> bool {
+        self.0.capability().upload_from_url
+    }
+
+    /// Check if current backend supports moving files across buckets or not.
+    pub fn can_move_object(&self) -> bool {
+        self.0.capability().move_object
+    }
+
+    /// Check if current backend supports concatenating files server-side
+    /// or not.
+    pub fn can_concat_objects(&self) -> bool {
+        self.0.capability().concat_objects
+    }
+
+    /// Check if current backend supports updating user metadata of files
+    /// or not.
+    pub fn can_update_metadata(&self) -> bool {
+        self.0.capability().update_metadata
+    }
+
+    /// Check if current backend supports locking files with retention or
+    /// not.
+    pub fn can_object_lock(&self) -> bool {
+        self.0.capability().object_lock
+    }
 }
@@ -15,16 +15,30 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::io;
 use std::ops::RangeBounds;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::pin::Pin;
 use std::time::Duration;
 
+#[cfg(not(target_arch = "wasm32"))]
+use async_compat::Compat;
+
 use bytes::Bytes;
 use flagset::FlagSet;
 use futures::stream;
 use futures::AsyncReadExt;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::AsyncWriteExt;
 use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use log::warn;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use tokio::io::ReadBuf;
 
 use super::BlockingOperator;
@@ -72,6 +86,12 @@ pub struct Operator {
     limit: usize,
 }
 
+impl From<Operator> for FusedAccessor {
+    fn from(op: Operator) -> Self {
+        op.into_inner()
+    }
+}
+
 /// # Operator basic API.
 impl Operator {
     pub(super) fn inner(&self) -> &FusedAccessor {
@@ -125,6 +145,22 @@ impl Operator {
         OperatorInfo::new(self.accessor.info())
     }
 
+    /// Check if current operator has the capability selected by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Operator;
+    /// # fn test(op: Operator) {
+    /// if op.has_capability(|cap| cap.tagging && cap.versioning) {
+    ///     // Tag specified versions of files.
+    /// }
+    /// # }
+    /// ```
+    pub fn has_capability(&self, f: impl FnOnce(&Capability) -> bool) -> bool {
+        f(&self.accessor.info().capability())
+    }
+
     /// Create a new blocking operator.
     ///
     /// This operation is nearly no cost.
@@ -225,6 +261,30 @@ impl Operator {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// ## Refresh metadata only if changed
+    ///
+    /// Services that support `stat_with_if_none_match` will return
+    /// `ConditionNotMatch` (`304 Not Modified`) if the etag is not changed,
+    /// so callers can keep their cached metadata.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use opendal::ErrorKind;
+    /// use opendal::Metadata;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator, cached: Metadata) -> Result<()> {
+    /// let etag = cached.etag().expect("etag must exist");
+    /// let meta = match op.stat_with("test").if_none_match(etag).await {
+    ///     Ok(meta) => meta,
+    ///     Err(e) if e.kind() == ErrorKind::ConditionNotMatch => cached,
+    ///     Err(e) => return Err(e.into()),
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn stat_with(&self, path: &str) -> FutureStat {
         let path = normalize_path(path);
 
@@ -245,6 +305,35 @@ impl Operator {
         fut
     }
 
+    /// Get current path's content length and etag **without cache** directly.
+    ///
+    /// # Notes
+    ///
+    /// `head` is a cheaper alternative of `stat` which declares that
+    /// only content length and etag are needed. Services could fetch them
+    /// without building the full metadata.
+    ///
+    /// Etag could be `None` if services don't support it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let (content_length, etag) = op.head("test").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn head(&self, path: &str) -> Result<(u64, Option<String>)> {
+        let path = normalize_path(path);
+
+        let rp = self.inner().head(&path, OpHead::new()).await?;
+        Ok(rp.into_parts())
+    }
+
     /// Get current metadata with cache.
     ///
     /// `metadata` will check the given query with already cached metadata
@@ -363,14 +452,9 @@ impl Operator {
     /// }
     /// ```
     pub async fn is_exist(&self, path: &str) -> Result<bool> {
-        let r = self.stat(path).await;
-        match r {
-            Ok(_) => Ok(true),
-            Err(err) => match err.kind() {
-                ErrorKind::NotFound => Ok(false),
-                _ => Err(err),
-            },
-        }
+        let path = normalize_path(path);
+
+        self.inner().object_exists(&path).await
     }
 
     /// Create a dir at given path.
@@ -386,6 +470,12 @@ impl Operator {
     /// - Create on existing dir will succeed.
     /// - Create dir is always recursive, works like `mkdir -p`
     ///
+    /// To create a file with initial content, use [`Operator::write`]
+    /// instead. If the file must not be overwritten, use
+    /// `write_with(path, bs).if_none_match("*")` on services that support
+    /// `write_with_if_none_match`, which fails with `AlreadyExists` or
+    /// `ConditionNotMatch` if the file exists.
+    ///
     /// # Examples
     ///
     /// ```
@@ -437,6 +527,39 @@ impl Operator {
         self.range_read(path, ..).await
     }
 
+    /// Read the whole path and deserialize it from JSON.
+    ///
+    /// Returns [`ErrorKind::Unexpected`] if the content is not a valid JSON
+    /// of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Config {
+    ///     name: String,
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let config: Config = op.read_json("path/to/config.json").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_json<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let bs = self.read(path).await?;
+
+        serde_json::from_slice(&bs).map_err(|err| {
+            new_json_deserialize_error(err)
+                .with_operation("Operator::read_json")
+                .with_context("path", path)
+        })
+    }
+
     /// Read the whole path into a bytes with extra options.
     ///
     /// This function will allocate a new bytes internally. For more precise memory control or
@@ -475,8 +598,24 @@ impl Operator {
 
                     let br = args.range();
                     let (rp, mut s) = inner.read(&path, args).await?;
+                    let meta = rp.into_metadata();
+
+                    // Length of some readers is unknown until fully read,
+                    // for example, decompressed content.
+                    if !meta.bit().contains(Metakey::ContentLength) {
+                        let mut buffer = Vec::new();
+                        s.read_to_end(&mut buffer).await.map_err(|err| {
+                            Error::new(ErrorKind::Unexpected, "read from storage")
+                                .with_operation("range_read")
+                                .with_context("service", inner.info().scheme().into_static())
+                                .with_context("path", &path)
+                                .with_context("range", br.to_string())
+                                .set_source(err)
+                        })?;
+                        return Ok(buffer);
+                    }
 
-                    let length = rp.into_metadata().content_length() as usize;
+                    let length = meta.content_length() as usize;
                     let mut buffer = Vec::with_capacity(length);
 
                     let dst = buffer.spare_capacity_mut();
@@ -533,6 +672,148 @@ impl Operator {
         self.read_with(path).range(range).await
     }
 
+    /// Read `len` bytes of path starting from `offset`.
+    ///
+    /// At most `len` bytes will be buffered in memory.
+    ///
+    /// # Notes
+    ///
+    /// - The returning content's length may be smaller than `len` if the
+    ///   file is shorter than `offset + len`. Use [`Operator::read_exact_at`]
+    ///   if exact length is required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op.read_at("path/to/file", 1024, 1024).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_at(&self, path: &str, offset: u64, len: u64) -> Result<Bytes> {
+        if len == 0 {
+            return Ok(Bytes::new());
+        }
+
+        let end = Self::checked_range_end(path, offset, len, "Operator::read_at")?;
+        let bs = self.range_read(path, offset..end).await?;
+        Ok(Bytes::from(bs))
+    }
+
+    /// Read exactly `buf.len()` bytes of path starting from `offset` into `buf`.
+    ///
+    /// # Notes
+    ///
+    /// - `ContentIncomplete` will be returned if the file is shorter than
+    ///   `offset + buf.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut buf = vec![0; 1024];
+    /// op.read_exact_at("path/to/file", 1024, &mut buf).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_exact_at(&self, path: &str, offset: u64, buf: &mut [u8]) -> Result<()> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+
+        let len = buf.len() as u64;
+        let end = Self::checked_range_end(path, offset, len, "Operator::read_exact_at")?;
+        let mut r = self.range_reader(path, offset..end).await?;
+        r.read_exact(buf).await.map_err(|err| {
+            let is_opendal_error = err.get_ref().map_or(false, |v| v.is::<Error>());
+            let err = if err.kind() == io::ErrorKind::UnexpectedEof {
+                Error::new(ErrorKind::ContentIncomplete, "read exact from storage").set_source(err)
+            } else if is_opendal_error {
+                // Errors returned by reader are converted from opendal's
+                // error, take it back to keep its kind.
+                *err.into_inner()
+                    .expect("inner error must exist")
+                    .downcast::<Error>()
+                    .expect("inner error must be opendal error")
+            } else {
+                Error::new(ErrorKind::Unexpected, "read exact from storage").set_source(err)
+            };
+            err.with_operation("Operator::read_exact_at")
+                .with_context("service", self.info().scheme())
+                .with_context("path", path)
+                .with_context(
+                    "range",
+                    BytesRange::new(Some(offset), Some(len)).to_string(),
+                )
+        })
+    }
+
+    /// Return the end of range `offset..offset + len`, or an `InvalidInput`
+    /// error if it overflows.
+    fn checked_range_end(
+        path: &str,
+        offset: u64,
+        len: u64,
+        operation: &'static str,
+    ) -> Result<u64> {
+        offset.checked_add(len).ok_or_else(|| {
+            Error::new(ErrorKind::InvalidInput, "range end overflows u64")
+                .with_operation(operation)
+                .with_context("path", path)
+                .with_context("offset", offset.to_string())
+                .with_context("len", len.to_string())
+        })
+    }
+
+    /// Read the whole path into a local file.
+    ///
+    /// The local file will be created if not exist, and truncated if exist.
+    /// Returns the size of bytes that have been read.
+    ///
+    /// # Notes
+    ///
+    /// - The content will be streamed with a constant in-memory buffer
+    ///   instead of being loaded into memory at once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use std::path::Path;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let size = op
+    ///     .read_to_path("path/to/file", Path::new("/tmp/file"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn read_to_path(&self, path: &str, local_path: &Path) -> Result<u64> {
+        let r = self.reader(path).await?;
+
+        let f = tokio::fs::File::create(local_path)
+            .await
+            .map_err(|err| new_local_io_error(err, "Operator::read_to_path", local_path))?;
+        let mut f = Compat::new(f);
+
+        let size = futures::io::copy(r, &mut f)
+            .await
+            .map_err(|err| new_local_io_error(err, "Operator::read_to_path", local_path))?;
+        f.close()
+            .await
+            .map_err(|err| new_local_io_error(err, "Operator::read_to_path", local_path))?;
+
+        Ok(size)
+    }
+
     /// Create a new reader which can read the whole path.
     ///
     /// # Examples
@@ -643,6 +924,200 @@ impl Operator {
         self.write_with(path, bs).await
     }
 
+    /// Serialize value to JSON and write it into path with content type
+    /// `application/json`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Config {
+    ///     name: String,
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let config = Config {
+    ///     name: "opendal".to_string(),
+    /// };
+    /// op.write_json("path/to/config.json", &config).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_json<T: Serialize + ?Sized>(&self, path: &str, value: &T) -> Result<()> {
+        let bs = serde_json::to_vec(value).map_err(|err| {
+            new_json_serialize_error(err)
+                .with_operation("Operator::write_json")
+                .with_context("path", path)
+        })?;
+
+        self.write_with(path, bs)
+            .content_type("application/json")
+            .await
+    }
+
+    /// Write the content of a local file into path.
+    ///
+    /// Returns the size of bytes that have been written.
+    ///
+    /// # Notes
+    ///
+    /// - The file will be streamed with a constant in-memory buffer instead
+    ///   of being loaded into memory at once if the service supports
+    ///   `write_can_sink`, otherwise the whole file will be loaded.
+    /// - OpenDAL itself doesn't use `sendfile`, whether zero copy happens
+    ///   depends on the underlying transport.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use std::path::Path;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let size = op
+    ///     .write_from_path("path/to/file", Path::new("/tmp/file"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn write_from_path(&self, path: &str, local_path: &Path) -> Result<u64> {
+        let f = tokio::fs::File::open(local_path)
+            .await
+            .map_err(|err| new_local_io_error(err, "Operator::write_from_path", local_path))?;
+        let size = f
+            .metadata()
+            .await
+            .map_err(|err| new_local_io_error(err, "Operator::write_from_path", local_path))?
+            .len();
+
+        if !self.info().capability().write_can_sink {
+            let mut bs = Vec::with_capacity(size as usize);
+            Compat::new(f)
+                .read_to_end(&mut bs)
+                .await
+                .map_err(|err| new_local_io_error(err, "Operator::write_from_path", local_path))?;
+            self.write(path, bs).await?;
+            return Ok(size);
+        }
+
+        let mut w = self.writer_with(path).content_length(size).await?;
+        w.copy(size, Compat::new(f)).await?;
+        w.close().await?;
+
+        Ok(size)
+    }
+
+    /// Write all data from a `tokio::io::AsyncRead` into path.
+    ///
+    /// Returns the size of bytes that have been written.
+    ///
+    /// # Notes
+    ///
+    /// - `size_hint` must be the exact size of data in `reader` if given.
+    ///   The data will be streamed with a constant in-memory buffer if the
+    ///   service supports `write_can_sink`.
+    /// - The whole data will be loaded into memory if `size_hint` is `None`
+    ///   or the service doesn't support `write_can_sink`, since most
+    ///   services require the `Content-Length` to be known.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let f = tokio::fs::File::open("/tmp/file").await?;
+    /// let size = f.metadata().await?.len();
+    /// let written = op
+    ///     .write_from_async_read("path/to/file", f, Some(size))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_from_async_read<R>(
+        &self,
+        path: &str,
+        reader: R,
+        size_hint: Option<u64>,
+    ) -> Result<u64>
+    where
+        R: tokio::io::AsyncRead + Unpin + Send,
+    {
+        let mut reader = reader;
+        let new_read_error = |err: io::Error| {
+            Error::new(ErrorKind::Unexpected, "read data from async read")
+                .with_operation("Operator::write_from_async_read")
+                .with_context("path", path)
+                .set_source(err)
+        };
+
+        match size_hint {
+            Some(size) if self.info().capability().write_can_sink => {
+                let mut w = self.writer_with(path).content_length(size).await?;
+
+                // Writer can only sink `'static` streams, so data is forwarded
+                // from reader into writer via a bounded channel.
+                let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+                let forward = async move {
+                    loop {
+                        let mut bs = Vec::with_capacity(ASYNC_READ_CHUNK_SIZE);
+                        let res = match read_into_spare(&mut reader, &mut bs).await {
+                            Ok(0) => return,
+                            Ok(_) => Ok(Bytes::from(bs)),
+                            Err(err) => Err(new_read_error(err)),
+                        };
+                        let failed = res.is_err();
+                        // Channel is closed once sink returns.
+                        if tx.send(res).await.is_err() || failed {
+                            return;
+                        }
+                    }
+                };
+                let stream = stream::poll_fn(move |cx| rx.poll_recv(cx));
+
+                let (_, res) = futures::future::join(forward, w.sink(size, stream)).await;
+                if let Err(err) = res {
+                    // The error of sink is more useful, so error of abort is ignored.
+                    let _ = w.abort().await;
+                    return Err(err);
+                }
+                w.close().await?;
+
+                Ok(size)
+            }
+            _ => {
+                if size_hint.is_none() {
+                    warn!("write {path} without size hint, the whole data will be buffered");
+                }
+
+                let mut bs = Vec::with_capacity(size_hint.unwrap_or_default() as usize);
+                loop {
+                    bs.reserve(ASYNC_READ_CHUNK_SIZE);
+                    let n = read_into_spare(&mut reader, &mut bs)
+                        .await
+                        .map_err(new_read_error)?;
+                    if n == 0 {
+                        break;
+                    }
+                }
+
+                let size = bs.len() as u64;
+                self.write(path, bs).await?;
+                Ok(size)
+            }
+        }
+    }
+
     /// Append bytes into path.
     ///
     /// # Notes
@@ -725,16 +1200,902 @@ impl Operator {
 
         self.inner().copy(&from, &to, OpCopy::new()).await?;
 
-        Ok(())
+        Ok(())
+    }
+
+    /// Copy all files under dir `from` to dir `to` recursively.
+    ///
+    /// Returns the number of files copied.
+    ///
+    /// # Notes
+    ///
+    /// - `from` and `to` must be dirs, and `to` can't be inside `from`.
+    /// - Files under `to` will be overwritten if they exist.
+    /// - Dir markers (entries end with `/`) will be skipped.
+    /// - Server side copy will be used if services support, otherwise
+    ///   files will be streamed from reader into writer. Services that
+    ///   don't support [`Capability::write_can_sink`] will load files into
+    ///   memory one by one instead.
+    /// - At most `max_concurrent` files are copied at the same time, which
+    ///   must be greater than 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let copied = op
+    ///     .copy_dir("data/2024-01/", "data/2024-01-backup/", 8)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_dir(&self, from: &str, to: &str, max_concurrent: usize) -> Result<usize> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+
+        let new_error = |kind: ErrorKind, msg: &str| {
+            Error::new(kind, msg)
+                .with_operation("Operator::copy_dir")
+                .with_context("service", self.info().scheme())
+                .with_context("from", &from)
+                .with_context("to", &to)
+        };
+
+        if !validate_path(&from, EntryMode::DIR) {
+            return Err(new_error(
+                ErrorKind::NotADirectory,
+                "from path is not a directory",
+            ));
+        }
+        if !validate_path(&to, EntryMode::DIR) {
+            return Err(new_error(
+                ErrorKind::NotADirectory,
+                "to path is not a directory",
+            ));
+        }
+        if from == to {
+            return Err(new_error(
+                ErrorKind::IsSameFile,
+                "from and to paths are same",
+            ));
+        }
+        if from == "/" || to.starts_with(&from) {
+            return Err(new_error(
+                ErrorKind::InvalidInput,
+                "to path can't be inside from path",
+            ));
+        }
+        if max_concurrent == 0 {
+            return Err(new_error(
+                ErrorKind::InvalidInput,
+                "max_concurrent must be greater than 0",
+            ));
+        }
+
+        let can_copy = self.info().capability().copy;
+        let (from, to) = (from.as_str(), to.as_str());
+
+        let lister = self.scan(from).await?;
+        let copied = lister
+            .try_filter(|entry| futures::future::ready(!entry.path().ends_with('/')))
+            .map_ok(|entry| async move {
+                // `scan` only returns entries under `from`.
+                let target = format!("{to}{}", &entry.path()[from.len()..]);
+
+                if can_copy {
+                    self.inner()
+                        .copy(entry.path(), &target, OpCopy::new())
+                        .await?;
+                } else {
+                    let size = self
+                        .metadata(&entry, Metakey::ContentLength)
+                        .await?
+                        .content_length();
+                    self.stream_copy(entry.path(), self, &target, size).await?;
+                }
+                Ok::<usize, Error>(1)
+            })
+            .try_buffer_unordered(max_concurrent)
+            .try_fold(0, |acc, n| futures::future::ready(Ok(acc + n)))
+            .await?;
+
+        Ok(copied)
+    }
+
+    /// Copy file `from` into `to` of operator `target` by streaming
+    /// the reader into writer.
+    ///
+    /// Files will be loaded into memory if `target` doesn't support
+    /// `write_can_sink`.
+    async fn stream_copy(&self, from: &str, target: &Operator, to: &str, size: u64) -> Result<()> {
+        if !target.info().capability().write_can_sink {
+            let bs = self.read(from).await?;
+            return target.write(to, bs).await;
+        }
+
+        let r = self.reader(from).await?;
+        let mut w = target.writer_with(to).content_length(size).await?;
+        if let Err(err) = w.copy(size, r).await {
+            // The error of copy is more useful, so error of abort is ignored.
+            let _ = w.abort().await;
+            return Err(err);
+        }
+        w.close().await
+    }
+
+    /// Synchronize files under dir `from` to dir `to` of operator `target`.
+    ///
+    /// Only changed files decided by [`SyncStrategy`] will be copied.
+    ///
+    /// # Notes
+    ///
+    /// - `from` and `to` must be dirs.
+    /// - Files are streamed from current operator into `target`. If `target`
+    ///   doesn't support [`Capability::write_can_sink`], files will be loaded
+    ///   into memory one by one instead.
+    /// - While comparing, at most `max_concurrent` files of `from` and `to`
+    ///   are `stat`ed at the same time respectively. At most `max_concurrent`
+    ///   files are synchronized at the same time. `max_concurrent` must be
+    ///   greater than 0.
+    /// - Files in `to` which don't exist in `from` will only be deleted if
+    ///   [`SyncStrategy::with_delete`] is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use opendal::SyncStrategy;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator, backup: Operator) -> Result<()> {
+    /// let stats = op
+    ///     .sync_dir("data/", &backup, "data/", SyncStrategy::etag(), 8)
+    ///     .await?;
+    /// println!("copied {} files", stats.copied);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sync_dir(
+        &self,
+        from: &str,
+        target: &Operator,
+        to: &str,
+        strategy: SyncStrategy,
+        max_concurrent: usize,
+    ) -> Result<SyncStats> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+
+        for path in [&from, &to] {
+            if !validate_path(path, EntryMode::DIR) {
+                return Err(
+                    Error::new(ErrorKind::NotADirectory, "path is not a directory")
+                        .with_operation("Operator::sync_dir")
+                        .with_context("service", self.info().scheme())
+                        .with_context("path", path),
+                );
+            }
+        }
+        if max_concurrent == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "max_concurrent must be greater than 0",
+            )
+            .with_operation("Operator::sync_dir")
+            .with_context("service", self.info().scheme()));
+        }
+
+        // Entries of root are not prefixed with `/`.
+        let rel_path = |dir: &str, path: &str| -> String {
+            if dir == "/" {
+                path.to_string()
+            } else {
+                path[dir.len()..].to_string()
+            }
+        };
+
+        let (source, existing) = futures::future::try_join(
+            Snapshot::capture_concurrently(self, &from, max_concurrent),
+            Snapshot::capture_concurrently(target, &to, max_concurrent),
+        )
+        .await?;
+        let existing: HashMap<String, &Entry> = existing
+            .iter()
+            .map(|v| (rel_path(&to, v.path()), v))
+            .collect();
+
+        let mut stats = SyncStats::default();
+        let mut pending = Vec::new();
+        let mut synced = HashSet::new();
+        for entry in source.iter() {
+            let rel = rel_path(&from, entry.path());
+            let target_meta = existing.get(&rel).and_then(|v| v.metadata().as_ref());
+            let need_copy = match (entry.metadata(), target_meta) {
+                (Some(source), Some(target)) => strategy.need_copy(source, target),
+                _ => true,
+            };
+
+            if need_copy {
+                let size = match entry.metadata() {
+                    Some(meta) => meta.content_length(),
+                    None => self.stat(entry.path()).await?.content_length(),
+                };
+                pending.push((entry.path().to_string(), format!("{to}{rel}"), size));
+            } else {
+                stats.skipped += 1;
+            }
+            synced.insert(rel);
+        }
+
+        stats.copied = pending.len();
+        stream::iter(pending)
+            .map(Ok)
+            .try_for_each_concurrent(max_concurrent, |(source, target_path, size)| async move {
+                self.stream_copy(&source, target, &target_path, size).await
+            })
+            .await?;
+
+        if strategy.delete() {
+            let deleting: Vec<String> = existing
+                .iter()
+                .filter(|(rel, _)| !synced.contains(*rel))
+                .map(|(_, entry)| entry.path().to_string())
+                .collect();
+
+            stats.deleted = deleting.len();
+            target.remove_via(stream::iter(deleting)).await?;
+        }
+
+        Ok(stats)
+    }
+
+    /// Rename a file from `from` to `to`.
+    ///
+    /// # Notes
+    ///
+    /// - `from` and `to` must be a file.
+    /// - `to` will be overwritten if it exists.
+    /// - If `from` and `to` are the same, an `IsSameFile` error will occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.rename("path/to/file", "path/to/file2").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rename(&self, from: &str, to: &str) -> Result<()> {
+        let from = normalize_path(from);
+
+        if !validate_path(&from, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "from path is a directory")
+                    .with_operation("Operator::move_")
+                    .with_context("service", self.info().scheme())
+                    .with_context("from", from),
+            );
+        }
+
+        let to = normalize_path(to);
+
+        if !validate_path(&to, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "to path is a directory")
+                    .with_operation("Operator::move_")
+                    .with_context("service", self.info().scheme())
+                    .with_context("to", to),
+            );
+        }
+
+        if from == to {
+            return Err(
+                Error::new(ErrorKind::IsSameFile, "from and to paths are same")
+                    .with_operation("Operator::move_")
+                    .with_context("service", self.info().scheme())
+                    .with_context("from", from)
+                    .with_context("to", to),
+            );
+        }
+
+        self.inner().rename(&from, &to, OpRename::new()).await?;
+
+        Ok(())
+    }
+
+    /// Create a symlink at `dst` that points to `src`.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::symlink`].
+    /// - `src` and `dst` must be a file, `src` is not required to exist.
+    /// - `AlreadyExists` will be returned if `dst` exists.
+    /// - Symlinks will be returned with [`EntryMode::SYMLINK`] by `stat`
+    ///   and `list`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.create_symlink("path/to/file", "path/to/link").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_symlink(&self, src: &str, dst: &str) -> Result<()> {
+        let src = normalize_path(src);
+
+        if !validate_path(&src, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "src path is a directory")
+                    .with_operation("Operator::create_symlink")
+                    .with_context("service", self.info().scheme())
+                    .with_context("src", src),
+            );
+        }
+
+        let dst = normalize_path(dst);
+
+        if !validate_path(&dst, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "dst path is a directory")
+                    .with_operation("Operator::create_symlink")
+                    .with_context("service", self.info().scheme())
+                    .with_context("dst", dst),
+            );
+        }
+
+        if src == dst {
+            return Err(
+                Error::new(ErrorKind::IsSameFile, "src and dst paths are same")
+                    .with_operation("Operator::create_symlink")
+                    .with_context("service", self.info().scheme())
+                    .with_context("src", src)
+                    .with_context("dst", dst),
+            );
+        }
+
+        self.inner().create_symlink(&src, &dst).await
+    }
+
+    /// Update the last modified time of a file without changing its content.
+    ///
+    /// # Notes
+    ///
+    /// - `path` must be a file.
+    /// - `NotFound` will be returned if `path` doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.touch("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn touch(&self, path: &str) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(Error::new(ErrorKind::IsADirectory, "path is a directory")
+                .with_operation("Operator::touch")
+                .with_context("service", self.info().scheme())
+                .with_context("path", path));
+        }
+
+        self.inner().touch(&path, OpTouch::new()).await?;
+
+        Ok(())
+    }
+
+    /// Get the lifecycle rules of current bucket.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::lifecycle`].
+    /// - An empty list will be returned if no rules configured.
+    /// - Prefixes of rules are relative to root, only rules whose prefix is
+    ///   under root will be returned. Rules outside of root, including
+    ///   bucket-wide rules, are not managed by this operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let rules = op.get_lifecycle_rules().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_lifecycle_rules(&self) -> Result<Vec<LifecycleRule>> {
+        let rp = self
+            .inner()
+            .get_lifecycle_rules(OpGetLifecycleRules::new())
+            .await?;
+
+        Ok(rp.into_rules())
+    }
+
+    /// Set the lifecycle rules of current bucket.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::lifecycle`].
+    /// - Prefixes of rules are relative to root.
+    /// - Existing rules under root will be replaced by given rules, rules
+    ///   outside of root are kept as is. So the rules returned by
+    ///   [`Operator::get_lifecycle_rules`] can be set back safely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use opendal::Expiration;
+    /// use opendal::LifecycleRule;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.set_lifecycle_rules(vec![LifecycleRule {
+    ///     id: "expire-logs".to_string(),
+    ///     prefix: "logs/".to_string(),
+    ///     enabled: true,
+    ///     transitions: vec![],
+    ///     expiration: Some(Expiration { days: 30 }),
+    /// }])
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_lifecycle_rules(&self, rules: Vec<LifecycleRule>) -> Result<()> {
+        self.inner()
+            .set_lifecycle_rules(OpSetLifecycleRules::new(rules))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get the cors rules of current bucket.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::cors`].
+    /// - An empty list will be returned if no rules configured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let rules = op.get_cors_config().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_cors_config(&self) -> Result<Vec<CorsRule>> {
+        let rp = self.inner().get_cors_config(OpGetCorsConfig::new()).await?;
+
+        Ok(rp.into_rules())
+    }
+
+    /// Set the cors rules of current bucket.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::cors`].
+    /// - All existing rules will be replaced by given rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use opendal::CorsRule;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.set_cors_config(vec![CorsRule {
+    ///     allowed_origins: vec!["https://example.com".to_string()],
+    ///     allowed_methods: vec!["GET".to_string(), "HEAD".to_string()],
+    ///     allowed_headers: vec!["*".to_string()],
+    ///     expose_headers: vec!["ETag".to_string()],
+    ///     max_age_seconds: 3600,
+    /// }])
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_cors_config(&self, rules: Vec<CorsRule>) -> Result<()> {
+        self.inner()
+            .set_cors_config(OpSetCorsConfig::new(rules))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get the acl of current bucket.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::bucket_acl`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let acl = op.get_bucket_acl().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_bucket_acl(&self) -> Result<BucketAcl> {
+        let rp = self.inner().get_bucket_acl(OpGetBucketAcl::new()).await?;
+
+        Ok(rp.into_acl())
+    }
+
+    /// Set the acl of current bucket.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::bucket_acl`].
+    /// - All existing grants will be replaced by given grants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use opendal::AclGrant;
+    /// use opendal::AclGrantee;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut acl = op.get_bucket_acl().await?;
+    /// acl.grants.push(AclGrant {
+    ///     grantee: AclGrantee::Everyone,
+    ///     permission: "READ".to_string(),
+    /// });
+    /// op.set_bucket_acl(acl).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_bucket_acl(&self, acl: BucketAcl) -> Result<()> {
+        self.inner()
+            .set_bucket_acl(OpSetBucketAcl::new(acl))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get the tags of given file.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::tagging`].
+    /// - An empty map will be returned if no tags set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let tags = op.get_tags("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tags(&self, path: &str) -> Result<HashMap<String, String>> {
+        let path = self.tags_path(path, "Operator::get_tags")?;
+
+        let rp = self.inner().get_tags(&path, OpGetTags::new()).await?;
+        Ok(rp.into_tags())
+    }
+
+    /// Set the tags of given file.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::tagging`].
+    /// - All existing tags will be replaced by given tags. Use
+    ///   [`Operator::add_tags`] or [`Operator::remove_tags`] to update only
+    ///   part of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use std::collections::HashMap;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let tags = HashMap::from([("project".to_string(), "opendal".to_string())]);
+    /// op.set_tags("path/to/file", tags).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_tags(&self, path: &str, tags: HashMap<String, String>) -> Result<()> {
+        let path = self.tags_path(path, "Operator::set_tags")?;
+
+        self.inner().set_tags(&path, OpSetTags::new(tags)).await?;
+        Ok(())
+    }
+
+    /// Update the user metadata of given file without rewriting its
+    /// content.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::update_metadata`].
+    /// - All existing user metadata will be replaced by given metadata.
+    /// - `NotFound` will be returned if `path` doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use std::collections::HashMap;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let metadata = HashMap::from([("owner".to_string(), "opendal".to_string())]);
+    /// op.update_metadata("path/to/file", metadata).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_metadata(
+        &self,
+        path: &str,
+        metadata: HashMap<String, String>,
+    ) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(Error::new(ErrorKind::IsADirectory, "path is a directory")
+                .with_operation("Operator::update_metadata")
+                .with_context("service", self.info().scheme())
+                .with_context("path", path));
+        }
+
+        self.inner()
+            .update_metadata(&path, OpUpdateMetadata::new(metadata))
+            .await?;
+        Ok(())
+    }
+
+    /// Lock given file with retention, the file can't be overwritten or
+    /// deleted until the lock expired.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::object_lock`].
+    /// - `Unsupported` will be returned if the lock mode is not supported
+    ///   by the service.
+    /// - Deleting a locked file will return [`ErrorKind::ObjectLocked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use chrono::Duration;
+    /// # use chrono::Utc;
+    /// # use opendal::LockMode;
+    /// # use opendal::ObjectLockConfig;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let config = ObjectLockConfig::new(LockMode::Compliance, Utc::now() + Duration::days(30));
+    /// op.lock_object("path/to/file", config).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn lock_object(&self, path: &str, config: ObjectLockConfig) -> Result<()> {
+        let path = self.tags_path(path, "Operator::lock_object")?;
+
+        self.inner()
+            .lock_object(&path, OpLockObject::new(config))
+            .await?;
+        Ok(())
+    }
+
+    /// Get the lock of given file, `None` will be returned if the file is
+    /// not locked.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::object_lock`].
+    /// - The returned lock could be expired already, use
+    ///   [`ObjectLockConfig::is_active`] to check it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// if let Some(lock) = op.get_object_lock("path/to/file").await? {
+    ///     println!("locked until {}", lock.retain_until);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_object_lock(&self, path: &str) -> Result<Option<ObjectLockConfig>> {
+        let path = self.tags_path(path, "Operator::get_object_lock")?;
+
+        let rp = self
+            .inner()
+            .get_object_lock(&path, OpGetObjectLock::new())
+            .await?;
+        Ok(rp.into_config())
+    }
+
+    /// Add tags to given file, existing tags with the same keys will be
+    /// overwritten while others are kept.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::tagging`].
+    /// - Adding tags that already exist is a no-op.
+    /// - See [`Operator::remove_tags`] for the consistency of updating tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use std::collections::HashMap;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let tags = HashMap::from([("env".to_string(), "dev".to_string())]);
+    /// op.add_tags("path/to/file", tags).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_tags(&self, path: &str, tags: HashMap<String, String>) -> Result<()> {
+        let path = self.tags_path(path, "Operator::add_tags")?;
+
+        self.update_tags(&path, "Operator::add_tags", |current| {
+            current.extend(tags.clone())
+        })
+        .await
+    }
+
+    /// Remove tags with given keys from given file, other tags are kept.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::tagging`].
+    /// - Removing tags that don't exist is a no-op.
+    ///
+    /// # Consistency
+    ///
+    /// Tags are updated by get, merge and set. Services don't support
+    /// setting tags conditionally (OBS ignores `If-Match` on tagging), so
+    /// tags are read again right before setting. If they have been changed
+    /// in the meantime, the update will be merged into the latest tags and
+    /// retried. An error with [`ErrorKind::ConditionNotMatch`] will be
+    /// returned if tags keep changing after 3 attempts.
+    ///
+    /// This narrows but doesn't close the window of concurrent updates:
+    /// tags changed between the last read and the set will still be
+    /// overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.remove_tags("path/to/file", &["env"]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_tags(&self, path: &str, keys: &[&str]) -> Result<()> {
+        let path = self.tags_path(path, "Operator::remove_tags")?;
+
+        self.update_tags(&path, "Operator::remove_tags", |current| {
+            for key in keys {
+                current.remove(*key);
+            }
+        })
+        .await
+    }
+
+    fn tags_path(&self, path: &str, op: &'static str) -> Result<String> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(Error::new(ErrorKind::IsADirectory, "path is a directory")
+                .with_operation(op)
+                .with_context("service", self.info().scheme())
+                .with_context("path", path));
+        }
+
+        Ok(path)
+    }
+
+    async fn update_tags(
+        &self,
+        path: &str,
+        op: &'static str,
+        f: impl Fn(&mut HashMap<String, String>),
+    ) -> Result<()> {
+        let get_tags = || async {
+            let rp = self.inner().get_tags(path, OpGetTags::new()).await?;
+            Ok::<_, Error>(rp.into_tags())
+        };
+
+        let mut current = get_tags().await?;
+        for _ in 0..UPDATE_TAGS_MAX_ATTEMPTS {
+            let mut tags = current.clone();
+            f(&mut tags);
+            if tags == current {
+                return Ok(());
+            }
+
+            // Tags changed since last read must be merged again, otherwise
+            // they will be overwritten.
+            let latest = get_tags().await?;
+            if latest != current {
+                current = latest;
+                continue;
+            }
+
+            self.inner().set_tags(path, OpSetTags::new(tags)).await?;
+            return Ok(());
+        }
+
+        Err(Error::new(
+            ErrorKind::ConditionNotMatch,
+            "tags are changed concurrently while updating",
+        )
+        .with_operation(op)
+        .with_context("service", self.info().scheme())
+        .with_context("path", path)
+        .with_context("attempts", UPDATE_TAGS_MAX_ATTEMPTS.to_string()))
     }
 
-    /// Rename a file from `from` to `to`.
+    /// Get the restore status of given archived file.
     ///
     /// # Notes
     ///
-    /// - `from` and `to` must be a file.
-    /// - `to` will be overwritten if it exists.
-    /// - If `from` and `to` are the same, an `IsSameFile` error will occur.
+    /// - Require [`Capability::restore_status`].
+    /// - Files that are neither being restored nor restored will return
+    ///   `in_progress` as `false` without expiry.
     ///
     /// # Examples
     ///
@@ -744,46 +2105,87 @@ impl Operator {
     ///
     /// # #[tokio::main]
     /// # async fn test(op: Operator) -> Result<()> {
-    /// op.rename("path/to/file", "path/to/file2").await?;
+    /// let status = op.get_restore_status("path/to/file").await?;
+    /// if status.in_progress {
+    ///     println!("file is still being restored");
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn rename(&self, from: &str, to: &str) -> Result<()> {
-        let from = normalize_path(from);
+    pub async fn get_restore_status(&self, path: &str) -> Result<RestoreStatus> {
+        let path = normalize_path(path);
 
-        if !validate_path(&from, EntryMode::FILE) {
-            return Err(
-                Error::new(ErrorKind::IsADirectory, "from path is a directory")
-                    .with_operation("Operator::move_")
-                    .with_context("service", self.info().scheme())
-                    .with_context("from", from),
-            );
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(Error::new(ErrorKind::IsADirectory, "path is a directory")
+                .with_operation("Operator::get_restore_status")
+                .with_context("service", self.info().scheme())
+                .with_context("path", path));
         }
 
-        let to = normalize_path(to);
-
-        if !validate_path(&to, EntryMode::FILE) {
-            return Err(
-                Error::new(ErrorKind::IsADirectory, "to path is a directory")
-                    .with_operation("Operator::move_")
-                    .with_context("service", self.info().scheme())
-                    .with_context("to", to),
-            );
-        }
+        let rp = self
+            .inner()
+            .get_restore_status(&path, OpGetRestoreStatus::new())
+            .await?;
+        Ok(rp.into_status())
+    }
 
-        if from == to {
-            return Err(
-                Error::new(ErrorKind::IsSameFile, "from and to paths are same")
-                    .with_operation("Operator::move_")
-                    .with_context("service", self.info().scheme())
-                    .with_context("from", from)
-                    .with_context("to", to),
-            );
-        }
+    /// Poll the restore status of given file every `interval` until the
+    /// restoration finished.
+    ///
+    /// A temporary error will be returned if the restoration is still in
+    /// progress after `timeout`.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::restore_status`].
+    /// - Restoration of archived files could take hours, please choose
+    ///   `interval` wisely to avoid sending too many requests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use std::time::Duration;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.poll_restore(
+    ///     "path/to/file",
+    ///     Duration::from_secs(60),
+    ///     Duration::from_secs(6 * 3600),
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn poll_restore(
+        &self,
+        path: &str,
+        interval: Duration,
+        timeout: Duration,
+    ) -> Result<()> {
+        let start = std::time::Instant::now();
+
+        loop {
+            if !self.get_restore_status(path).await?.in_progress {
+                return Ok(());
+            }
 
-        self.inner().rename(&from, &to, OpRename::new()).await?;
+            if start.elapsed() + interval > timeout {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "restoration is still in progress after timeout",
+                )
+                .with_operation("Operator::poll_restore")
+                .with_context("service", self.info().scheme())
+                .with_context("path", path)
+                .with_context("timeout", timeout.as_secs_f64().to_string())
+                .set_temporary());
+            }
 
-        Ok(())
+            tokio::time::sleep(interval).await;
+        }
     }
 
     /// Write multiple bytes into path.
@@ -920,6 +2322,45 @@ impl Operator {
         fut
     }
 
+    /// Write bytes into path only if the path doesn't exist.
+    ///
+    /// Returns `true` if the file has been written, or `false` if the file
+    /// already exists. This is useful for init-once patterns like writing a
+    /// config file the first time a service starts.
+    ///
+    /// # Notes
+    ///
+    /// This operation requires [`Capability::write_with_if_none_match`], it's
+    /// the same as `write_with(path, bs).if_none_match("*")`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// if op.write_if_not_exists("path/to/config", "init").await? {
+    ///     println!("config initialized");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_if_not_exists(&self, path: &str, bs: impl Into<Bytes>) -> Result<bool> {
+        match self.write_with(path, bs).if_none_match("*").await {
+            Ok(()) => Ok(true),
+            Err(err)
+                if matches!(
+                    err.kind(),
+                    ErrorKind::AlreadyExists | ErrorKind::ConditionNotMatch
+                ) =>
+            {
+                Ok(false)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     /// Append multiple bytes into path.
     ///
     /// Refer to [`Appender`] for more details.
@@ -1115,6 +2556,32 @@ impl Operator {
         fut
     }
 
+    /// Delete the given version of path.
+    ///
+    /// This is a shortcut of `op.delete_with(path).version(version)`.
+    ///
+    /// # Notes
+    ///
+    /// - Deleting a version that does not exist won't return errors.
+    /// - Other versions of this path will not be touched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.delete_version("test", "<version>").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_version(&self, path: &str, version: &str) -> Result<()> {
+        self.delete_with(path).version(version).await
+    }
+
+    /// Remove all given paths.
     ///
     /// # Notes
     ///
@@ -1266,6 +2733,81 @@ impl Operator {
         Ok(())
     }
 
+    /// Begin a new transaction which applies writes and deletes together.
+    ///
+    /// Changes are collected in memory and applied by
+    /// [`TransactionHandle::commit`]. Read [`TransactionHandle`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut txn = op.begin_transaction();
+    /// txn.write("path/to/file", "Hello, World!");
+    /// txn.commit().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn begin_transaction(&self) -> TransactionHandle {
+        TransactionHandle::new(self.clone())
+    }
+
+    /// Watch changes of given path by polling every `poll_interval`.
+    ///
+    /// If path is a dir (ends with `/`), entries in it will be watched.
+    /// Read [`Watcher`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anyhow::Result;
+    /// # use futures::StreamExt;
+    /// # use opendal::Operator;
+    /// # use opendal::WatchEvent;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut watcher = op.watch("path/to/config", Duration::from_secs(5));
+    /// while let Some(event) = watcher.next().await {
+    ///     if let WatchEvent::Modified(_) = event {
+    ///         // Reload config.
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch(&self, path: &str, poll_interval: Duration) -> Watcher {
+        self.watch_with_config(path, WatchConfig::new(poll_interval))
+    }
+
+    /// Watch changes of given path with config.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # use opendal::WatchConfig;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let watcher = op.watch_with_config(
+    ///     "path/to/dir/",
+    ///     WatchConfig::new(Duration::from_secs(5)).with_initial_state(false),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_with_config(&self, path: &str, config: WatchConfig) -> Watcher {
+        Watcher::new(self.clone(), &normalize_path(path), config)
+    }
+
     /// List given path.
     ///
     /// This function will create a new handle to list entries.
@@ -1293,7 +2835,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::SYMLINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1336,7 +2878,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::SYMLINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1366,7 +2908,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::SYMLINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1391,7 +2933,20 @@ impl Operator {
                         .with_context("path", &path));
                     }
 
-                    let (_, pager) = inner.list(&path, args).await?;
+                    let (min_size, max_size) = (args.min_size(), args.max_size());
+                    let sort_by = args.sort_by();
+                    let (_, mut pager) = inner.list(&path, args).await?;
+                    if min_size.is_some() || max_size.is_some() {
+                        pager = Box::new(oio::to_size_filter_pager(
+                            inner.clone(),
+                            pager,
+                            min_size,
+                            max_size,
+                        ));
+                    }
+                    if let Some((by, order)) = sort_by {
+                        pager = Box::new(oio::to_sorted_pager(inner, pager, by, order));
+                    }
 
                     Ok(Lister::new(pager))
                 };
@@ -1434,7 +2989,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::SYMLINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1443,6 +2998,84 @@ impl Operator {
     pub async fn scan(&self, path: &str) -> Result<Lister> {
         self.list_with(path).delimiter("").await
     }
+
+    /// List all versions of objects under given path.
+    ///
+    /// The given path will be used as prefix, so both file and dir path
+    /// are accepted. Every returned entry carries its version which can
+    /// be fetched via [`Metadata::version`].
+    ///
+    /// # Notes
+    ///
+    /// This operation requires [`Capability::versioning`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Metakey;
+    /// use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ds = op.list_versions("path/to/file").await?;
+    /// while let Some(de) = ds.try_next().await? {
+    ///     let meta = op.metadata(&de, Metakey::Version).await?;
+    ///     println!("{} has version {:?}", de.path(), meta.version());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_versions(&self, path: &str) -> Result<Lister> {
+        self.list_versions_with(path).await
+    }
+
+    /// List all versions of objects under given path with extra options.
+    ///
+    /// # Notes
+    ///
+    /// This operation requires [`Capability::versioning`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ds = op
+    ///     .list_versions_with("path/to/file")
+    ///     .limit(100)
+    ///     .delete_markers(true)
+    ///     .await?;
+    /// while let Some(de) = ds.try_next().await? {
+    ///     println!("{}", de.path());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_versions_with(&self, path: &str) -> FutureListVersions {
+        let path = normalize_path(path);
+
+        let fut = FutureListVersions(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpListVersions::default(),
+            |inner, path, args| {
+                let fut = async move {
+                    let (_, pager) = inner.list_versions(&path, args).await?;
+                    Ok(Lister::new(pager))
+                };
+
+                Box::pin(fut)
+            },
+        ));
+
+        fut
+    }
 }
 /// Operator presign API.
 impl Operator {
@@ -1623,4 +3256,121 @@ impl Operator {
         ));
         fut
     }
+
+    /// Presign a form upload which allows uploading file to path via html
+    /// form in the browser.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let post = op.presign_post("test.txt", Duration::from_secs(3600)).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// - `post.url()`: `https://examplebucket.obs.cn-north-4.myhuaweicloud.com`
+    /// - `post.fields()`: `{ "key": "test.txt", "policy": "<policy>", "AccessKeyId": "<access_key_id>", "signature": "<signature>" }`
+    ///
+    /// The form should carry all fields as hidden inputs and the file as the
+    /// last field named `file`:
+    ///
+    /// ```html
+    /// <form action="https://examplebucket.obs.cn-north-4.myhuaweicloud.com" method="post" enctype="multipart/form-data">
+    ///   <input type="hidden" name="key" value="test.txt" />
+    ///   <!-- other fields -->
+    ///   <input type="file" name="file" />
+    /// </form>
+    /// ```
+    pub async fn presign_post(&self, path: &str, expire: Duration) -> Result<PresignedPost> {
+        self.presign_post_with(path, expire).await
+    }
+
+    /// Presign a form upload with extra fields and policy conditions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::raw::PolicyCondition;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let post = op
+    ///         .presign_post_with("test.txt", Duration::from_secs(3600))
+    ///         .field("content-type", "text/plain")
+    ///         .condition(PolicyCondition::ContentLengthRange(0, 1024 * 1024))
+    ///         .await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn presign_post_with(&self, path: &str, expire: Duration) -> FuturePresignPost {
+        let path = normalize_path(path);
+
+        let fut = FuturePresignPost(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpPresignPost::new(expire),
+            |inner, path, args| {
+                let fut = async move {
+                    if !validate_path(&path, EntryMode::FILE) {
+                        return Err(Error::new(ErrorKind::IsADirectory, "path is a directory")
+                            .with_operation("Operator::presign_post")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("path", path));
+                    }
+
+                    let rp = inner.presign_post(&path, args).await?;
+                    Ok(rp.into_presigned_post())
+                };
+                Box::pin(fut)
+            },
+        ));
+        fut
+    }
+}
+
+/// Size of chunks read from the reader of [`Operator::write_from_async_read`].
+const ASYNC_READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Max attempts of [`Operator::add_tags`] and [`Operator::remove_tags`]
+/// while tags are changed concurrently.
+const UPDATE_TAGS_MAX_ATTEMPTS: usize = 3;
+
+/// Read data from `r` into the spare capacity of `buf`, returns the size
+/// of read data. `0` means the reader has reached EOF.
+async fn read_into_spare<R>(r: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    let len = buf.len();
+    let mut rb = ReadBuf::uninit(buf.spare_capacity_mut());
+    futures::future::poll_fn(|cx| Pin::new(&mut *r).poll_read(cx, &mut rb)).await?;
+    let n = rb.filled().len();
+
+    // Safety: `n` bytes after `len` have been initialized by the reader.
+    unsafe { buf.set_len(len + n) };
+    Ok(n)
+}
+
+/// Convert errors returned while operating on local files.
+#[cfg(not(target_arch = "wasm32"))]
+fn new_local_io_error(err: io::Error, op: &'static str, local_path: &Path) -> Error {
+    let kind = match err.kind() {
+        io::ErrorKind::NotFound => ErrorKind::NotFound,
+        io::ErrorKind::PermissionDenied => ErrorKind::PermissionDenied,
+        _ => ErrorKind::Unexpected,
+    };
+
+    Error::new(kind, "operate on local file failed")
+        .with_operation(op)
+        .with_context("local_path", local_path.display().to_string())
+        .set_source(err)
 }
@@ -114,3 +114,74 @@ impl FunctionDelete {
         self.0.call()
     }
 }
+
+/// Function that generated by [`BlockingOperator::list_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FunctionList(pub(crate) OperatorFunction<OpList, BlockingLister>);
+
+impl FunctionList {
+    /// Change the limit of this list operation.
+    pub fn limit(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_limit(v));
+        self
+    }
+
+    /// Change the start_after of this list operation.
+    pub fn start_after(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_start_after(v));
+        self
+    }
+
+    /// Only return entries under given prefix.
+    ///
+    /// Refer to [`crate::operator_futures::FutureList::prefix_filter`] for more details.
+    pub fn prefix_filter(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_prefix_filter(v));
+        self
+    }
+
+    /// Change the delimiter. The default delimiter is "/"
+    pub fn delimiter(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_delimiter(v));
+        self
+    }
+
+    /// Only return files whose content length is not less than given size.
+    ///
+    /// Size filtering happens at client side, all pages are still fetched.
+    pub fn min_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_min_size(v));
+        self
+    }
+
+    /// Only return files whose content length is not greater than given size.
+    ///
+    /// Size filtering happens at client side, all pages are still fetched.
+    pub fn max_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_size(v));
+        self
+    }
+
+    /// Change the error policy of this list operation.
+    ///
+    /// Refer to [`ErrorPolicy`] for more details.
+    pub fn error_policy(mut self, v: ErrorPolicy) -> Self {
+        self.0 = self.0.map_args(|args| args.with_error_policy(v));
+        self
+    }
+
+    /// Sort returned entries by given key and order.
+    ///
+    /// Refer to [`crate::operator_futures::FutureList::sort_by`] for more details.
+    pub fn sort_by(mut self, by: SortBy, order: SortOrder) -> Self {
+        self.0 = self.0.map_args(|args| args.with_sort_by(by, order));
+        self
+    }
+
+    /// Call the function to consume all the input and generate a
+    /// result.
+    pub fn call(self) -> Result<BlockingLister> {
+        self.0.call()
+    }
+}
@@ -324,6 +324,34 @@ impl Future for FuturePresignWrite {
     }
 }
 
+/// Future that generated by [`Operator::presign_post_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FuturePresignPost(pub(crate) OperatorFuture<OpPresignPost, PresignedPost>);
+
+impl FuturePresignPost {
+    /// Add a form field which must be carried by the form, for example
+    /// `content-type`.
+    pub fn field(mut self, key: &str, value: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_field(key, value));
+        self
+    }
+
+    /// Add a condition that the form upload must match.
+    pub fn condition(mut self, condition: PolicyCondition) -> Self {
+        self.0 = self.0.map_args(|args| args.with_condition(condition));
+        self
+    }
+}
+
+impl Future for FuturePresignPost {
+    type Output = Result<PresignedPost>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
 /// Future that generated by [`Operator::read_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
@@ -418,6 +446,14 @@ impl FutureReader {
         self.0 = self.0.map_args(|args| args.with_if_none_match(v));
         self
     }
+
+    /// Set the buffer size hint for this operation.
+    ///
+    /// Services that don't support it will ignore this hint.
+    pub fn buffer_size(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_buffer_size(v));
+        self
+    }
 }
 
 impl Future for FutureReader {
@@ -461,6 +497,17 @@ impl FutureWrite {
         self
     }
 
+    /// Set the content encoding of option
+    ///
+    /// See [`OpWrite::with_content_encoding`] for the difference with
+    /// content type.
+    pub fn content_encoding(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_content_encoding(v), bs));
+        self
+    }
+
     /// Set the content type of option
     pub fn cache_control(mut self, v: &str) -> Self {
         self.0 = self
@@ -468,6 +515,46 @@ impl FutureWrite {
             .map_args(|(args, bs)| (args.with_cache_control(v), bs));
         self
     }
+
+    /// Set the If-None-Match for this operation.
+    ///
+    /// Use `*` to write only if the file doesn't exist.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_if_none_match(v), bs));
+        self
+    }
+
+    /// Set the expected checksum for this operation.
+    ///
+    /// The write will fail with [`ErrorKind::InvalidData`] if the content
+    /// doesn't match it.
+    pub fn expected_checksum(mut self, v: Checksum) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_expected_checksum(v), bs));
+        self
+    }
+
+    /// Compute the md5 of content and set it as expected checksum for this
+    /// operation.
+    pub fn compute_content_md5(mut self) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.compute_content_md5(&bs), bs));
+        self
+    }
+
+    /// Set the multipart threshold for this operation.
+    ///
+    /// See [`OpWrite::with_multipart_threshold`] for details.
+    pub fn multipart_threshold(mut self, v: u64) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_multipart_threshold(v), bs));
+        self
+    }
 }
 
 impl Future for FutureWrite {
@@ -505,11 +592,55 @@ impl FutureWriter {
         self
     }
 
+    /// Set the content encoding of option
+    ///
+    /// See [`OpWrite::with_content_encoding`] for the difference with
+    /// content type.
+    pub fn content_encoding(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_content_encoding(v));
+        self
+    }
+
     /// Set the content type of option
     pub fn cache_control(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_cache_control(v));
         self
     }
+
+    /// Set the If-None-Match for this operation.
+    ///
+    /// Use `*` to write only if the file doesn't exist.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_none_match(v));
+        self
+    }
+
+    /// Set the expected checksum for this operation.
+    ///
+    /// The write will fail with [`ErrorKind::InvalidData`] if the content
+    /// doesn't match it.
+    pub fn expected_checksum(mut self, v: Checksum) -> Self {
+        self.0 = self.0.map_args(|args| args.with_expected_checksum(v));
+        self
+    }
+
+    /// Set the raw md5 digest of content as expected checksum for this
+    /// operation.
+    ///
+    /// Content written by writer isn't known upfront, so the md5 must be
+    /// pre-computed by callers.
+    pub fn known_content_md5(mut self, md5: [u8; 16]) -> Self {
+        self.0 = self.0.map_args(|args| args.with_known_content_md5(md5));
+        self
+    }
+
+    /// Set the multipart threshold for this operation.
+    ///
+    /// See [`OpWrite::with_multipart_threshold`] for details.
+    pub fn multipart_threshold(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_multipart_threshold(v));
+        self
+    }
 }
 
 impl Future for FutureWriter {
@@ -559,11 +690,59 @@ impl FutureList {
         self
     }
 
+    /// Only return entries under given prefix.
+    ///
+    /// The prefix is relative to root like the listing path and must be
+    /// under it, for example, `dir/a` while listing `dir/`. The filter is
+    /// applied by service, entries outside of prefix will not be fetched
+    /// at all.
+    pub fn prefix_filter(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_prefix_filter(v));
+        self
+    }
+
     /// Change the delimiter. The default delimiter is "/"
     pub fn delimiter(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_delimiter(v));
         self
     }
+
+    /// Only return files whose content length is not less than given size.
+    ///
+    /// Size filtering happens at client side, all pages are still fetched.
+    pub fn min_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_min_size(v));
+        self
+    }
+
+    /// Only return files whose content length is not greater than given size.
+    ///
+    /// Size filtering happens at client side, all pages are still fetched.
+    pub fn max_size(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_size(v));
+        self
+    }
+
+    /// Change the error policy of this list operation.
+    ///
+    /// Refer to [`ErrorPolicy`] for more details.
+    pub fn error_policy(mut self, v: ErrorPolicy) -> Self {
+        self.0 = self.0.map_args(|args| args.with_error_policy(v));
+        self
+    }
+
+    /// Sort returned entries by given key and order.
+    ///
+    /// # Notes
+    ///
+    /// Services like `obs` always return entries sorted by path, so sorting
+    /// happens at client side: all entries will be buffered in memory
+    /// before the first one is returned. For large buckets, please narrow
+    /// the listing via prefix before sorting.
+    pub fn sort_by(mut self, by: SortBy, order: SortOrder) -> Self {
+        self.0 = self.0.map_args(|args| args.with_sort_by(by, order));
+        self
+    }
 }
 
 impl Future for FutureList {
@@ -573,3 +752,30 @@ impl Future for FutureList {
         self.0.poll_unpin(cx)
     }
 }
+
+/// Future that generated by [`Operator::list_versions_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureListVersions(pub(crate) OperatorFuture<OpListVersions, Lister>);
+
+impl FutureListVersions {
+    /// Change the limit of this list versions operation.
+    pub fn limit(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_limit(v));
+        self
+    }
+
+    /// Change whether to return delete markers.
+    pub fn delete_markers(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_delete_markers(v));
+        self
+    }
+}
+
+impl Future for FutureListVersions {
+    type Output = Result<Lister>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
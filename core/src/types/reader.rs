@@ -22,6 +22,7 @@ use std::task::Context;
 use std::task::Poll;
 
 use bytes::Bytes;
+use bytes::BytesMut;
 use futures::AsyncRead;
 use futures::AsyncSeek;
 use futures::Stream;
@@ -69,10 +70,31 @@ impl Reader {
     pub(crate) async fn create_dir(acc: FusedAccessor, path: &str, op: OpRead) -> Result<Self> {
         let (_, r) = acc.read(path, op).await?;
 
-        Ok(Reader {
-            inner: r,
+        Ok(Reader::from_oio_reader(r))
+    }
+
+    /// Create a reader from an existing [`oio::Reader`].
+    pub(crate) fn from_oio_reader(inner: oio::Reader) -> Self {
+        Reader {
+            inner,
             seek_state: SeekState::Init,
-        })
+        }
+    }
+
+    /// Convert this reader into a [`ChunkedReader`] which yields `Bytes`
+    /// of `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    pub fn into_chunked_reader(self, chunk_size: usize) -> ChunkedReader {
+        ChunkedReader::new(self, chunk_size)
+    }
+
+    /// Convert this reader into a [`PeekableReader`] which allows reading
+    /// the first bytes without consuming them.
+    pub fn into_peekable_reader(self) -> PeekableReader {
+        PeekableReader::new(self)
     }
 }
 
@@ -177,6 +199,218 @@ impl Stream for Reader {
     }
 }
 
+/// ChunkedReader is a stream that reads data from [`Reader`] in fixed-size
+/// chunks.
+///
+/// Every chunk yielded by ChunkedReader will have exactly `chunk_size`
+/// bytes except the last one which could be smaller. Only one chunk will
+/// be buffered in memory at the same time.
+///
+/// ChunkedReader can be created by [`Reader::into_chunked_reader`].
+pub struct ChunkedReader {
+    inner: Reader,
+    chunk_size: usize,
+
+    buf: BytesMut,
+    filled: usize,
+    done: bool,
+}
+
+impl ChunkedReader {
+    fn new(inner: Reader, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than 0");
+
+        Self {
+            inner,
+            chunk_size,
+
+            buf: BytesMut::zeroed(chunk_size),
+            filled: 0,
+            done: false,
+        }
+    }
+
+    /// Take the filled data out as a chunk.
+    fn take_chunk(&mut self) -> Bytes {
+        let bs = self.buf.split_to(self.filled).freeze();
+        self.buf.resize(self.chunk_size, 0);
+        self.filled = 0;
+        bs
+    }
+}
+
+impl Stream for ChunkedReader {
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        while !this.done && this.filled < this.chunk_size {
+            let n = ready!(oio::Read::poll_read(
+                &mut this.inner,
+                cx,
+                &mut this.buf[this.filled..]
+            ))?;
+            if n == 0 {
+                this.done = true;
+            }
+            this.filled += n;
+        }
+
+        if this.filled == 0 {
+            return Poll::Ready(None);
+        }
+        Poll::Ready(Some(Ok(this.take_chunk())))
+    }
+}
+
+/// PeekableReader is a reader that allows peeking data from [`Reader`]
+/// without consuming it.
+///
+/// Peeked data will be buffered in memory and replayed by following reads,
+/// so that callers can detect the format of file by magic bytes and then
+/// read the whole file without sending another request.
+///
+/// PeekableReader implements `AsyncRead`, `tokio::io::AsyncRead` and
+/// `Stream<Item = io::Result<Bytes>>`. Seeking is only supported via
+/// [`oio::Read`], and the peeked data will be dropped after seeking.
+///
+/// PeekableReader can be created by [`Reader::into_peekable_reader`].
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// # use opendal::Operator;
+/// use futures::AsyncReadExt;
+///
+/// # #[tokio::main]
+/// # async fn test(op: Operator) -> Result<()> {
+/// let mut r = op.reader("path/to/file").await?.into_peekable_reader();
+/// let is_gzip = r.peek(2).await? == [0x1f, 0x8b];
+///
+/// // The peeked bytes will be read again.
+/// let mut bs = vec![];
+/// r.read_to_end(&mut bs).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PeekableReader {
+    inner: Reader,
+    /// Data that has been peeked but not consumed yet.
+    buf: BytesMut,
+}
+
+impl PeekableReader {
+    fn new(inner: Reader) -> Self {
+        Self {
+            inner,
+            buf: BytesMut::new(),
+        }
+    }
+
+    /// Peek at most `n` bytes without consuming them.
+    ///
+    /// Less than `n` bytes will be returned only if the reader reaches EOF.
+    pub async fn peek(&mut self, n: usize) -> Result<&[u8]> {
+        while self.buf.len() < n {
+            let m = futures::future::poll_fn(|cx| self.poll_fill(cx, n)).await?;
+            if m == 0 {
+                break;
+            }
+        }
+
+        Ok(&self.buf[..n.min(self.buf.len())])
+    }
+
+    /// Read from inner reader until `buf` has `n` bytes.
+    ///
+    /// Read data is appended into `buf` before returning, so nothing will
+    /// be lost if the `peek` future is dropped.
+    fn poll_fill(&mut self, cx: &mut Context<'_>, n: usize) -> Poll<Result<usize>> {
+        let mut chunk = vec![0; n - self.buf.len()];
+        let m = ready!(oio::Read::poll_read(&mut self.inner, cx, &mut chunk))?;
+        self.buf.extend_from_slice(&chunk[..m]);
+        Poll::Ready(Ok(m))
+    }
+}
+
+impl oio::Read for PeekableReader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        if self.buf.is_empty() {
+            return self.inner.poll_read(cx, buf);
+        }
+
+        let n = buf.len().min(self.buf.len());
+        buf[..n].copy_from_slice(&self.buf.split_to(n));
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: io::SeekFrom) -> Poll<Result<u64>> {
+        // The inner reader is ahead of the caller by the peeked bytes.
+        let pos = match pos {
+            io::SeekFrom::Current(n) => io::SeekFrom::Current(n - self.buf.len() as i64),
+            pos => pos,
+        };
+
+        let n = ready!(self.inner.poll_seek(cx, pos))?;
+        self.buf.clear();
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        if self.buf.is_empty() {
+            return self.inner.poll_next(cx);
+        }
+
+        Poll::Ready(Some(Ok(self.buf.split().freeze())))
+    }
+}
+
+impl AsyncRead for PeekableReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.buf.is_empty() {
+            return AsyncRead::poll_read(Pin::new(&mut self.inner), cx, buf);
+        }
+
+        let n = buf.len().min(self.buf.len());
+        buf[..n].copy_from_slice(&self.buf.split_to(n));
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl tokio::io::AsyncRead for PeekableReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if self.buf.is_empty() {
+            return tokio::io::AsyncRead::poll_read(Pin::new(&mut self.inner), cx, buf);
+        }
+
+        let n = buf.remaining().min(self.buf.len());
+        buf.put_slice(&self.buf.split_to(n));
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Stream for PeekableReader {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.buf.is_empty() {
+            return Stream::poll_next(Pin::new(&mut self.inner), cx);
+        }
+
+        Poll::Ready(Some(Ok(self.buf.split().freeze())))
+    }
+}
+
 /// BlockingReader is designed to read data from given path in an blocking
 /// manner.
 pub struct BlockingReader {
@@ -259,12 +493,15 @@ impl Iterator for BlockingReader {
 
 #[cfg(test)]
 mod tests {
+    use bytes::Bytes;
+    use futures::TryStreamExt;
     use rand::rngs::ThreadRng;
     use rand::Rng;
     use rand::RngCore;
     use tokio::io::AsyncReadExt;
     use tokio::io::AsyncSeekExt;
 
+    use super::*;
     use crate::services;
     use crate::Operator;
 
@@ -325,4 +562,175 @@ mod tests {
             .expect("read to end must succeed");
         assert_eq!(buf, content);
     }
+
+    #[tokio::test]
+    async fn test_chunked_reader() {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        let path = "test_file";
+
+        let content = gen_random_bytes();
+        op.write(path, content.clone())
+            .await
+            .expect("write must succeed");
+
+        let chunk_size = 1024 * 1024;
+        let chunks: Vec<Bytes> = op
+            .reader(path)
+            .await
+            .unwrap()
+            .into_chunked_reader(chunk_size)
+            .try_collect()
+            .await
+            .expect("read chunks must succeed");
+
+        assert_eq!(chunks.len(), (content.len() + chunk_size - 1) / chunk_size);
+        let (last, rest) = chunks.split_last().unwrap();
+        assert!(rest.iter().all(|bs| bs.len() == chunk_size));
+        assert!(!last.is_empty() && last.len() <= chunk_size);
+        assert_eq!(chunks.concat(), content);
+    }
+
+    #[tokio::test]
+    async fn test_peekable_reader() {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        let path = "test_file";
+
+        let content = gen_random_bytes();
+        op.write(path, content.clone())
+            .await
+            .expect("write must succeed");
+
+        let mut reader = op.reader(path).await.unwrap().into_peekable_reader();
+        let n = content.len().min(4);
+        let peeked = reader.peek(n).await.expect("peek must succeed");
+        assert_eq!(peeked, &content[..n]);
+        // Peek again should return the same data.
+        let peeked = reader.peek(n).await.expect("peek must succeed");
+        assert_eq!(peeked, &content[..n]);
+
+        let mut buf = Vec::new();
+        reader
+            .read_to_end(&mut buf)
+            .await
+            .expect("read to end must succeed");
+        assert_eq!(buf, content);
+    }
+
+    #[tokio::test]
+    async fn test_peekable_reader_cancel() {
+        /// MockReader returns `Hel` first, then pending once before `lo`.
+        struct MockReader {
+            chunks: Vec<&'static [u8]>,
+            pending: bool,
+        }
+
+        impl oio::Read for MockReader {
+            fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+                if self.chunks.len() == 1 && !self.pending {
+                    self.pending = true;
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                if self.chunks.is_empty() {
+                    return Poll::Ready(Ok(0));
+                }
+
+                let chunk = self.chunks.remove(0);
+                buf[..chunk.len()].copy_from_slice(chunk);
+                Poll::Ready(Ok(chunk.len()))
+            }
+
+            fn poll_seek(&mut self, _: &mut Context<'_>, _: io::SeekFrom) -> Poll<Result<u64>> {
+                unimplemented!()
+            }
+
+            fn poll_next(&mut self, _: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+                unimplemented!()
+            }
+        }
+
+        let mut reader = Reader::from_oio_reader(Box::new(MockReader {
+            chunks: vec![b"Hel", b"lo"],
+            pending: false,
+        }))
+        .into_peekable_reader();
+
+        // Drop the peek future while it's pending.
+        {
+            let fut = reader.peek(5);
+            futures::pin_mut!(fut);
+            assert!(futures::poll!(fut).is_pending());
+        }
+
+        let mut bs = vec![];
+        reader
+            .read_to_end(&mut bs)
+            .await
+            .expect("read must succeed");
+        assert_eq!(bs, b"Hello");
+    }
+
+    #[tokio::test]
+    async fn test_peekable_reader_stream() {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        let path = "test_file";
+
+        op.write(path, "Hello, World!")
+            .await
+            .expect("write must succeed");
+
+        let mut reader = op.reader(path).await.unwrap().into_peekable_reader();
+        // Peek more than file size should return the whole file.
+        let peeked = reader.peek(1024).await.expect("peek must succeed");
+        assert_eq!(peeked, b"Hello, World!");
+
+        let chunks: Vec<Bytes> = reader.try_collect().await.expect("read must succeed");
+        assert_eq!(chunks.concat(), b"Hello, World!");
+    }
+
+    #[tokio::test]
+    async fn test_peekable_reader_seek() {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        let path = "test_file";
+
+        op.write(path, "Hello, World!")
+            .await
+            .expect("write must succeed");
+
+        let mut reader = op.reader(path).await.unwrap().into_peekable_reader();
+        reader.peek(5).await.expect("peek must succeed");
+
+        // Seek from current should take the peeked bytes into account.
+        let n = futures::future::poll_fn(|cx| {
+            oio::Read::poll_seek(&mut reader, cx, io::SeekFrom::Current(7))
+        })
+        .await
+        .expect("seek must succeed");
+        assert_eq!(n, 7);
+
+        let mut buf = Vec::new();
+        reader
+            .read_to_end(&mut buf)
+            .await
+            .expect("read to end must succeed");
+        assert_eq!(buf, b"World!");
+    }
+
+    #[tokio::test]
+    async fn test_chunked_reader_empty() {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        let path = "test_file";
+
+        op.write(path, vec![]).await.expect("write must succeed");
+
+        let chunks: Vec<Bytes> = op
+            .reader(path)
+            .await
+            .unwrap()
+            .into_chunked_reader(1024)
+            .try_collect()
+            .await
+            .expect("read chunks must succeed");
+        assert!(chunks.is_empty());
+    }
 }
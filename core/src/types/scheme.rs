@@ -215,3 +215,86 @@ impl From<Scheme> for String {
         v.into_static().to_string()
     }
 }
+
+/// SchemeExt provides the information needed to build URLs for
+/// [`Scheme`].
+pub trait SchemeExt {
+    /// Get the default port of the service, which is used while the
+    /// endpoint doesn't have an explicit port.
+    ///
+    /// Returns `0` for services that don't serve over network, like `fs`
+    /// and `memory`, and for [`Scheme::Custom`].
+    fn default_port(&self) -> u16;
+
+    /// Get the scheme string used by the endpoint URL of the service, like
+    /// `https`, `sftp` and `file`.
+    ///
+    /// Other services that don't serve over network will return their own
+    /// name.
+    fn scheme_str(&self) -> &'static str;
+}
+
+impl SchemeExt for Scheme {
+    fn default_port(&self) -> u16 {
+        match self {
+            Scheme::Azblob
+            | Scheme::Azdfs
+            | Scheme::Cos
+            | Scheme::Dropbox
+            | Scheme::Gcs
+            | Scheme::Gdrive
+            | Scheme::Ghac
+            | Scheme::Obs
+            | Scheme::Onedrive
+            | Scheme::Oss
+            | Scheme::S3
+            | Scheme::Supabase
+            | Scheme::VercelArtifacts
+            | Scheme::Wasabi => 443,
+            Scheme::Http | Scheme::Ipfs | Scheme::Webdav => 80,
+            Scheme::Ipmfs => 5001,
+            Scheme::Webhdfs => 9870,
+            Scheme::Hdfs => 8020,
+            Scheme::Ftp => 21,
+            Scheme::Sftp => 22,
+            Scheme::Redis => 6379,
+            Scheme::Memcached => 11211,
+            Scheme::Cacache
+            | Scheme::Dashmap
+            | Scheme::Fs
+            | Scheme::Memory
+            | Scheme::MiniMoka
+            | Scheme::Moka
+            | Scheme::Persy
+            | Scheme::Redb
+            | Scheme::Rocksdb
+            | Scheme::Sled
+            | Scheme::Custom(_) => 0,
+        }
+    }
+
+    fn scheme_str(&self) -> &'static str {
+        match self {
+            Scheme::Azblob
+            | Scheme::Azdfs
+            | Scheme::Cos
+            | Scheme::Dropbox
+            | Scheme::Gcs
+            | Scheme::Gdrive
+            | Scheme::Ghac
+            | Scheme::Obs
+            | Scheme::Onedrive
+            | Scheme::Oss
+            | Scheme::S3
+            | Scheme::Supabase
+            | Scheme::VercelArtifacts
+            | Scheme::Wasabi => "https",
+            Scheme::Http | Scheme::Ipfs | Scheme::Ipmfs | Scheme::Webdav | Scheme::Webhdfs => {
+                "http"
+            }
+            Scheme::Memcached => "tcp",
+            Scheme::Fs => "file",
+            v => v.into_static(),
+        }
+    }
+}
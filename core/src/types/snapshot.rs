@@ -0,0 +1,338 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use futures::future;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::*;
+
+/// Snapshot is a view of all files under given prefix captured at a
+/// point in time.
+///
+/// Snapshot could be used to process a fixed set of files without being
+/// confused by concurrent writes, and to find out what has been changed
+/// between two snapshots via [`Snapshot::diff`].
+///
+/// # Notes
+///
+/// Snapshot is built by scanning the prefix, so it's only as consistent
+/// as the list operation provided by services.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use anyhow::Result;
+/// use opendal::Operator;
+/// use opendal::Snapshot;
+///
+/// # #[tokio::main]
+/// # async fn test(op: Operator) -> Result<()> {
+/// let before = Snapshot::capture(&op, "path/to/dir/").await?;
+/// // Do something.
+/// let after = Snapshot::capture(&op, "path/to/dir/").await?;
+///
+/// let diff = before.diff(&after);
+/// println!("added: {:?}", diff.added());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    captured_at: DateTime<Utc>,
+    entries: Vec<Entry>,
+}
+
+impl Snapshot {
+    /// Capture a snapshot of all files under given prefix.
+    ///
+    /// Entries in snapshot carry their etag, content length and last
+    /// modified, and are sorted by path.
+    ///
+    /// Files deleted between listed and `stat`ed are not included.
+    pub async fn capture(op: &Operator, prefix: &str) -> Result<Self> {
+        Self::capture_concurrently(op, prefix, 1).await
+    }
+
+    /// Capture a snapshot with at most `max_concurrent` files `stat`ed at
+    /// the same time.
+    pub(crate) async fn capture_concurrently(
+        op: &Operator,
+        prefix: &str,
+        max_concurrent: usize,
+    ) -> Result<Self> {
+        let captured_at = Utc::now();
+
+        let lister = op.scan(prefix).await?;
+        let mut entries: Vec<Entry> = lister
+            .map(|res| async move {
+                let de = res?;
+                let meta = match op
+                    .metadata(
+                        &de,
+                        Metakey::Mode
+                            | Metakey::Etag
+                            | Metakey::ContentLength
+                            | Metakey::LastModified,
+                    )
+                    .await
+                {
+                    Ok(meta) => meta,
+                    Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+                    Err(err) => return Err(err),
+                };
+                if !meta.is_file() {
+                    return Ok(None);
+                }
+
+                Ok(Some(Entry::new_with(de.path().to_string(), meta)))
+            })
+            .buffer_unordered(max_concurrent.max(1))
+            .try_filter_map(|v| future::ready(Ok(v)))
+            .try_collect()
+            .await?;
+        entries.sort_by(|a, b| a.path().cmp(b.path()));
+
+        Ok(Self {
+            captured_at,
+            entries,
+        })
+    }
+
+    /// Get the time when this snapshot is captured.
+    pub fn captured_at(&self) -> DateTime<Utc> {
+        self.captured_at
+    }
+
+    /// Iterate over entries in this snapshot.
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// Get the count of entries in this snapshot.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this snapshot has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Compare with a newer snapshot to find out added, removed and
+    /// changed files.
+    ///
+    /// Files are compared by etag. If etag is not available, content
+    /// length and last modified will be compared instead.
+    pub fn diff(&self, other: &Snapshot) -> SnapshotDiff {
+        let old: BTreeMap<&str, &Entry> = self.iter().map(|v| (v.path(), v)).collect();
+        let new: BTreeMap<&str, &Entry> = other.iter().map(|v| (v.path(), v)).collect();
+
+        let mut diff = SnapshotDiff::default();
+        for (path, entry) in &new {
+            match old.get(path) {
+                None => diff.added.push(path.to_string()),
+                Some(old_entry) if is_changed(old_entry, entry) => {
+                    diff.changed.push(path.to_string())
+                }
+                Some(_) => {}
+            }
+        }
+        for path in old.keys() {
+            if !new.contains_key(path) {
+                diff.removed.push(path.to_string());
+            }
+        }
+
+        diff
+    }
+}
+
+pub(crate) fn is_changed(old: &Entry, new: &Entry) -> bool {
+    match (old.metadata(), new.metadata()) {
+        (Some(old), Some(new)) => match (old.etag(), new.etag()) {
+            (Some(old_etag), Some(new_etag)) => old_etag != new_etag,
+            _ => {
+                old.content_length() != new.content_length()
+                    || old.last_modified() != new.last_modified()
+            }
+        },
+        // Entries in snapshot always carry metadata.
+        _ => true,
+    }
+}
+
+/// SnapshotDiff is the difference between two [`Snapshot`]s.
+///
+/// SnapshotDiff can be serialized for audit logs. All paths are sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    added: Vec<String>,
+    removed: Vec<String>,
+    changed: Vec<String>,
+}
+
+impl SnapshotDiff {
+    /// Paths of files which only exist in the newer snapshot.
+    pub fn added(&self) -> &[String] {
+        &self.added
+    }
+
+    /// Paths of files which only exist in the older snapshot.
+    pub fn removed(&self) -> &[String] {
+        &self.removed
+    }
+
+    /// Paths of files which exist in both snapshots but have been changed.
+    pub fn changed(&self) -> &[String] {
+        &self.changed
+    }
+
+    /// Returns `true` if nothing has been changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::raw::*;
+    use crate::services::Memory;
+
+    /// MockService lists 8 files, and tracks the max count of `stat`
+    /// running at the same time.
+    #[derive(Debug, Default)]
+    struct MockService {
+        running: AtomicUsize,
+        max_running: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Accessor for Arc<MockService> {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = MockPager;
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.capability_mut().stat = true;
+            am.capability_mut().list = true;
+            am.capability_mut().list_without_delimiter = true;
+            am
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+
+            Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE).with_content_length(1),
+            ))
+        }
+
+        async fn list(&self, _: &str, _: OpList) -> Result<(RpList, Self::Pager)> {
+            Ok((RpList::default(), MockPager { done: false }))
+        }
+    }
+
+    struct MockPager {
+        done: bool,
+    }
+
+    #[async_trait]
+    impl oio::Page for MockPager {
+        async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+            if self.done {
+                return Ok(None);
+            }
+            self.done = true;
+
+            Ok(Some(
+                (0..8)
+                    .map(|i| oio::Entry::new(&format!("file-{i}"), Metadata::new(EntryMode::FILE)))
+                    .collect(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_capture_concurrently() -> Result<()> {
+        let srv = Arc::new(MockService::default());
+        let op = OperatorBuilder::new(srv.clone()).finish();
+
+        let snapshot = Snapshot::capture_concurrently(&op, "/", 4).await?;
+        assert_eq!(snapshot.len(), 8);
+        assert_eq!(snapshot.iter().next().map(|v| v.path()), Some("file-0"));
+        assert_eq!(srv.max_running.load(Ordering::SeqCst), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_diff() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+
+        op.write("dir/keep", "keep").await?;
+        op.write("dir/change", "before").await?;
+        op.write("dir/remove", "remove").await?;
+        op.write("other", "other").await?;
+
+        let before = Snapshot::capture(&op, "dir/").await?;
+        assert_eq!(
+            before.iter().map(|v| v.path()).collect::<Vec<_>>(),
+            ["dir/change", "dir/keep", "dir/remove"]
+        );
+
+        op.write("dir/change", "after, longer").await?;
+        op.delete("dir/remove").await?;
+        op.write("dir/add", "add").await?;
+
+        let after = Snapshot::capture(&op, "dir/").await?;
+        let diff = before.diff(&after);
+        assert_eq!(diff.added(), ["dir/add"]);
+        assert_eq!(diff.removed(), ["dir/remove"]);
+        assert_eq!(diff.changed(), ["dir/change"]);
+
+        assert!(after.diff(&after).is_empty());
+
+        let json = serde_json::to_string(&diff).expect("serialize must succeed");
+        assert_eq!(
+            json,
+            r#"{"added":["dir/add"],"removed":["dir/remove"],"changed":["dir/change"]}"#
+        );
+        Ok(())
+    }
+}
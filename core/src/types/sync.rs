@@ -0,0 +1,158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::*;
+
+/// SyncStrategy decides how [`Operator::sync_dir`](crate::Operator::sync_dir)
+/// synchronizes files.
+///
+/// # Examples
+///
+/// ```
+/// use opendal::SyncStrategy;
+///
+/// // Skip files with the same etag, and delete files that only exist in target.
+/// let strategy = SyncStrategy::etag().with_delete();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncStrategy {
+    compare: SyncCompare,
+    delete: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SyncCompare {
+    ETag,
+    Size,
+    Always,
+}
+
+impl SyncStrategy {
+    /// Skip files if their etag are the same.
+    ///
+    /// Files will be copied if etag is not available.
+    pub fn etag() -> Self {
+        Self {
+            compare: SyncCompare::ETag,
+            delete: false,
+        }
+    }
+
+    /// Skip files if their content length are the same.
+    pub fn size() -> Self {
+        Self {
+            compare: SyncCompare::Size,
+            delete: false,
+        }
+    }
+
+    /// Always copy files.
+    pub fn always() -> Self {
+        Self {
+            compare: SyncCompare::Always,
+            delete: false,
+        }
+    }
+
+    /// Delete files in target which don't exist in source.
+    pub fn with_delete(mut self) -> Self {
+        self.delete = true;
+        self
+    }
+
+    /// Check whether files in target which don't exist in source will be deleted.
+    pub fn delete(&self) -> bool {
+        self.delete
+    }
+
+    /// Check whether the target file needs to be copied from source.
+    pub(crate) fn need_copy(&self, source: &Metadata, target: &Metadata) -> bool {
+        match self.compare {
+            SyncCompare::ETag => match (source.etag_raw(), target.etag_raw()) {
+                (Some(source), Some(target)) => source != target,
+                _ => true,
+            },
+            SyncCompare::Size => source.content_length_raw() != target.content_length_raw(),
+            SyncCompare::Always => true,
+        }
+    }
+}
+
+/// SyncStats is the result of [`Operator::sync_dir`](crate::Operator::sync_dir).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncStats {
+    /// Count of files copied from source to target.
+    pub copied: usize,
+    /// Count of files skipped since they are not changed.
+    pub skipped: usize,
+    /// Count of files deleted from target.
+    pub deleted: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_sync_dir() -> Result<()> {
+        let source = Operator::new(Memory::default())?.finish();
+        let target = Operator::new(Memory::default())?.finish();
+
+        source.write("src/same", "same").await?;
+        source.write("src/changed", "after").await?;
+        source.write("src/new/file", "new").await?;
+        target.write("dst/same", "same").await?;
+        target.write("dst/changed", "before, longer").await?;
+        target.write("dst/extra", "extra").await?;
+
+        let stats = source
+            .sync_dir("src/", &target, "dst/", SyncStrategy::size(), 4)
+            .await?;
+        assert_eq!(
+            stats,
+            SyncStats {
+                copied: 2,
+                skipped: 1,
+                deleted: 0,
+            }
+        );
+        assert_eq!(target.read("dst/changed").await?, b"after");
+        assert_eq!(target.read("dst/new/file").await?, b"new");
+        assert!(target.is_exist("dst/extra").await?);
+
+        let stats = source
+            .sync_dir(
+                "src/",
+                &target,
+                "dst/",
+                SyncStrategy::always().with_delete(),
+                4,
+            )
+            .await?;
+        assert_eq!(
+            stats,
+            SyncStats {
+                copied: 3,
+                skipped: 0,
+                deleted: 1,
+            }
+        );
+        assert!(!target.is_exist("dst/extra").await?);
+        Ok(())
+    }
+}
@@ -0,0 +1,385 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Bytes;
+use futures::TryStreamExt;
+use log::warn;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::*;
+
+/// The dir to store manifests and staged data of transactions.
+const TRANSACTION_DIR: &str = ".txn/";
+
+/// TransactionHandle collects writes and deletes and applies them together.
+///
+/// Most storage services don't support transactions natively, so
+/// TransactionHandle is built on top of a write-ahead log:
+///
+/// 1. Data of all writes are staged under `.txn/<id>/`.
+/// 2. A manifest `.txn/<id>.txn` listing all changes is written.
+/// 3. Changes are applied in order.
+/// 4. The manifest and staged data are removed.
+///
+/// If any change failed to apply, the manifest will be removed and files
+/// written by this transaction will be deleted. If the process crashed
+/// during commit, call [`TransactionHandle::recover`] on startup to finish
+/// it, rolled back transactions will not be re-applied.
+///
+/// # Notes
+///
+/// - Rollback only deletes files written by this transaction, overwritten
+///   content and deleted files can't be restored.
+/// - Changes are visible to others while applying, the transaction is
+///   atomic only in the sense that it will be finished eventually.
+/// - All data of writes are kept in memory until commit.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use anyhow::Result;
+/// use opendal::Operator;
+///
+/// # #[tokio::main]
+/// # async fn test(op: Operator) -> Result<()> {
+/// let mut txn = op.begin_transaction();
+/// txn.write("path/to/file", "Hello, World!");
+/// txn.delete("path/to/old_file");
+/// txn.commit().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TransactionHandle {
+    op: Operator,
+    id: String,
+    changes: Vec<Change>,
+}
+
+#[derive(Debug)]
+enum Change {
+    Write { path: String, bs: Bytes },
+    Delete { path: String },
+}
+
+/// Manifest is the content stored in the `.txn` file.
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    changes: Vec<ManifestChange>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ManifestChange {
+    /// Write the data staged at `staged` into `path`.
+    Write {
+        path: String,
+        staged: String,
+    },
+    Delete {
+        path: String,
+    },
+}
+
+impl Manifest {
+    fn to_vec(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "serialize transaction manifest").set_source(err)
+        })
+    }
+
+    fn from_slice(bs: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bs).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "deserialize transaction manifest").set_source(err)
+        })
+    }
+}
+
+fn manifest_path(id: &str) -> String {
+    format!("{TRANSACTION_DIR}{id}.txn")
+}
+
+fn staged_dir(id: &str) -> String {
+    format!("{TRANSACTION_DIR}{id}/")
+}
+
+impl TransactionHandle {
+    pub(crate) fn new(op: Operator) -> Self {
+        Self {
+            op,
+            id: Uuid::new_v4().to_string(),
+            changes: vec![],
+        }
+    }
+
+    /// Get the id of this transaction.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Write `bs` into `path` while committing.
+    pub fn write(&mut self, path: &str, bs: impl Into<Bytes>) -> &mut Self {
+        self.changes.push(Change::Write {
+            path: path.to_string(),
+            bs: bs.into(),
+        });
+        self
+    }
+
+    /// Delete `path` while committing.
+    pub fn delete(&mut self, path: &str) -> &mut Self {
+        self.changes.push(Change::Delete {
+            path: path.to_string(),
+        });
+        self
+    }
+
+    /// Commit all changes of this transaction in order.
+    ///
+    /// If any change failed, files written by this transaction will be
+    /// deleted and the error will be returned.
+    pub async fn commit(self) -> Result<()> {
+        if self.changes.is_empty() {
+            return Ok(());
+        }
+
+        if let Err(err) = self.prepare().await {
+            self.cleanup().await;
+            return Err(err);
+        }
+
+        let mut written = vec![];
+        for change in &self.changes {
+            let res = match change {
+                Change::Write { path, bs } => self
+                    .op
+                    .write(path, bs.clone())
+                    .await
+                    .map(|_| written.push(path.as_str())),
+                Change::Delete { path } => self.op.delete(path).await,
+            };
+
+            if let Err(err) = res {
+                self.rollback(&written).await;
+                return Err(err.with_context("transaction", &self.id));
+            }
+        }
+
+        self.cleanup().await;
+        Ok(())
+    }
+
+    /// Delete files written by this transaction after commit failed.
+    ///
+    /// The manifest is removed before rollback so that this transaction
+    /// won't be re-applied by [`TransactionHandle::recover`]. If the
+    /// manifest failed to remove, rollback is skipped and the transaction
+    /// will be finished by recover instead.
+    async fn rollback(&self, written: &[&str]) {
+        if let Err(err) = self.op.delete(&manifest_path(&self.id)).await {
+            warn!(
+                "transaction {} remove manifest failed, rollback skipped: {err}",
+                self.id
+            );
+            return;
+        }
+
+        for path in written {
+            if let Err(err) = self.op.delete(path).await {
+                warn!("transaction {} rollback {path} failed: {err}", self.id);
+            }
+        }
+        if let Err(err) = self.op.remove_all(&staged_dir(&self.id)).await {
+            warn!("transaction {} remove staged data failed: {err}", self.id);
+        }
+    }
+
+    /// Stage data of writes and write the manifest.
+    async fn prepare(&self) -> Result<()> {
+        let mut changes = Vec::with_capacity(self.changes.len());
+        for (idx, change) in self.changes.iter().enumerate() {
+            match change {
+                Change::Write { path, bs } => {
+                    let staged = format!("{}{idx}", staged_dir(&self.id));
+                    self.op.write(&staged, bs.clone()).await?;
+                    changes.push(ManifestChange::Write {
+                        path: path.to_string(),
+                        staged,
+                    });
+                }
+                Change::Delete { path } => changes.push(ManifestChange::Delete {
+                    path: path.to_string(),
+                }),
+            }
+        }
+
+        let manifest = Manifest { changes }.to_vec()?;
+        self.op.write(&manifest_path(&self.id), manifest).await
+    }
+
+    /// Remove the manifest and staged data.
+    ///
+    /// Errors are ignored here, left files will be removed by
+    /// [`TransactionHandle::recover`].
+    async fn cleanup(&self) {
+        if let Err(err) = self.op.delete(&manifest_path(&self.id)).await {
+            warn!("transaction {} remove manifest failed: {err}", self.id);
+            return;
+        }
+        if let Err(err) = self.op.remove_all(&staged_dir(&self.id)).await {
+            warn!("transaction {} remove staged data failed: {err}", self.id);
+        }
+    }
+
+    /// Recover incomplete transactions left by crashed processes.
+    ///
+    /// - Transactions with a manifest will be re-applied.
+    /// - Transactions without a manifest haven't started applying, they
+    ///   will be aborted.
+    ///
+    /// Returns the count of re-applied transactions.
+    ///
+    /// # Notes
+    ///
+    /// Please make sure there are no running transactions while recovering,
+    /// otherwise they will be aborted or applied twice.
+    pub async fn recover(op: &Operator) -> Result<usize> {
+        let entries: Vec<Entry> = match op.list(TRANSACTION_DIR).await {
+            Ok(lister) => lister.try_collect().await?,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err),
+        };
+
+        let mut recovered = 0;
+        for entry in &entries {
+            let Some(id) = entry.name().strip_suffix(".txn") else {
+                continue;
+            };
+
+            let manifest = Manifest::from_slice(&op.read(entry.path()).await?)?;
+            for change in manifest.changes {
+                match change {
+                    ManifestChange::Write { path, staged } => {
+                        let bs = op.read(&staged).await?;
+                        op.write(&path, bs).await?
+                    }
+                    ManifestChange::Delete { path } => op.delete(&path).await?,
+                }
+            }
+
+            op.delete(entry.path()).await?;
+            op.remove_all(&staged_dir(id)).await?;
+            recovered += 1;
+        }
+
+        // Staged data without manifest belongs to aborted transactions.
+        for entry in &entries {
+            let id = entry.name().trim_end_matches('/');
+            if entry.path().ends_with('/')
+                && !entries.iter().any(|v| v.name() == format!("{id}.txn"))
+            {
+                op.remove_all(entry.path()).await?;
+            }
+        }
+
+        Ok(recovered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    fn new_operator() -> Operator {
+        Operator::new(Memory::default()).unwrap().finish()
+    }
+
+    /// List all files left under transaction dir.
+    async fn list_left(op: &Operator) -> Result<Vec<String>> {
+        let lister = op.scan(TRANSACTION_DIR).await?;
+        let entries: Vec<Entry> = lister.try_collect().await?;
+        Ok(entries
+            .into_iter()
+            .filter(|v| !v.path().ends_with('/'))
+            .map(|v| v.path().to_string())
+            .collect())
+    }
+
+    #[tokio::test]
+    async fn test_commit() -> Result<()> {
+        let op = new_operator();
+        op.write("old", "old").await?;
+
+        let mut txn = op.begin_transaction();
+        txn.write("a", "Hello").write("b", "World").delete("old");
+        txn.commit().await?;
+
+        assert_eq!(op.read("a").await?, b"Hello");
+        assert_eq!(op.read("b").await?, b"World");
+        assert!(!op.is_exist("old").await?);
+        assert!(list_left(&op).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_commit_rollback() -> Result<()> {
+        let op = new_operator();
+
+        let mut txn = op.begin_transaction();
+        // Write into a dir path must fail.
+        txn.write("a", "Hello").write("dir/", "World");
+        let err = txn.commit().await.expect_err("commit must fail");
+        assert_eq!(err.kind(), ErrorKind::IsADirectory);
+
+        assert!(!op.is_exist("a").await?);
+        assert!(list_left(&op).await?.is_empty());
+
+        // Rolled back transaction must not be re-applied.
+        assert_eq!(TransactionHandle::recover(&op).await?, 0);
+        assert!(!op.is_exist("a").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recover() -> Result<()> {
+        let op = new_operator();
+        op.write("old", "old").await?;
+
+        // Crashed after manifest written.
+        let mut txn = op.begin_transaction();
+        txn.write("a", "Hello").delete("old");
+        txn.prepare().await?;
+
+        // Crashed while staging data.
+        let mut aborted = op.begin_transaction();
+        aborted.write("b", "World");
+        op.write(&format!("{}0", staged_dir(aborted.id())), "World")
+            .await?;
+
+        assert_eq!(TransactionHandle::recover(&op).await?, 1);
+        assert_eq!(op.read("a").await?, b"Hello");
+        assert!(!op.is_exist("old").await?);
+        assert!(!op.is_exist("b").await?);
+        assert!(list_left(&op).await?.is_empty());
+
+        assert_eq!(TransactionHandle::recover(&op).await?, 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,364 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use futures::stream::BoxStream;
+use futures::Stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use log::warn;
+
+use super::snapshot::is_changed;
+use crate::*;
+
+/// WatchEvent is the change of a watched path found by [`Watcher`].
+#[derive(Debug, Clone)]
+pub enum WatchEvent {
+    /// The entry has been created, or existed while the watcher started.
+    Created(Entry),
+    /// The entry has been modified.
+    Modified(Entry),
+    /// The entry at this path has been deleted.
+    Deleted(String),
+}
+
+impl WatchEvent {
+    /// Get the path of this event.
+    pub fn path(&self) -> &str {
+        match self {
+            WatchEvent::Created(entry) | WatchEvent::Modified(entry) => entry.path(),
+            WatchEvent::Deleted(path) => path,
+        }
+    }
+}
+
+/// WatchConfig is the config of [`Watcher`].
+#[derive(Debug, Clone)]
+pub struct WatchConfig {
+    poll_interval: Duration,
+    max_missed_events: usize,
+    initial_state: bool,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(1),
+            max_missed_events: 1024,
+            initial_state: true,
+        }
+    }
+}
+
+impl WatchConfig {
+    /// Create a new WatchConfig which polls every `poll_interval`.
+    pub fn new(poll_interval: Duration) -> Self {
+        Self::default().with_poll_interval(poll_interval)
+    }
+
+    /// Set the interval between two polls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        assert!(!interval.is_zero(), "poll interval must be greater than 0");
+
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Set the max count of events that haven't been consumed.
+    ///
+    /// If a poll finds more changes than this, the oldest events will be
+    /// dropped with a warning.
+    pub fn with_max_missed_events(mut self, v: usize) -> Self {
+        self.max_missed_events = v;
+        self
+    }
+
+    /// Set whether to emit [`WatchEvent::Created`] for entries that exist
+    /// while the watcher starts, default to `true`.
+    pub fn with_initial_state(mut self, v: bool) -> Self {
+        self.initial_state = v;
+        self
+    }
+}
+
+/// Watcher is a stream of [`WatchEvent`] built by polling the service.
+///
+/// Most services don't provide event notifications, so Watcher `stat`s
+/// the path (or `list`s it if the path is a dir) every poll interval and
+/// compares the result with the previous one.
+///
+/// # Notes
+///
+/// - Entries are compared by etag. If etag is not available, content
+///   length and last modified will be compared instead.
+/// - Dirs are listed non-recursively.
+/// - The first poll happens while the stream is polled for the first
+///   time, changes before that are part of the initial state.
+/// - Changes happened between two polls are merged, for example a file
+///   created and deleted within one interval will not be reported.
+/// - Errors during polls will be logged and retried in next poll.
+/// - Watcher stops polling once it's dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use anyhow::Result;
+/// use futures::StreamExt;
+/// use opendal::Operator;
+///
+/// # #[tokio::main]
+/// # async fn test(op: Operator) -> Result<()> {
+/// let mut watcher = op.watch("path/to/dir/", Duration::from_secs(5));
+/// while let Some(event) = watcher.next().await {
+///     println!("{event:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Watcher {
+    inner: BoxStream<'static, WatchEvent>,
+}
+
+impl Watcher {
+    pub(crate) fn new(op: Operator, path: &str, config: WatchConfig) -> Self {
+        let state = WatchState {
+            op,
+            path: path.to_string(),
+            config,
+            started: false,
+            entries: None,
+            pending: VecDeque::new(),
+        };
+
+        let inner = futures::stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Some((event, state));
+                }
+
+                // The first poll happens immediately.
+                if state.started {
+                    tokio::time::sleep(state.config.poll_interval).await;
+                }
+                state.started = true;
+                if let Err(err) = state.poll().await {
+                    warn!("watcher poll {} failed: {err}", state.path);
+                }
+            }
+        });
+
+        Self {
+            inner: inner.boxed(),
+        }
+    }
+}
+
+impl Stream for Watcher {
+    type Item = WatchEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+struct WatchState {
+    op: Operator,
+    path: String,
+    config: WatchConfig,
+    started: bool,
+    /// Entries found by last poll, `None` if not polled yet.
+    entries: Option<BTreeMap<String, Entry>>,
+    pending: VecDeque<WatchEvent>,
+}
+
+impl WatchState {
+    async fn poll(&mut self) -> Result<()> {
+        let current = self.current().await?;
+
+        let events = match &self.entries {
+            None if self.config.initial_state => current
+                .values()
+                .map(|v| WatchEvent::Created(v.clone()))
+                .collect(),
+            None => vec![],
+            Some(old) => diff(old, &current),
+        };
+        self.entries = Some(current);
+
+        self.pending.extend(events);
+        if self.pending.len() > self.config.max_missed_events {
+            let dropped = self.pending.len() - self.config.max_missed_events;
+            self.pending.drain(..dropped);
+            warn!("watcher {} dropped {dropped} events", self.path);
+        }
+        Ok(())
+    }
+
+    /// Get the entries of watched path.
+    async fn current(&self) -> Result<BTreeMap<String, Entry>> {
+        let mut entries = BTreeMap::new();
+
+        if !self.path.ends_with('/') {
+            match self.op.stat(&self.path).await {
+                Ok(meta) => {
+                    entries.insert(self.path.clone(), Entry::new_with(self.path.clone(), meta));
+                }
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+            return Ok(entries);
+        }
+
+        let mut lister = match self.op.list(&self.path).await {
+            Ok(lister) => lister,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(entries),
+            Err(err) => return Err(err),
+        };
+        while let Some(de) = lister.try_next().await? {
+            if de.path() == self.path {
+                continue;
+            }
+
+            let meta = match self
+                .op
+                .metadata(
+                    &de,
+                    Metakey::Mode | Metakey::Etag | Metakey::ContentLength | Metakey::LastModified,
+                )
+                .await
+            {
+                Ok(meta) => meta,
+                // The entry has been removed after listed.
+                Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            };
+            entries.insert(
+                de.path().to_string(),
+                Entry::new_with(de.path().to_string(), meta),
+            );
+        }
+        Ok(entries)
+    }
+}
+
+fn diff(old: &BTreeMap<String, Entry>, new: &BTreeMap<String, Entry>) -> Vec<WatchEvent> {
+    let mut events = vec![];
+    for (path, entry) in new {
+        match old.get(path) {
+            None => events.push(WatchEvent::Created(entry.clone())),
+            Some(old_entry) if is_changed(old_entry, entry) => {
+                events.push(WatchEvent::Modified(entry.clone()))
+            }
+            Some(_) => {}
+        }
+    }
+    for path in old.keys() {
+        if !new.contains_key(path) {
+            events.push(WatchEvent::Deleted(path.clone()));
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    const INTERVAL: Duration = Duration::from_millis(10);
+
+    #[tokio::test]
+    async fn test_watch_file() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("file", "Hello").await?;
+
+        let mut watcher = op.watch("file", INTERVAL);
+        let event = watcher.next().await.expect("must have event");
+        assert!(matches!(event, WatchEvent::Created(_)));
+        assert_eq!(event.path(), "file");
+
+        op.write("file", "Hello, World!").await?;
+        let event = watcher.next().await.expect("must have event");
+        assert!(matches!(event, WatchEvent::Modified(_)));
+
+        op.delete("file").await?;
+        let event = watcher.next().await.expect("must have event");
+        assert!(matches!(event, WatchEvent::Deleted(_)));
+        assert_eq!(event.path(), "file");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_watch_dir() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("dir/a", "Hello").await?;
+
+        let mut watcher =
+            op.watch_with_config("dir/", WatchConfig::new(INTERVAL).with_initial_state(false));
+
+        // Write after the initial poll.
+        let writer = op.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(INTERVAL * 5).await;
+            writer.write("dir/b", "World").await
+        });
+        let event = watcher.next().await.expect("must have event");
+        assert!(matches!(event, WatchEvent::Created(_)));
+        assert_eq!(event.path(), "dir/b");
+
+        op.delete("dir/a").await?;
+        let event = watcher.next().await.expect("must have event");
+        assert!(matches!(event, WatchEvent::Deleted(_)));
+        assert_eq!(event.path(), "dir/a");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_watch_max_missed_events() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        for i in 0..10 {
+            op.write(&format!("dir/{i}"), "Hello").await?;
+        }
+
+        let mut watcher =
+            op.watch_with_config("dir/", WatchConfig::new(INTERVAL).with_max_missed_events(3));
+        let mut paths = vec![];
+        for _ in 0..3 {
+            paths.push(
+                watcher
+                    .next()
+                    .await
+                    .expect("must have event")
+                    .path()
+                    .to_string(),
+            );
+        }
+        assert_eq!(paths, vec!["dir/7", "dir/8", "dir/9"]);
+        Ok(())
+    }
+}
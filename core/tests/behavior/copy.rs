@@ -34,7 +34,9 @@ pub fn behavior_copy_tests(op: &Operator) -> Vec<Trial> {
         test_copy_target_dir,
         test_copy_self,
         test_copy_nested,
-        test_copy_overwrite
+        test_copy_overwrite,
+        test_copy_dir,
+        test_copy_dir_into_itself
     )
 }
 
@@ -170,3 +172,47 @@ pub async fn test_copy_overwrite(op: Operator) -> Result<()> {
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Copy a dir should copy all files under it recursively.
+pub async fn test_copy_dir(op: Operator) -> Result<()> {
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+
+    let paths = ["a", "b/c", "b/d/e"];
+    let mut contents = vec![];
+    for path in paths {
+        let (content, _) = gen_bytes();
+        op.write(&format!("{source_dir}{path}"), content.clone())
+            .await?;
+        contents.push(content);
+    }
+    op.create_dir(&format!("{source_dir}empty/")).await?;
+
+    let copied = op.copy_dir(&source_dir, &target_dir, 4).await?;
+    assert_eq!(copied, paths.len());
+
+    for (path, content) in paths.iter().zip(contents) {
+        let bs = op
+            .read(&format!("{target_dir}{path}"))
+            .await
+            .expect("read must succeed");
+        assert_eq!(bs, content, "content of {path} must be the same");
+    }
+
+    op.remove_all(&source_dir).await?;
+    op.remove_all(&target_dir).await?;
+    Ok(())
+}
+
+/// Copy a dir into itself should return an error.
+pub async fn test_copy_dir_into_itself(op: Operator) -> Result<()> {
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{source_dir}backup/");
+
+    let err = op
+        .copy_dir(&source_dir, &target_dir, 4)
+        .await
+        .expect_err("copy must fail");
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn behavior_cors_tests(op: &Operator) -> Vec<Trial> {
+    let cap = op.info().capability();
+
+    if !cap.cors {
+        return vec![];
+    }
+
+    async_trials!(op, test_set_and_get_cors_config)
+}
+
+/// Set cors rules and get them back should be the same.
+pub async fn test_set_and_get_cors_config(op: Operator) -> Result<()> {
+    // Keep existing rules so that we can restore them later.
+    let existing = op.get_cors_config().await?;
+
+    let origin = format!("https://{}.example.com", uuid::Uuid::new_v4());
+    let rule = CorsRule {
+        allowed_origins: vec![origin.clone()],
+        allowed_methods: vec!["GET".to_string(), "HEAD".to_string()],
+        allowed_headers: vec!["*".to_string()],
+        expose_headers: vec!["ETag".to_string()],
+        max_age_seconds: 3600,
+    };
+    let mut rules = existing.clone();
+    rules.push(rule.clone());
+
+    op.set_cors_config(rules).await?;
+
+    let actual = op.get_cors_config().await?;
+    let actual = actual
+        .into_iter()
+        .find(|v| v.allowed_origins.contains(&origin))
+        .expect("rule must be set");
+    assert_eq!(actual, rule);
+
+    op.set_cors_config(existing).await?;
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn behavior_lifecycle_tests(op: &Operator) -> Vec<Trial> {
+    let cap = op.info().capability();
+
+    if !cap.lifecycle {
+        return vec![];
+    }
+
+    async_trials!(op, test_set_and_get_lifecycle_rules)
+}
+
+/// Set lifecycle rules and get them back should be the same.
+pub async fn test_set_and_get_lifecycle_rules(op: Operator) -> Result<()> {
+    // Keep existing rules so that we can restore them later.
+    let existing = op.get_lifecycle_rules().await?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let rule = LifecycleRule {
+        id: id.clone(),
+        prefix: format!("{id}/"),
+        enabled: true,
+        transitions: vec![],
+        expiration: Some(Expiration { days: 30 }),
+    };
+    let mut rules = existing.clone();
+    rules.push(rule.clone());
+
+    op.set_lifecycle_rules(rules).await?;
+
+    let actual = op.get_lifecycle_rules().await?;
+    let actual = actual
+        .into_iter()
+        .find(|v| v.id == id)
+        .expect("rule must be set");
+    assert_eq!(actual, rule);
+
+    op.set_lifecycle_rules(existing).await?;
+    Ok(())
+}
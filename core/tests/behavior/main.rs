@@ -24,19 +24,31 @@ pub use utils::*;
 // Async test cases
 mod append;
 mod copy;
+mod cors;
+mod lifecycle;
 mod list;
 mod list_only;
+mod multipart;
 mod presign;
 mod read_only;
 mod rename;
+mod tags;
+mod touch;
+mod versions;
 mod write;
 use append::behavior_append_tests;
 use copy::behavior_copy_tests;
+use cors::behavior_cors_tests;
+use lifecycle::behavior_lifecycle_tests;
 use list::behavior_list_tests;
 use list_only::behavior_list_only_tests;
+use multipart::behavior_multipart_tests;
 use presign::behavior_presign_tests;
 use read_only::behavior_read_only_tests;
 use rename::behavior_rename_tests;
+use tags::behavior_tags_tests;
+use touch::behavior_touch_tests;
+use versions::behavior_versions_tests;
 use write::behavior_write_tests;
 
 // Blocking test cases
@@ -78,11 +90,17 @@ fn behavior_test<B: Builder>() -> Vec<Trial> {
     // Async tests
     trials.extend(behavior_append_tests(&operator));
     trials.extend(behavior_copy_tests(&operator));
+    trials.extend(behavior_cors_tests(&operator));
+    trials.extend(behavior_lifecycle_tests(&operator));
     trials.extend(behavior_list_only_tests(&operator));
     trials.extend(behavior_list_tests(&operator));
+    trials.extend(behavior_multipart_tests(&operator));
     trials.extend(behavior_presign_tests(&operator));
     trials.extend(behavior_read_only_tests(&operator));
     trials.extend(behavior_rename_tests(&operator));
+    trials.extend(behavior_tags_tests(&operator));
+    trials.extend(behavior_touch_tests(&operator));
+    trials.extend(behavior_versions_tests(&operator));
     trials.extend(behavior_write_tests(&operator));
 
     trials
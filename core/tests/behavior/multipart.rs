@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+use bytes::Bytes;
+use opendal::raw::oio::MultipartWriter;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::*;
+
+/// S3 like services require parts except the last one larger than 5 MiB.
+const PART_SIZE: u64 = 5 * 1024 * 1024;
+
+pub fn behavior_multipart_tests(op: &Operator) -> Vec<Trial> {
+    let cap = op.info().capability();
+
+    if !(cap.read && cap.write && cap.write_multipart) {
+        return vec![];
+    }
+
+    async_trials!(op, test_multipart_write, test_multipart_abort)
+}
+
+/// Write a file by multipart and read it back.
+pub async fn test_multipart_write(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let content = gen_fixed_bytes(PART_SIZE as usize + 1024);
+
+    let mut w = MultipartWriter::new(op.clone().into(), &path, PART_SIZE);
+    w.write_part(Bytes::from(content.clone())).await?;
+    w.complete().await?;
+
+    let bs = op.read(&path).await.expect("read must succeed");
+    assert_eq!(bs.len(), content.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Abort a multipart upload should not create the file.
+pub async fn test_multipart_abort(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let content = gen_fixed_bytes(PART_SIZE as usize);
+
+    let mut w = MultipartWriter::new(op.clone().into(), &path, PART_SIZE);
+    w.write_part(Bytes::from(content)).await?;
+    assert!(w.upload_id().is_some(), "upload must be created");
+    w.abort().await?;
+
+    let err = op.stat(&path).await.expect_err("stat must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    Ok(())
+}
@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn behavior_tags_tests(op: &Operator) -> Vec<Trial> {
+    let cap = op.info().capability();
+
+    if !(cap.tagging && cap.write) {
+        return vec![];
+    }
+
+    async_trials!(
+        op,
+        test_set_and_get_tags,
+        test_add_and_remove_tags,
+        test_get_tags_not_exist
+    )
+}
+
+/// Set tags and get them back should be the same.
+pub async fn test_set_and_get_tags(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    op.write(&path, "Hello, World!").await?;
+
+    assert!(op.get_tags(&path).await?.is_empty());
+
+    let tags = HashMap::from([("project".to_string(), "opendal".to_string())]);
+    op.set_tags(&path, tags.clone()).await?;
+    assert_eq!(op.get_tags(&path).await?, tags);
+
+    op.set_tags(&path, HashMap::new()).await?;
+    assert!(op.get_tags(&path).await?.is_empty());
+
+    op.delete(&path).await?;
+    Ok(())
+}
+
+/// Add and remove tags should keep other tags.
+pub async fn test_add_and_remove_tags(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    op.write(&path, "Hello, World!").await?;
+
+    op.set_tags(
+        &path,
+        HashMap::from([("project".to_string(), "opendal".to_string())]),
+    )
+    .await?;
+
+    op.add_tags(
+        &path,
+        HashMap::from([("env".to_string(), "dev".to_string())]),
+    )
+    .await?;
+    // Add the same tags again should be a no-op.
+    op.add_tags(
+        &path,
+        HashMap::from([("env".to_string(), "dev".to_string())]),
+    )
+    .await?;
+    assert_eq!(
+        op.get_tags(&path).await?,
+        HashMap::from([
+            ("project".to_string(), "opendal".to_string()),
+            ("env".to_string(), "dev".to_string()),
+        ])
+    );
+
+    op.remove_tags(&path, &["project", "not_exist"]).await?;
+    assert_eq!(
+        op.get_tags(&path).await?,
+        HashMap::from([("env".to_string(), "dev".to_string())])
+    );
+
+    op.delete(&path).await?;
+    Ok(())
+}
+
+/// Get tags of a not exist file should return NotFound.
+pub async fn test_get_tags_not_exist(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    let err = op
+        .get_tags(&path)
+        .await
+        .expect_err("get tags of not exist file must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    let err = op
+        .add_tags(&path, HashMap::new())
+        .await
+        .expect_err("add tags to not exist file must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    Ok(())
+}
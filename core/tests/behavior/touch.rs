@@ -0,0 +1,66 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn behavior_touch_tests(op: &Operator) -> Vec<Trial> {
+    let cap = op.info().capability();
+
+    if !(cap.read && cap.write && cap.touch) {
+        return vec![];
+    }
+
+    async_trials!(op, test_touch_file, test_touch_not_existing)
+}
+
+/// Touch a file should update last modified without changing content.
+pub async fn test_touch_file(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    op.write(&path, content.clone()).await?;
+    let before = op.stat(&path).await?;
+
+    // Most services only keep last modified in seconds.
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    op.touch(&path).await?;
+
+    let after = op.stat(&path).await?;
+    assert_eq!(after.content_length(), size as u64);
+    if let (Some(before), Some(after)) = (before.last_modified(), after.last_modified()) {
+        assert!(after > before, "last modified must be updated");
+    }
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs, content);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Touch a not existing file should return NotFound.
+pub async fn test_touch_not_existing(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    let err = op.touch(&path).await.expect_err("touch must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    Ok(())
+}
@@ -0,0 +1,101 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+use futures::TryStreamExt;
+
+use crate::*;
+
+pub fn behavior_versions_tests(op: &Operator) -> Vec<Trial> {
+    let cap = op.info().capability();
+
+    if !(cap.write && cap.versioning) {
+        return vec![];
+    }
+
+    async_trials!(
+        op,
+        test_list_versions,
+        test_list_versions_not_exist,
+        test_delete_version
+    )
+}
+
+/// Write the same file twice and both versions should be listed.
+pub async fn test_list_versions(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content_one, _) = gen_bytes();
+    let (content_two, _) = gen_bytes();
+
+    op.write(&path, content_one).await?;
+    op.write(&path, content_two).await?;
+
+    let versions = list_versions_of(&op, &path).await?;
+    assert_eq!(
+        versions.len(),
+        2,
+        "writing twice must generate two versions"
+    );
+    assert_ne!(versions[0], versions[1], "versions must be different");
+
+    for version in versions {
+        op.delete_version(&path, &version).await?;
+    }
+    Ok(())
+}
+
+/// List versions of a not exist path should return empty.
+pub async fn test_list_versions_not_exist(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    let versions = list_versions_of(&op, &path).await?;
+    assert!(versions.is_empty());
+    Ok(())
+}
+
+/// Delete a version should keep other versions untouched.
+pub async fn test_delete_version(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content_one, _) = gen_bytes();
+    let (content_two, _) = gen_bytes();
+
+    op.write(&path, content_one).await?;
+    op.write(&path, content_two).await?;
+
+    let versions = list_versions_of(&op, &path).await?;
+    assert_eq!(versions.len(), 2);
+
+    op.delete_version(&path, &versions[0]).await?;
+
+    let remaining = list_versions_of(&op, &path).await?;
+    assert_eq!(remaining, vec![versions[1].clone()]);
+
+    op.delete_version(&path, &versions[1]).await?;
+    Ok(())
+}
+
+/// Collect all versions of the exact given path.
+async fn list_versions_of(op: &Operator, path: &str) -> Result<Vec<String>> {
+    let entries: Vec<Entry> = op.list_versions(path).await?.try_collect().await?;
+
+    let mut versions = vec![];
+    for de in entries.iter().filter(|v| v.path() == path) {
+        let meta = op.metadata(de, Metakey::Version).await?;
+        versions.push(meta.version().expect("version must be set").to_string());
+    }
+    Ok(versions)
+}
@@ -51,6 +51,7 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_write_with_cache_control,
         test_write_with_content_type,
         test_write_with_content_disposition,
+        test_write_if_not_exists,
         test_stat_file,
         test_stat_dir,
         test_stat_with_special_chars,
@@ -59,9 +60,13 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_stat_with_if_match,
         test_stat_with_if_none_match,
         test_stat_root,
+        test_head_file,
+        test_head_not_exist,
         test_read_full,
         test_read_range,
         test_read_large_range,
+        test_read_at,
+        test_read_exact_at,
         test_reader_range,
         test_reader_from,
         test_reader_tail,
@@ -86,6 +91,10 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_writer_copy,
         test_writer_abort,
         test_writer_futures_copy,
+        test_write_from_path,
+        test_write_from_async_read,
+        test_write_json,
+        test_read_to_path,
         test_fuzz_unsized_writer,
         test_invalid_reader_seek
     )
@@ -133,6 +142,83 @@ pub async fn test_write_only(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a local file into path should succeed.
+pub async fn test_write_from_path(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    let local_path = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    std::fs::write(&local_path, &content)?;
+
+    let written = op.write_from_path(&path, &local_path).await?;
+    assert_eq!(written, size as u64);
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs, content, "read content");
+
+    op.delete(&path).await.expect("delete must succeed");
+    std::fs::remove_file(&local_path)?;
+    Ok(())
+}
+
+/// Write data from async read into path should succeed.
+pub async fn test_write_from_async_read(op: Operator) -> Result<()> {
+    let (content, size) = gen_bytes();
+
+    for size_hint in [Some(size as u64), None] {
+        let path = uuid::Uuid::new_v4().to_string();
+
+        let reader = std::io::Cursor::new(content.clone());
+        let written = op.write_from_async_read(&path, reader, size_hint).await?;
+        assert_eq!(written, size as u64);
+
+        let bs = op.read(&path).await?;
+        assert_eq!(bs, content, "read content with size hint {size_hint:?}");
+
+        op.delete(&path).await.expect("delete must succeed");
+    }
+    Ok(())
+}
+
+/// Write a value as JSON and read it back should succeed.
+pub async fn test_write_json(op: Operator) -> Result<()> {
+    let path = format!("{}.json", uuid::Uuid::new_v4());
+    let value = serde_json::json!({"name": "opendal", "tags": ["storage", "rust"]});
+
+    op.write_json(&path, &value).await?;
+
+    let actual: serde_json::Value = op.read_json(&path).await?;
+    assert_eq!(actual, value);
+
+    let err = op
+        .read_json::<Vec<u8>>(&path)
+        .await
+        .expect_err("read json into wrong type must fail");
+    assert_eq!(err.kind(), ErrorKind::Unexpected);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Read path into a local file should succeed.
+pub async fn test_read_to_path(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    op.write(&path, content.clone()).await?;
+
+    let local_path = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    let read = op.read_to_path(&path, &local_path).await?;
+    assert_eq!(read, size as u64);
+
+    let bs = std::fs::read(&local_path)?;
+    assert_eq!(bs, content, "local content");
+
+    op.delete(&path).await.expect("delete must succeed");
+    std::fs::remove_file(&local_path)?;
+    Ok(())
+}
+
 /// Write file with dir path should return an error
 pub async fn test_write_with_dir_path(op: Operator) -> Result<()> {
     let path = format!("{}/", uuid::Uuid::new_v4());
@@ -245,6 +331,39 @@ pub async fn test_write_with_content_disposition(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write if not exists should succeed exactly once among concurrent writers.
+pub async fn test_write_if_not_exists(op: Operator) -> Result<()> {
+    if !op.info().capability().write_with_if_none_match {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes_with_range(1..1024);
+    let content = bytes::Bytes::from(content);
+
+    let results = futures::future::join_all((0..100).map(|_| {
+        let op = op.clone();
+        let path = path.clone();
+        let content = content.clone();
+        async move { op.write_if_not_exists(&path, content).await }
+    }))
+    .await;
+
+    let mut written = 0;
+    for res in results {
+        if res? {
+            written += 1;
+        }
+    }
+    assert_eq!(written, 1, "exactly one writer must succeed");
+    assert_eq!(op.read(&path).await?, content);
+
+    assert!(!op.write_if_not_exists(&path, content).await?);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Stat existing file should return metadata
 pub async fn test_stat_file(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -321,6 +440,33 @@ pub async fn test_stat_not_exist(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Head file should return the same content length and etag as stat.
+pub async fn test_head_file(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let (content_length, etag) = op.head(&path).await?;
+    assert_eq!(content_length, size as u64);
+
+    let meta = op.metadata(&Entry::new(&path), Metakey::Etag).await?;
+    assert_eq!(etag.as_deref(), meta.etag());
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Head not exist file should return NotFound
+pub async fn test_head_not_exist(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    let err = op.head(&path).await.expect_err("head must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    Ok(())
+}
+
 /// Stat with if_match should succeed, else get a ConditionNotMatch error.
 pub async fn test_stat_with_if_match(op: Operator) -> Result<()> {
     if !op.info().capability().stat_with_if_match {
@@ -452,6 +598,78 @@ pub async fn test_read_range(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Read at offset should match.
+pub async fn test_read_at(op: Operator) -> Result<()> {
+    if !op.info().capability().read_with_range {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+    let (offset, length) = gen_offset_length(size);
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let bs = op.read_at(&path, offset, length).await?;
+    assert_eq!(bs.len() as u64, length, "read size");
+    assert_eq!(
+        bs,
+        content[offset as usize..(offset + length) as usize],
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Read exact at offset should fill the buffer, or return ContentIncomplete.
+pub async fn test_read_exact_at(op: Operator) -> Result<()> {
+    if !op.info().capability().read_with_range {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+    let (offset, length) = gen_offset_length(size);
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let mut buf = vec![0; length as usize];
+    op.read_exact_at(&path, offset, &mut buf).await?;
+    assert_eq!(
+        buf,
+        content[offset as usize..(offset + length) as usize],
+        "read content"
+    );
+
+    let mut buf = vec![0; size + 1];
+    let err = op
+        .read_exact_at(&path, 0, &mut buf)
+        .await
+        .expect_err("read exact must fail");
+    assert_eq!(err.kind(), ErrorKind::ContentIncomplete);
+
+    let mut buf = vec![0; 2];
+    let err = op
+        .read_exact_at(&path, u64::MAX, &mut buf)
+        .await
+        .expect_err("read exact with overflowed range must fail");
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    let err = op
+        .read_exact_at(&path, 0, &mut buf)
+        .await
+        .expect_err("read exact on not exist file must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    Ok(())
+}
+
 /// Read large range content should match.
 pub async fn test_read_large_range(op: Operator) -> Result<()> {
     if !op.info().capability().read_with_range {